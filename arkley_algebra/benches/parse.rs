@@ -0,0 +1,48 @@
+//! Times parsing of long expressions , run with `cargo bench -p arkley_algebra --bench parse`
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use arkley_algebra::{parse_expression, Context};
+
+const ITERATIONS : u32 = 20;
+
+/// Average times of the token based parser (before the lexer rewrite) for the same inputs , in the same order ,
+/// measured with this benchmark in release mode. They are only a rough reference as they depend on the machine
+/// but are kept so the speed-up can be seen without checking out the old parser
+const BASELINE : [Duration;3] = [
+    Duration::from_micros(99_873),
+    Duration::from_micros(203_370),
+    Duration::from_micros(10_872),
+];
+
+/// Parses `input` [ITERATIONS] times and returns the average time taken
+fn time(input : &str,context : &Context) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        let (remaining,expression) = parse_expression(context)(black_box(input)).expect("the input should parse");
+        assert!(remaining.is_empty());
+        black_box(expression);
+    }
+
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let context = Context::default();
+
+    let inputs = [
+        ("sum of terms",vec!["2x"; 5000].join(" + ")),
+        ("sum without spaces",vec!["2x"; 5000].join("+")),
+        ("mixed operations",vec!["3x^2 * (y - 1) / 4"; 2000].join(" - ")),
+    ];
+
+    println!("{:<20} {:>12} {:>12} {:>12} {:>9}","input","size","time","baseline","speed-up");
+
+    for ((name,input),baseline) in inputs.iter().zip(BASELINE) {
+        let time = time(input,&context);
+        let speed_up = baseline.as_secs_f64() / time.as_secs_f64();
+        println!("{name:<20} {:>6} bytes {time:>12.3?} {baseline:>12.3?} {speed_up:>8.1}x",input.len());
+    }
+}
@@ -1,9 +1,9 @@
-use crate::{Term,Expression};
+use crate::{Term,Expression,Numeric};
 
-impl std::ops::Add for Term {
-    type Output = Expression;
+impl<N : Numeric> std::ops::Add for Term<N> {
+    type Output = Expression<N>;
 
-    fn add(self,other : Term) -> Self::Output {
+    fn add(self,other : Term<N>) -> Self::Output {
         if self.is_combinable_with(&other) {
             return self.force_add_terms(other).into();
         }
@@ -13,19 +13,19 @@ impl std::ops::Add for Term {
 }
 
 
-impl std::ops::Add for Expression {
-    type Output = Expression;
+impl<N : Numeric> std::ops::Add for Expression<N> {
+    type Output = Expression<N>;
 
-    fn add(self,other : Expression) -> Self::Output {
+    fn add(self,other : Expression<N>) -> Self::Output {
         Expression::new_plus(self,other).combine_terms()
     }
 }
 
 
-impl std::ops::Add<Term> for Expression {
-    type Output = Expression;
+impl<N : Numeric> std::ops::Add<Term<N>> for Expression<N> {
+    type Output = Expression<N>;
 
-    fn add(self,other : Term) -> Self::Output {
+    fn add(self,other : Term<N>) -> Self::Output {
         Expression::new_plus(self,other.into()).combine_terms()
     }
 }
@@ -34,11 +34,11 @@ impl std::ops::Add<Term> for Expression {
 use crate::Function;
 
 #[cfg(feature="function")]
-impl std::ops::Add<Function > for Function  {
-    type Output = Expression; 
-    fn add(self, rhs: Function ) -> Self::Output {
+impl<N : Numeric> std::ops::Add<Function<N>> for Function<N> {
+    type Output = Expression<N>; 
+    fn add(self, rhs: Function<N>) -> Self::Output {
         match self.same(&rhs) && self.arguments_empty(&rhs) {
-            true => Expression::new_mal(2.0.into(), self.into()),
+            true => Expression::new_mal(Expression::constant(N::one() + N::one()), self.into()),
             false => Expression::new_plus(
                 self.into(), 
                 rhs.into()
@@ -48,20 +48,20 @@ impl std::ops::Add<Function > for Function  {
 }
 
 #[cfg(feature="function")]
-impl std::ops::Add<Term> for Function  {
-    type Output = Expression; 
-    fn add(self, rhs: Term) -> Self::Output {
+impl<N : Numeric> std::ops::Add<Term<N>> for Function<N> {
+    type Output = Expression<N>; 
+    fn add(self, rhs: Term<N>) -> Self::Output {
         Expression::new_plus(self.into(),rhs.into())
     }
 }
 
 #[cfg(feature="function")]
-impl std::ops::Add<Function > for Expression {
-    type Output = Expression; 
-    fn add(self, rhs: Function ) -> Self::Output {
+impl<N : Numeric> std::ops::Add<Function<N>> for Expression<N> {
+    type Output = Expression<N>; 
+    fn add(self, rhs: Function<N>) -> Self::Output {
         match self {
             Self::Function(ref func) if func.arguments_empty(&rhs) => match func.same(&rhs) {
-                true => Expression::new_mal(2.0.into(), self.into()),
+                true => Expression::new_mal(Expression::constant(N::one() + N::one()), self.into()),
                 false => Expression::new_plus(self.into(),rhs.into()),
             },
             Self::Function(_) => Expression::new_plus(self.into(),rhs.into()),
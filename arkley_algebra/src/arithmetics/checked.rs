@@ -0,0 +1,156 @@
+use num_notation::{Number, Pow};
+
+use crate::{Term, Expression, ArithmeticOperation, AlgebraError, Numeric, exponent_of};
+use crate::manipulation::number_to_f64;
+
+impl<N : Numeric> Term<N> {
+    /// Checks if the term is `0` , no matter which variables it contains
+    pub(crate) fn is_zero(&self) -> bool {
+        self.coefficient.is_zero()
+    }
+
+    /// Checks if the coefficient and all exponents are finite numbers
+    fn is_finite(&self) -> bool {
+        self.coefficient.to_float().is_finite() && self.variables.values().all(|exponent| number_to_f64(exponent).is_finite())
+    }
+}
+
+impl<N : Numeric> Expression<N> {
+    /// Checks if the expression is known to be `0` , like `0`, `0x` or `0 * (x + 1)`
+    pub(crate) fn is_zero(&self) -> bool {
+        match self {
+            Expression::Term(term) => term.is_zero(),
+            Expression::Binary { operation : ArithmeticOperation::Mal, left, right } => left.is_zero() || right.is_zero(),
+            Expression::Binary { operation : ArithmeticOperation::Durch, left, .. } => left.is_zero(),
+            _ => false
+        }
+    }
+
+    /// Checks if the expression is a negative constant like `-2`
+    fn is_negative_constant(&self) -> bool {
+        matches!(self,Expression::Term(term) if term.variables.is_empty() && term.coefficient.is_negative())
+    }
+
+    /// Checks if all coefficients and exponents within the expression are finite numbers
+    fn is_finite(&self) -> bool {
+        match self {
+            Expression::Term(term) => term.is_finite(),
+            Expression::Binary { left, right, .. } => left.is_finite() && right.is_finite(),
+            Expression::Function(func) => func.arguments.values().flatten().all(Expression::is_finite)
+        }
+    }
+
+    /// Returns the expression if it is finite otherwise [AlgebraError::NonFinite]
+    pub(crate) fn ensure_finite(self) -> Result<Self,AlgebraError<N>> {
+        match self.is_finite() {
+            true => Ok(self),
+            false => Err(AlgebraError::NonFinite(self))
+        }
+    }
+}
+
+impl<N : Numeric> Expression<N> {
+    /// Adds the expressions , returning an error if the result is not finite
+    pub fn checked_add(self,other : Expression<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        (self + other).ensure_finite()
+    }
+
+    /// Subtracts the expressions , returning an error if the result is not finite
+    pub fn checked_sub(self,other : Expression<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        (self - other).ensure_finite()
+    }
+
+    /// Multiplies the expressions , returning an error if the result is not finite
+    pub fn checked_mul(self,other : Expression<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        (self * other).ensure_finite()
+    }
+
+    /// Divides the expressions , returning [AlgebraError::DivisionByZero] if `other` is `0`
+    /// or an error if the result is not finite
+    pub fn checked_div(self,other : Expression<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        if other.is_zero() {
+            return Err(AlgebraError::DivisionByZero(self));
+        }
+
+        (self / other).ensure_finite()
+    }
+
+    /// Raises the expression to the power , returning [AlgebraError::UndefinedPower] for `0^0` ,
+    /// [AlgebraError::DivisionByZero] for `0` to a negative power or an error if the result is not finite
+    pub fn checked_pow(self,exponent : Expression<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        if self.is_zero() {
+            if exponent.is_zero() {
+                return Err(AlgebraError::UndefinedPower(self.into(),exponent.into()));
+            }
+
+            if exponent.is_negative_constant() {
+                return Err(AlgebraError::DivisionByZero(Expression::constant(N::one())));
+            }
+        }
+
+        self.pow(exponent).ensure_finite()
+    }
+}
+
+impl<N : Numeric> Term<N> {
+    /// Adds the terms , see [Expression::checked_add]
+    pub fn checked_add(self,other : Term<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        Expression::from(self).checked_add(other.into())
+    }
+
+    /// Subtracts the terms , see [Expression::checked_sub]
+    pub fn checked_sub(self,other : Term<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        Expression::from(self).checked_sub(other.into())
+    }
+
+    /// Multiplies the terms , see [Expression::checked_mul]
+    pub fn checked_mul(self,other : Term<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        Expression::from(self).checked_mul(other.into())
+    }
+
+    /// Divides the terms , see [Expression::checked_div]
+    pub fn checked_div(self,other : Term<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        Expression::from(self).checked_div(other.into())
+    }
+
+    /// Raises the term to the power , see [Expression::checked_pow]
+    pub fn checked_pow(self,exponent : Number) -> Result<Expression<N>,AlgebraError<N>> {
+        Expression::from(self).checked_pow(exponent_of(&exponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn division_by_zero() {
+        let result = Term::from('x').checked_div(Term::from(0.0));
+        assert_eq!(result,Err(AlgebraError::DivisionByZero('x'.into())));
+
+        let result = Expression::from(6.0).checked_div(Term::from(0.0) * Term::from('y'));
+        assert!(matches!(result,Err(AlgebraError::DivisionByZero(_))));
+
+        assert_eq!(Term::from(6.0).checked_div(Term::from(3.0)).unwrap().to_string(),"2");
+    }
+
+    #[test]
+    fn undefined_powers() {
+        let result = Term::from(0.0).checked_pow(Number::Decimal(0.0));
+        assert!(matches!(result,Err(AlgebraError::UndefinedPower(..))));
+
+        let result = Term::from(0.0).checked_pow(Number::Decimal(-1.0));
+        assert!(matches!(result,Err(AlgebraError::DivisionByZero(..))));
+
+        assert_eq!(Term::from(2.0).checked_pow(Number::Decimal(3.0)).unwrap().to_string(),"8");
+    }
+
+    #[test]
+    fn non_finite() {
+        // Even roots of negative numbers are kept as powers instead of becoming `NaN`
+        assert_eq!(Term::from(-4.0).checked_pow(Number::Decimal(0.5)).unwrap().to_string(),"(-4)^0.5");
+
+        let result = Term::from(f64::MAX).checked_mul(Term::from(10.0));
+        assert!(matches!(result,Err(AlgebraError::NonFinite(_))));
+    }
+}
@@ -1,16 +1,12 @@
 use std::collections::{BTreeMap, HashMap};
-use num_notation::{
-    Number,
-    fraction::Signed,
-};
-use crate::{Expression,Term,Variables,ArithmeticOperation, Function};
+use crate::{Expression,Term,Variables,ArithmeticOperation, Function, Numeric};
 
-impl Term {
+impl<N : Numeric> Term<N> {
     pub(in crate::arithmetics) fn is_combinable_with(&self,other : &Self) -> bool {
         self.variables == other.variables
     }
 
-    pub(in crate::arithmetics) fn force_add_terms(self,other : Term) -> Self {
+    pub(in crate::arithmetics) fn force_add_terms(self,other : Term<N>) -> Self {
         let coefficient = self.coefficient + other.coefficient;
         let variables = self.variables;
         Term::new_with_variable(coefficient,variables)
@@ -19,7 +15,7 @@ impl Term {
 
 /// TODO : Make this so it 'handles' cases like a/a or things like that so it simplifys itself
 /// Used to combine terms like 2x + x into 3x 
-impl Expression { 
+impl<N : Numeric> Expression<N> { 
     /// Collects all terms of addition (+) or subtraction (-) variants into 'treemap'
     ///
     /// # Returns
@@ -27,7 +23,7 @@ impl Expression {
     /// An optional Expression representing the result of combining terms from nested (Nested), multiplication (*),
     /// and division (/) variants.
     /// `None` = No expr left
-    fn collect_terms(self,term_map : &mut BTreeMap<Variables,Number>,fn_map : &mut HashMap<Function,i16>) -> Option<Expression> {
+    fn collect_terms(self,term_map : &mut BTreeMap<Variables,N>,fn_map : &mut HashMap<Function<N>,i16>) -> Option<Expression<N>> {
         match self {
             Self::Term(term) => {
                 term_map.entry(term.variables)
@@ -67,13 +63,35 @@ impl Expression {
                             .or_insert(-1);
                         None
                     },
+                    // Terms of nested sums like `a - (b + c)` are subtracted , so they are collected separately first
+                    Self::Binary { operation : ArithmeticOperation::Plus | ArithmeticOperation::Minus , .. } => {
+                        let mut right_terms = BTreeMap::new();
+                        let mut right_functions = HashMap::new();
+
+                        let rexpr = right.collect_terms(&mut right_terms,&mut right_functions);
+
+                        for (variables,coefficient) in right_terms {
+                            term_map.entry(variables)
+                                .and_modify(|value| *value -= coefficient.clone())
+                                .or_insert(-coefficient);
+                        }
+
+                        for (func,count) in right_functions {
+                            fn_map.entry(func)
+                                .and_modify(|value| *value -= count)
+                                .or_insert(-count);
+                        }
+
+                        rexpr
+                    },
                     _ => right.collect_terms(term_map,fn_map),
                 };
 
                 match (lexpr,rexpr) {
                     (None,None) => None,
-                    (Some(expr),None) | (None,Some(expr)) => Some(expr),
-                    // TODO : Change sign here maybe issue
+                    (Some(expr),None) => Some(expr),
+                    // The leftover of the right side is still subtracted , so `y - (4 + y)^0.5` keeps its sign
+                    (None,Some(expr)) => Some(Expression::new_minus(Expression::constant(N::zero()),expr)),
                     (Some(expr1),Some(expr2)) => Some(Expression::new_binary(operation,expr1,expr2))
                 }  
             },
@@ -91,16 +109,23 @@ impl Expression {
     ///
     /// The reconstructed expression.
     fn reconstruct_expression(
-        mut terms : BTreeMap<Variables,Number>,
-        functions : HashMap<Function,i16>,
-        nested_expr : Option<Expression>
+        mut terms : BTreeMap<Variables,N>,
+        functions : HashMap<Function<N>,i16>,
+        nested_expr : Option<Expression<N>>
     ) -> Self {  
-        let mut functions_sorted_by_count : Vec<(Function,i16)> = functions.into_iter().collect();
+        // Terms like `0x` which cancelled out are dropped
+        terms.retain(|_,coefficient| !coefficient.is_zero());
+
+        let mut functions_sorted_by_count : Vec<(Function<N>,i16)> = functions.into_iter().filter(|(_,count)| *count != 0).collect();
+
         functions_sorted_by_count.sort_by_key(|(_,key)| *key);
 
-        let mut expression : Expression = match functions_sorted_by_count.is_empty() {
-            true => Self::from_terms(&mut terms),
-            false => Self::from_function(&mut functions_sorted_by_count)
+        let mut expression : Expression<N> = match functions_sorted_by_count.pop() {
+            Some(function) => Self::from_function(function),
+            None => match terms.pop_first() {
+                Some((variables,coefficient)) => Term::new_with_variable(coefficient,variables).into(),
+                None => return nested_expr.unwrap_or_else(|| Expression::constant(N::zero()))
+            }
         };
 
         expression = expression.join_functions(functions_sorted_by_count)
@@ -115,7 +140,7 @@ impl Expression {
     /// # Returns
     ///
     /// The expression with combined terms.
-    pub(in crate::arithmetics) fn combine_terms(self) -> Expression {
+    pub(in crate::arithmetics) fn combine_terms(self) -> Expression<N> {
         let mut term_map = BTreeMap::new();
 
         let mut fn_map = HashMap::new();
@@ -126,21 +151,17 @@ impl Expression {
     }
 }
 
-impl Expression {
-    fn join_nested_expression(self,nested_expr : Option<Expression>) -> Self { 
-        if let Some(nested) = nested_expr {
-            return Self::new_plus(self,nested);
+impl<N : Numeric> Expression<N> {
+    fn join_nested_expression(self,nested_expr : Option<Expression<N>>) -> Self { 
+        match nested_expr {
+            // A subtracted leftover like `0 - (4 + y)^0.5` is joined as `self - (4 + y)^0.5`
+            Some(Expression::Binary { operation : ArithmeticOperation::Minus, left, right }) if left.is_zero() => Self::new_minus(self,*right),
+            Some(nested) => Self::new_plus(self,nested),
+            None => self
         }
-        
-        self
     }
 
-    fn from_terms(terms : &mut BTreeMap<Variables,Number>) -> Self {
-        let (variables,coefficient) = terms.pop_first().unwrap();
-        Term::new_with_variable(coefficient,variables).into()
-    }
-
-    fn join_terms(mut self,terms : BTreeMap<Variables,Number>) -> Self {
+    fn join_terms(mut self,terms : BTreeMap<Variables,N>) -> Self {
         let _before = self.clone();
 
         for (variables,coefficient) in terms {
@@ -162,43 +183,42 @@ impl Expression {
         self
     }
 
-    fn from_function(functions : &mut Vec<(Function,i16)>) -> Self {
-        let (_function,count) = functions.pop().unwrap();
-        let function : Expression = _function.into();
+    /// Creates `count * function` for how many times a function occurs , writing it as a sum if the numeric type
+    /// can not represent `count` (like `i8`)
+    fn multiple(count : i16,function : Expression<N>) -> Self {
+        match N::from_i16(count) {
+            Some(count) => Expression::new_mal(Expression::constant(count),function),
+            None => {
+                let sum = (1..count.unsigned_abs()).fold(function.clone(),|sum,_| Expression::new_plus(sum,function.clone()));
+                match count.is_negative() {
+                    true => -sum,
+                    false => sum
+                }
+            }
+        }
+    }
+
+    fn from_function((_function,count) : (Function<N>,i16)) -> Self {
+        let function : Expression<N> = _function.into();
         
         if count == 1 {
             return function;
         }
 
-        Expression::new_mal(
-            count.into(), 
-            function
-        )
+        Self::multiple(count,function)
     }
 
-    fn join_functions(mut self,functions : Vec<(Function,i16)>) -> Self {
+    fn join_functions(mut self,functions : Vec<(Function<N>,i16)>) -> Self {
         for (_function,count) in functions {
-            let function : Expression = _function.into();
+            let function : Expression<N> = _function.into();
             if count == 1 {
                 self = Expression::new_plus(self, function);
                 continue;
             }
 
             self = match count.is_positive() {
-                true => Expression::new_plus(
-                    self, 
-                    Expression::new_mal(
-                        count.into(), 
-                        function
-                    )
-                ),
-                false => Expression::new_minus(
-                    self, 
-                    Expression::new_mal(
-                        (-count).into(), 
-                        function
-                    )
-                )
+                true => Expression::new_plus(self,Self::multiple(count,function)),
+                false => Expression::new_minus(self,Self::multiple(-count,function))
             }
         }
 
@@ -2,11 +2,11 @@ use std::{collections::{HashMap, BTreeSet, BTreeMap}, hash::Hash, vec};
 
 use arkley_describe::{
     DescribeAdd, Steps,
-    fluent_templates::{StaticLoader, LanguageIdentifier}, DescribeSub, DescribeMul, DescribeDiv
+    fluent_templates::{StaticLoader, LanguageIdentifier}, DescribeSub, DescribeMul, DescribeDiv, DescribePow, DescribeSqrt
 };
-use num_notation::One;
+use num_notation::{Number, One, Zero, Pow};
 
-use crate::{Term, manipulation::VariableAnalysis};
+use crate::{Term, Expression, ArithmeticOperation, manipulation::VariableAnalysis};
 
 impl DescribeAdd for Term {
     fn describe_add(self,other:Self,resources: &StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
@@ -136,4 +136,96 @@ impl DescribeDiv for Term {
 
        descriptions.into()
     }
+}
+
+impl DescribePow<Number> for Term {
+    fn describe_pow(self,other:Number,resources: &StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        let args = HashMap::from([
+            ("term", self.to_string().into()),
+            ("exponent", other.to_string().into()),
+            ("result", self.pow(other).to_string().into())
+        ]);
+
+        let string = resources.lookup_single_language::<&str>(lang,"algebric-term.pow",Some(&args))?;
+        vec![string].into()
+    }
+}
+
+impl DescribeSqrt for Term {
+    fn describe_sqrt(self,resources: &StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        let key = match self.coefficient < 0 {
+            true => "algebric-term.sqrt_negative",
+            false => "algebric-term.sqrt"
+        };
+
+        let args = HashMap::from([
+            ("term", self.to_string().into()),
+            ("result", self.sqrt().to_string().into())
+        ]);
+
+        let string = resources.lookup_single_language::<&str>(lang,key,Some(&args))?;
+        vec![string].into()
+    }
+}
+
+impl DescribePow<Number> for Expression {
+    fn describe_pow(self,other:Number,resources: &StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        if let Expression::Term(term) = self {
+            return term.describe_pow(other, resources, lang);
+        }
+
+        let mut args = HashMap::from([
+            ("expr", self.to_string().into()),
+            ("exponent", other.to_string().into()),
+            ("result", self.clone().pow(other.clone()).to_string().into())
+        ]);
+
+        let key = match self {
+            _ if other.is_zero() => "algebric-expression.pow_zero",
+            _ if other.is_one() => "algebric-expression.pow_one",
+            Expression::Binary { operation : ArithmeticOperation::Mal , .. } => "algebric-expression.pow_product",
+            Expression::Binary { operation : ArithmeticOperation::Durch , .. } => "algebric-expression.pow_quotient",
+            Expression::Binary { operation : ArithmeticOperation::Pow , .. } => "algebric-expression.pow_power",
+            Expression::Binary { operation , left , right } => match super::pow::as_expandable(&other).and_then(|n| Expression::binomial_terms(operation, *left, *right, n)) {
+                Some(terms) => {
+                    let expansion = terms
+                        .into_iter()
+                        .map(|(coefficient,a,b)| format!("{coefficient}({a})({b})"))
+                        .collect::<Vec<_>>()
+                        .join(" + ");
+
+                    args.insert("expansion", expansion.into());
+                    "algebric-expression.pow_binomial"
+                },
+                None => "algebric-expression.pow_unchanged"
+            },
+            _ => "algebric-expression.pow_unchanged"
+        };
+
+        let string = resources.lookup_single_language::<&str>(lang,key,Some(&args))?;
+        vec![string].into()
+    }
+}
+
+impl DescribeSqrt for Expression {
+    fn describe_sqrt(self,resources: &StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        if let Expression::Term(term) = self {
+            return term.describe_sqrt(resources, lang);
+        }
+
+        let key = match self {
+            Expression::Binary { operation : ArithmeticOperation::Mal , .. } => "algebric-expression.sqrt_product",
+            Expression::Binary { operation : ArithmeticOperation::Durch , .. } => "algebric-expression.sqrt_quotient",
+            Expression::Binary { operation : ArithmeticOperation::Pow , .. } => "algebric-expression.sqrt_power",
+            _ => "algebric-expression.sqrt_unchanged"
+        };
+
+        let args = HashMap::from([
+            ("expr", self.to_string().into()),
+            ("result", self.sqrt().to_string().into())
+        ]);
+
+        let string = resources.lookup_single_language::<&str>(lang,key,Some(&args))?;
+        vec![string].into()
+    }
 }
\ No newline at end of file
@@ -1,35 +1,40 @@
 use std::collections::{BTreeSet,HashMap, HashSet};
 use std::cmp::Ordering;
 
-use num_notation::{Number, One};
+use num_notation::Number;
 
-use crate::{Term, Expression, manipulation::VariableAnalysis};
+use crate::{Term, Expression, ArithmeticOperation, Numeric, manipulation::VariableAnalysis};
 
-impl Term  {
-    fn is_numeric_one(&self) -> bool {
+impl<N : Numeric> Term<N> {
+    pub(super) fn is_numeric_one(&self) -> bool {
         self.coefficient.is_one() && self.variables.is_empty()
     }
 
     fn both_contain_no_variables(&self,other : &Self) -> bool {
         self.variables.is_empty() && other.variables.is_empty()
     }
+
+    /// Checks if dividing by the term is undefined , so it is `0` or its coefficient is not finite (like `NaN` from `0/0`)
+    fn is_undefined_divisor(&self) -> bool {
+        self.coefficient.is_zero() || !self.coefficient.to_float().is_finite()
+    }
 }
 
 #[cfg(feature="function")]
 use crate::Function;
 
 #[cfg(feature="function")]
-impl std::ops::Div<Function > for Function  {
-    type Output = Expression; 
-    fn div(self, rhs: Function ) -> Self::Output {
-        if self.same(&rhs) { 1.into() } else { Expression::new_durch(self.into(),rhs.into()) }
+impl<N : Numeric> std::ops::Div<Function<N>> for Function<N> {
+    type Output = Expression<N>; 
+    fn div(self, rhs: Function<N>) -> Self::Output {
+        if self.same(&rhs) { Expression::constant(N::one()) } else { Expression::new_durch(self.into(),rhs.into()) }
     }
 }
 
 #[cfg(feature="function")]
-impl std::ops::Div<Term> for Function  {
-    type Output = Expression; 
-    fn div(self, other: Term) -> Self::Output {
+impl<N : Numeric> std::ops::Div<Term<N>> for Function<N> {
+    type Output = Expression<N>; 
+    fn div(self, other: Term<N>) -> Self::Output {
         if other.is_numeric_one() {
             return self.into();
         };
@@ -39,26 +44,35 @@ impl std::ops::Div<Term> for Function  {
 }
 
 #[cfg(feature="function")]
-impl std::ops::Div<Function > for Expression {
-    type Output = Expression; 
-    fn div(self, rhs: Function) -> Self::Output {
+impl<N : Numeric> std::ops::Div<Function<N>> for Expression<N> {
+    type Output = Expression<N>; 
+    fn div(self, rhs: Function<N>) -> Self::Output {
         match self {
-            Self::Function(func) if func == rhs => 1.into(),
+            Self::Function(func) if func == rhs => Expression::constant(N::one()),
             _ => Expression::new_durch(self,rhs.into())
         }        
     }
 }
 
-impl std::ops::Div for Term {
-    type Output = Expression;
+impl<N : Numeric> std::ops::Div for Term<N> {
+    type Output = Expression<N>;
 
-    fn div(mut self,mut other : Term) -> Self::Output {
+    fn div(mut self,mut other : Term<N>) -> Self::Output {
         if other.is_numeric_one() {
             return self.into();
         };
 
+        // Nothing can be cancelled when dividing by 0 or NaN , Expression::checked_div reports these as errors
+        if other.is_undefined_divisor() {
+            return Expression::new_durch(self.into(),other.into());
+        }
+
+        // Things like 2/3 for integers are kept as they are
         if self.both_contain_no_variables(&other) {
-            return Term::new(self.coefficient / other.coefficient).into()
+            return match self.coefficient.exact_div(&other.coefficient) {
+                Some(quotient) => Term::new(quotient).into(),
+                None => Expression::new_durch(self.into(),other.into())
+            }
         }
 
         let s_keys: BTreeSet<_> = self.get_unique_variables();
@@ -80,6 +94,12 @@ impl std::ops::Div for Term {
         self.cancel_variables_and_divide_coefficient(&min_exponents, gcd_coefficient.clone());
         other.cancel_variables_and_divide_coefficient(&min_exponents, gcd_coefficient);
 
+        // The sign is kept in the numerator so -y/2 is not written as y/-2
+        if other.coefficient.is_negative() {
+            self.coefficient = -self.coefficient;
+            other.coefficient = -other.coefficient;
+        }
+
         match other.is_numeric_one() && other.variables.is_empty() {
             true => self.into(),
             false => Expression::new_durch(self.into(), other.into())
@@ -87,9 +107,9 @@ impl std::ops::Div for Term {
     }
 }
 
-impl std::ops::Div<Term> for Expression {
+impl<N : Numeric> std::ops::Div<Term<N>> for Expression<N> {
     type Output = Self;
-    fn div(mut self,mut other : Term) -> Self::Output {
+    fn div(mut self,mut other : Term<N>) -> Self::Output {
         if other.is_numeric_one() {
             return self 
         }
@@ -97,6 +117,15 @@ impl std::ops::Div<Term> for Expression {
         if let Expression::Term(term) = self {
             return term / other;
         }
+
+        if other.is_undefined_divisor() {
+            return Expression::new_durch(self, other.into());
+        }
+
+        // Cancelling is only possible in sums of terms , not inside of a power like (x + 1)^2 / x or a fraction like (x/3) / 2
+        if !self.is_sum_of_terms() {
+            return Expression::new_durch(self, other.into());
+        }
         
         let expr_variables = self.get_unique_variables();
         let term_variables = other.get_unique_variables();
@@ -112,8 +141,11 @@ impl std::ops::Div<Term> for Expression {
         sclone.get_min_exponents_and_coefficient(&common_variables, &mut min_exponents, &mut coefficients);
         oclone.get_min_exponents_and_coefficient(&common_variables, &mut min_exponents, &mut coefficients);
 
-        // Figure out gcd to divide coefficients by
-        let gcd_coefficient = calculate_gcd(coefficients);
+        // Figure out gcd to divide coefficients by , which is negative if the term is so the sign is kept in the numerator
+        let gcd_coefficient = match other.coefficient.is_negative() {
+            true => -calculate_gcd(coefficients),
+            false => calculate_gcd(coefficients)
+        };
         
         self.cancel_variables_and_divide_coefficient(&min_exponents,gcd_coefficient.clone());
         other.cancel_variables_and_divide_coefficient(&min_exponents,gcd_coefficient);
@@ -125,12 +157,13 @@ impl std::ops::Div<Term> for Expression {
     }
 }
 
-impl std::ops::Div for Expression {
+impl<N : Numeric> std::ops::Div for Expression<N> {
     type Output = Self;
-    fn div(self,other : Expression) -> Self::Output {
+    fn div(self,other : Expression<N>) -> Self::Output {
         match (self,other) {
             (Expression::Term(t1), Expression::Term(t2)) => t1 / t2,
             (expr @_, Expression::Term(term)) => expr / term,
+            (top,bottom) if !top.is_sum_of_terms() || !bottom.is_sum_of_terms() => Expression::new_durch(top, bottom),
             (mut top @_,mut bottom @_)=> {
                 let top_variables = top.get_unique_variables();
                 let bottom_variables = bottom.get_unique_variables();
@@ -162,36 +195,37 @@ impl std::ops::Div for Expression {
     }
 }
 
-pub(crate) fn gcd(a : Number,b : Number) -> Number {
-    match b == 0 {
+pub(crate) fn gcd<N : Numeric>(a : N,b : N) -> N {
+    // The remainder of `NaN` or infinity is `NaN` , which would never reach `0`
+    if !a.to_float().is_finite() || !b.to_float().is_finite() {
+        return N::one();
+    }
+
+    match b.is_zero() {
         true => a,
         false => gcd(b.clone(),a % b)
     }
 }
 
-fn calculate_gcd(mut coefficients: HashSet<Number>) -> Number {
-    let _first_entry = coefficients.iter().next().cloned().unwrap();// equal to 'first' entry
-    let mut ans = coefficients.take(&_first_entry).unwrap(); 
+fn calculate_gcd<N : Numeric>(coefficients: HashSet<N>) -> N {
+    // Uses the absolute values so the sign does not depend on the order of the set
+    let ans = coefficients.into_iter()
+        .map(|coefficient| coefficient.abs())
+        .reduce(gcd)
+        .unwrap_or_else(N::one);
 
-    for item in coefficients.into_iter() {
-        ans = gcd(item,ans);
-
-        if ans.is_one() {
-            ans.set_one();
-            break;
-        }
+    match ans.is_zero() {
+        true => N::one(),
+        false => ans
     }
-
-    ans
 }
 
-impl Term {
+impl<N : Numeric> Term<N> {
     /// Recursively calculates the minimum variable exponent 
     pub(super) fn get_min_exponents<'a>(&'a self,common_variables : &BTreeSet<&&char>,min_exponents : &mut HashMap<&'a char,&'a Number>) {
-        self.variables.keys()
-            .filter(|key| common_variables.contains(key))
-            .for_each(|key|{
-                let possible_value = self.variables.get(key).unwrap();
+        self.variables.iter()
+            .filter(|(key,_)| common_variables.contains(key))
+            .for_each(|(key,possible_value)|{
                 min_exponents.entry(key)
                     .and_modify(|prev| *prev = (*prev).min(possible_value))
                     .or_insert(possible_value);
@@ -203,8 +237,12 @@ impl Term {
     /// So 2x^2 / 2x = 2x / 2 with this methods
     pub(super) fn cancel_variables(&mut self,min_exponents : &HashMap<&char, &Number>) {
         for (key,min_exponent) in min_exponents {
-            match min_exponent.cmp(&self.variables.get(key).unwrap()) {
-                Ordering::Less => *self.variables.get_mut(key).unwrap() -= (*min_exponent).clone(),
+            let Some(exponent) = self.variables.get_mut(key) else {
+                continue;
+            };
+
+            match (*min_exponent).cmp(exponent) {
+                Ordering::Less => *exponent -= (*min_exponent).clone(),
                 Ordering::Equal  | Ordering::Greater => {
                     self.variables.remove(key);
                 }
@@ -213,7 +251,7 @@ impl Term {
     }
 
     /// Recursively calculates the minimum exponent values for common variables between two expression trees.
-    fn get_min_exponents_and_coefficient<'a>(&'a self,common_variables : &BTreeSet<&&char>,min_exponents : &mut HashMap<&'a char, &'a Number>,coefficients :&mut HashSet<Number>) {
+    fn get_min_exponents_and_coefficient<'a>(&'a self,common_variables : &BTreeSet<&&char>,min_exponents : &mut HashMap<&'a char, &'a Number>,coefficients :&mut HashSet<N>) {
         if !coefficients.contains(&self.coefficient) {
             coefficients.insert(self.coefficient.clone());
         }
@@ -224,20 +262,29 @@ impl Term {
     /// Cancels common variables and divides the coefficient by the greatest common divisor (GCD).
     ///
     /// So 2x^2 / 2x = x with this method
-    fn cancel_variables_and_divide_coefficient(&mut self,min_exponents : &HashMap<&char, &Number>,gcd_coefficient : Number) {
+    fn cancel_variables_and_divide_coefficient(&mut self,min_exponents : &HashMap<&char, &Number>,gcd_coefficient : N) {
         self.coefficient /= gcd_coefficient;
         self.cancel_variables(min_exponents);
     }
 }
 
-impl Expression {
+impl<N : Numeric> Expression<N> {
+    /// Checks if the expression only adds or subtracts terms , so dividing it means dividing each of its terms
+    fn is_sum_of_terms(&self) -> bool {
+        match self {
+            Expression::Term(_) => true,
+            Expression::Binary { operation : ArithmeticOperation::Plus | ArithmeticOperation::Minus, left, right } => left.is_sum_of_terms() && right.is_sum_of_terms(),
+            _ => false
+        }
+    }
+
     /// Recursively calculates the minimum exponent values and coefficients for common variables
     /// within the expression.
     fn get_min_exponents_and_coefficient<'a>(
         &'a self,
         common_variables : &BTreeSet<&&char>,
         min_exponents : &mut HashMap<&'a char, &'a Number>,
-        coefficients :&mut HashSet<Number>
+        coefficients :&mut HashSet<N>
     ) {
         match self {
             Expression::Term(term) => term.get_min_exponents_and_coefficient(common_variables, min_exponents, coefficients),
@@ -253,7 +300,7 @@ impl Expression {
     ///
     /// This method cancels out common variables based on their minimum exponents and divides all
     /// coefficients within the expression by a common divisor (GCD - Greatest Common Divisor).
-    fn cancel_variables_and_divide_coefficient(&mut self,min_exponents : &HashMap<&char, &Number>,gcd_coefficient : Number) {
+    fn cancel_variables_and_divide_coefficient(&mut self,min_exponents : &HashMap<&char, &Number>,gcd_coefficient : N) {
         match self {
             Expression::Term(ref mut term) => term.cancel_variables_and_divide_coefficient(min_exponents, gcd_coefficient),
             Expression::Binary { left, right, .. } => {
@@ -418,4 +465,17 @@ mod expression_tests {
         let result = expr.clone() / num.clone();
         assert_eq!(result,Expression::new_durch(expr, num.into()));
     }
+
+    #[test]
+    fn division_by_negative_constant(){
+        // The sign is kept in the numerator whatever order the coefficients are found in
+        for _ in 0..16 {
+            assert_eq!((from_str("2x + 4") / Term::from(-2.0)).to_string(),"-1x + -2");
+            assert_eq!((from_str("x + 2") / Term::from(-2.0)).to_string(),"(-1x + -2)/2");
+        }
+
+        // Dividing every coefficient of a fraction like x/3 would not change it
+        let fraction = Expression::new_durch(from_str("x"),from_str("3"));
+        assert_eq!(fraction.clone() / Term::from(-1.0),Expression::new_durch(fraction,from_str("-1")));
+    }
 }
\ No newline at end of file
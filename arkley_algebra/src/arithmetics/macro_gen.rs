@@ -1,5 +1,5 @@
 use std::ops::{Add,Sub,Mul,Div};
-use num_notation::Number;
+use num_notation::{Number, Pow};
 
 use crate::{Term,Expression,Variables};
 
@@ -8,7 +8,7 @@ use crate::Function;
 
 #[cfg(feature="describe")]
 use arkley_describe::{
-    DescribeAdd, DescribeSub , DescribeMul , DescribeDiv ,Steps,
+    DescribeAdd, DescribeSub , DescribeMul , DescribeDiv , DescribePow ,Steps,
     fluent_templates::{StaticLoader, LanguageIdentifier},
 };
 
@@ -147,6 +147,38 @@ macro_rules! primitives_operations {
         )*
     };
 
+    (pow => $($t : ty),*) => {
+        $(
+            impl Pow<$t> for Term {
+                type Output = Expression;
+                fn pow(self, other: $t) -> Expression {
+                    self.pow(Number::Decimal(other as f64))
+                }
+            }
+
+            impl Pow<$t> for Expression {
+                type Output = Expression;
+                fn pow(self, other: $t) -> Expression {
+                    self.pow(Number::Decimal(other as f64))
+                }
+            }
+
+            #[cfg(feature="describe")]
+            impl DescribePow<$t> for Term {
+                fn describe_pow(self,other:$t,resources: &StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+                    self.describe_pow(Number::Decimal(other as f64),resources,lang)
+                }
+            }
+
+            #[cfg(feature="describe")]
+            impl DescribePow<$t> for Expression {
+                fn describe_pow(self,other:$t,resources: &StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+                    self.describe_pow(Number::Decimal(other as f64),resources,lang)
+                }
+            }
+        )*
+    };
+
     (ops => $($t:ty),*) => {
         $(
             primitives_operations!(add => $t);
@@ -158,3 +190,4 @@ macro_rules! primitives_operations {
 }
 
 primitives_operations!(ops => i8, i16, i32, i64, u8, u16, u32, u64,f32, f64 , Number , Variables);
+primitives_operations!(pow => i8, i16, i32, i64, u8, u16, u32, u64,f32, f64);
\ No newline at end of file
@@ -4,6 +4,8 @@ mod sub;
 mod mul;
 mod div;
 mod neg;
+mod pow;
+mod checked;
 mod macro_gen;
 mod combiner;
 
@@ -14,6 +16,8 @@ pub use div::*;
 pub use neg::*;
 pub use macro_gen::*;
 
+pub(crate) use pow::{exponent_of, power_of, root_of};
+
 #[cfg(feature="describe")]
 mod describe;
 
@@ -1,11 +1,9 @@
-use num_notation::{One, Zero};
+use crate::{Term,Expression,ArithmeticOperation,Numeric};
 
-use crate::{Term,Expression,ArithmeticOperation};
+impl<N : Numeric> std::ops::Mul for Term<N> {
+    type Output = Expression<N>;
 
-impl std::ops::Mul for Term {
-    type Output = Expression;
-
-    fn mul(self,other : Term) -> Self::Output {
+    fn mul(self,other : Term<N>) -> Self::Output {
         let mut variables = self.variables;
         for (var,exponent) in other.variables {
             variables.entry(var)
@@ -18,13 +16,18 @@ impl std::ops::Mul for Term {
     }
 }
 
-impl std::ops::Mul<Term> for Expression {
-    type Output = Expression;
+impl<N : Numeric> std::ops::Mul<Term<N>> for Expression<N> {
+    type Output = Expression<N>;
 
-    fn mul(self,other : Term) -> Self::Output {
+    fn mul(self,other : Term<N>) -> Self::Output {
         let expr = match self {
             Expression::Term(term) => term * other,
             Expression::Function(func) => func * other,
+            // powers are kept as they are as (x + 1)^2 * 3 can not be distributed into the base
+            expr @ Expression::Binary { operation : ArithmeticOperation::Pow, .. } => match other.is_numeric_one() {
+                true => expr,
+                false => Expression::new_mal(other.into(),expr)
+            },
             // if operation == ArithmeticOperation::Durch as 3x * (3/x) can be more simpily done as (3x/1) * (3/x) then other solution
             Expression::Binary { operation , left , right } if operation == ArithmeticOperation::Durch => {
                 let lexpr = *left * other;
@@ -32,7 +35,11 @@ impl std::ops::Mul<Term> for Expression {
             },
 
             //  if operation == ArithmeticOperation::Mal as things like 3x(4x * 3) need to be 'evaluted' inside before mal with outside 
-            Expression::Binary { operation, left , right } if operation == ArithmeticOperation::Mal => (*left * *right) * other,
+            //  if the inside can not be evaluated further , like -4((4 + y)^0.5) , the term goes into the left factor to avoid evaluating it forever
+            Expression::Binary { operation : ArithmeticOperation::Mal, left , right } => match *left * *right {
+                Expression::Binary { operation : ArithmeticOperation::Mal, left, right } => Expression::new_mal(*left * other, *right),
+                expr => expr * other
+            },
 
             Expression::Binary { operation , left , right }  => {
                 let lexpr = *left * other.clone();
@@ -46,8 +53,8 @@ impl std::ops::Mul<Term> for Expression {
 }
 
 
-impl std::ops::Mul for Expression {
-    type Output = Expression;
+impl<N : Numeric> std::ops::Mul for Expression<N> {
+    type Output = Expression<N>;
 
     fn mul(self,other : Self) -> Self::Output {
         match (self,other) {
@@ -56,6 +63,10 @@ impl std::ops::Mul for Expression {
             
             (Expression::Term(term),expr @_) | (expr @_,Expression::Term(term)) => expr * term,
 
+            // pow , any
+            (expr1 @ Expression::Binary { operation : ArithmeticOperation::Pow, .. }, expr2) |
+            (expr2, expr1 @ Expression::Binary { operation : ArithmeticOperation::Pow, .. }) => Expression::new_mal(expr2, expr1),
+
             // div , div
             (Expression::Binary { operation : op2, left : left1, right : right1 }, 
                 Expression::Binary { operation : op1, left : left2, right : right2 }
@@ -117,24 +128,24 @@ impl std::ops::Mul for Expression {
 use crate::Function;
 
 #[cfg(feature="function")]
-impl std::ops::Mul<Function > for Function  {
-    type Output = Expression; 
-    fn mul(self, rhs: Function ) -> Self::Output {
+impl<N : Numeric> std::ops::Mul<Function<N>> for Function<N> {
+    type Output = Expression<N>; 
+    fn mul(self, rhs: Function<N>) -> Self::Output {
         // TODO : For cases like f(x) * f(x) maybe output (f(x))^2
         Expression::new_mal(self.into(), rhs.into())
     }
 }
 
 #[cfg(feature="function")]
-impl std::ops::Mul<Term> for Function  {
-    type Output = Expression; 
-    fn mul(self, rhs: Term) -> Self::Output {
+impl<N : Numeric> std::ops::Mul<Term<N>> for Function<N> {
+    type Output = Expression<N>; 
+    fn mul(self, rhs: Term<N>) -> Self::Output {
         if rhs.variables.is_empty() {
             return if rhs.coefficient.is_one() {
                 self.into()
             }
             else if rhs.coefficient.is_zero() {
-                0.0.into()
+                Expression::constant(N::zero())
             }
             else {
                 Expression::new_mal(self.into(),rhs.into())
@@ -146,9 +157,9 @@ impl std::ops::Mul<Term> for Function  {
 }
 
 #[cfg(feature="function")]
-impl std::ops::Mul<Function > for Expression {
-    type Output = Expression; 
-    fn mul(self, rhs: Function) -> Self::Output {
+impl<N : Numeric> std::ops::Mul<Function<N>> for Expression<N> {
+    type Output = Expression<N>; 
+    fn mul(self, rhs: Function<N>) -> Self::Output {
         if let Expression::Term(value) = self  {
             return rhs * value
         }
@@ -238,7 +249,7 @@ mod expr {
         
         let result = expr1 - expr2;
 
-        check_expression_str(result,"1 + 3x(4x)");
+        check_expression_str(result,"1 - 3x(4x)");
     }
 
     #[test]
@@ -1,8 +1,8 @@
 use std::ops::Neg;
 
-use crate::{Term,Expression,ArithmeticOperation};
+use crate::{Term,Expression,ArithmeticOperation,Numeric};
 
-impl Neg for Term {
+impl<N : Numeric> Neg for Term<N> {
     type Output = Self;
 
     fn neg(mut self) -> Self {
@@ -11,7 +11,7 @@ impl Neg for Term {
     }
 }
 
-impl Neg for Expression {
+impl<N : Numeric> Neg for Expression<N> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {     
@@ -19,8 +19,9 @@ impl Neg for Expression {
             Expression::Term(term) => Expression::new_term(-term),
             Expression::Binary { operation , left , right } if operation == ArithmeticOperation::Plus => Expression::new_binary(ArithmeticOperation::Minus ,-*left,*right),
             Expression::Binary { operation , left , right } if operation == ArithmeticOperation::Minus => Expression::new_binary(ArithmeticOperation::Plus,-*left,-*right),
+            Expression::Binary { ref operation , .. } if operation == &ArithmeticOperation::Pow => Expression::new_minus(Expression::constant(N::zero()), self),
             Expression::Binary { operation , left , right } => Expression::new_binary(operation,-*left,-*right),
-            Expression::Function { ..  } => Expression::new_minus(0.into(), self),
+            Expression::Function { ..  } => Expression::new_minus(Expression::constant(N::zero()), self),
         }
     }
 }
@@ -29,10 +30,10 @@ impl Neg for Expression {
 use crate::Function;
 
 #[cfg(feature="function")]
-impl Neg for Function  {
-    type Output = Expression; 
+impl<N : Numeric> Neg for Function<N> {
+    type Output = Expression<N>; 
     fn neg(self) -> Self::Output {
-        Expression::new_minus(0.into(), self.into())
+        Expression::new_minus(Expression::constant(N::zero()), self.into())
     }
 }
 
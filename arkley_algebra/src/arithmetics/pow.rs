@@ -0,0 +1,388 @@
+use num_notation::{Number, Pow, One, Zero, fraction::Ratio};
+
+use crate::{Term, Expression, ArithmeticOperation, AlgebraError, Numeric, raise, MAX_EXPANDED_EXPONENT};
+use crate::manipulation::{number_to_f64, EPSILON};
+
+/// Returns the exponent as a whole number if it is one (eg `3` but not `0.5` or `-2`)
+fn as_natural(exponent : &Number) -> Option<u32> {
+    let value = number_to_f64(exponent);
+    match value >= 0.0 && value.fract() == 0.0 && value <= u32::MAX as f64 {
+        true => Some(value as u32),
+        false => None
+    }
+}
+
+/// Returns the exponent if sums raised to it are expanded , so it is a whole number up to [MAX_EXPANDED_EXPONENT]
+pub(super) fn as_expandable(exponent : &Number) -> Option<u32> {
+    as_natural(exponent).filter(|n| *n <= MAX_EXPANDED_EXPONENT)
+}
+
+/// Whether taking the `n`th root of a negative number stays real (so `n` is odd)
+fn is_odd_root(n : &Number) -> bool {
+    as_natural(n).is_some_and(|n| n % 2 == 1)
+}
+
+/// Whether the `n`th root of `a^exponent` is `|a|^(exponent/n)` rather than `a^(exponent/n)` , like `√((x + 1)^2) = |x + 1|`.
+///
+/// These roots are kept as they are , which is how [crate::Radical] writes `|x|` as `(x^2)^0.5`
+fn needs_absolute(exponent : &Number,n : &Number) -> bool {
+    let is_even = |value : f64| value.fract() == 0.0 && value % 2.0 == 0.0;
+    let (exponent,n) = (number_to_f64(exponent),number_to_f64(n));
+
+    is_even(n) && is_even(exponent) && !is_even(exponent / n)
+}
+
+/// Calculates the `n`th root of a number, keeping the sign for odd roots of negative numbers
+///
+/// **Note** : `None` is returned if the numeric type can not represent the root (like `√2` for integers)
+pub(crate) fn root_of<N : Numeric>(number : N,n : &Number) -> Option<N> {
+    let value = number.to_float();
+    let index = number_to_f64(n);
+
+    let root = match value < 0.0 && is_odd_root(n) {
+        true => -(-value).powf(1.0 / index),
+        false => value.powf(1.0 / index)
+    };
+
+    // So that things like √9 result in 3 instead of 2.9999999999999996
+    let rounded = root.round();
+    match (root - rounded).abs() < EPSILON {
+        true => N::from_float(rounded),
+        false => N::from_float(root)
+    }
+}
+
+/// Converts an exponent into the numeric type of the coefficients , writing it as a fraction like `1/2`
+/// if the numeric type can not represent it (like `0.5` for integers)
+pub(crate) fn exponent_of<N : Numeric>(exponent : &Number) -> Expression<N> {
+    if let Some(exponent) = N::from_number(exponent.clone()) {
+        return Expression::constant(exponent);
+    }
+
+    let value = number_to_f64(exponent);
+    let fraction = Ratio::<i64>::approximate_float(value)
+        .and_then(|ratio| Some((N::from_i64(*ratio.numer())?,N::from_i64(*ratio.denom())?)));
+
+    match fraction {
+        Some((numer,denom)) => Expression::new_durch(Expression::constant(numer),Expression::constant(denom)),
+        // Only infinity and `NaN` can not be written as a fraction , so they are written as `±1/0` and `0/0`
+        None => {
+            let numer = match value {
+                _ if value > 0.0 => N::one(),
+                _ if value < 0.0 => -N::one(),
+                _ => N::zero()
+            };
+            Expression::new_durch(Expression::constant(numer),Expression::constant(N::zero()))
+        }
+    }
+}
+
+/// Raises the number to the power , keeping it as a power like `2^(-1)` if the numeric type can not represent the result
+pub(crate) fn power_of<N : Numeric>(base : N,exponent : &Number) -> Expression<N> {
+    match raise(base.clone(),exponent) {
+        Some(value) => Expression::constant(value),
+        None => Expression::new_pow(Expression::constant(base),exponent_of(exponent))
+    }
+}
+
+impl<N : Numeric> Pow<Number> for Term<N> {
+    type Output = Expression<N>;
+
+    /// Raises the term to the power using the exponent laws so `(2x^2y)^3 = 8x^6y^3`
+    ///
+    /// **Note** : If the numeric type can not represent the power of the coefficient (like `2^(-1)` for integers or `(-y)^0.5`)
+    /// the term is kept as a power , as are variables which would have to be written as `|x|` like `x^2` in `(x^2)^0.5`
+    fn pow(mut self,exponent : Number) -> Self::Output {
+        if exponent.is_zero() {
+            return Expression::constant(N::one());
+        }
+
+        let (absolute,variables) = std::mem::take(&mut self.variables).into_iter()
+            .partition::<crate::Variables,_>(|(_,value)| needs_absolute(value,&(Number::one() / exponent.clone())));
+        self.variables = variables;
+
+        if !absolute.is_empty() {
+            let power = Expression::new_pow(Term::new_with_variable(N::one(),absolute).into(),exponent_of(&exponent));
+
+            return match self.is_numeric_one() {
+                true => power,
+                false => Expression::new_mal(self.pow(exponent),power)
+            };
+        }
+
+        // Repeated multiplication keeps fractions exact
+        let Some(coefficient) = raise(self.coefficient.clone(),&exponent) else {
+            return match self.variables.is_empty() {
+                true => power_of(self.coefficient,&exponent),
+                false => Expression::new_pow(self.into(),exponent_of(&exponent))
+            };
+        };
+        self.coefficient = coefficient;
+
+        for value in self.variables.values_mut() {
+            *value *= exponent.clone();
+        }
+
+        self.into()
+    }
+}
+
+impl<N : Numeric> Pow<Expression<N>> for Term<N> {
+    type Output = Expression<N>;
+
+    fn pow(self,exponent : Expression<N>) -> Self::Output {
+        Expression::from(self).pow(exponent)
+    }
+}
+
+impl<N : Numeric> Term<N> {
+    /// Calculates the `n`th root of the term by taking the root of the coefficient and dividing the exponent of each variable by `n`
+    ///
+    /// **Note** : If the coefficient is negative and `n` is even (or the numeric type can not represent the root) the root is kept
+    /// as a power of `1/n` , as are variables which would have to be written as `|x|` like `x^2` in `√(4x^2) = 2((x^2)^0.5)`.
+    /// [AlgebraError::DivisionByZero] is returned for `n = 0` , as the exponent `1/n` is undefined
+    pub fn nth_root(self,n : Number) -> Result<Expression<N>,AlgebraError<N>> {
+        match n.is_zero() {
+            true => Err(AlgebraError::DivisionByZero(Expression::constant(N::one()))),
+            false => Ok(self.root(n))
+        }
+    }
+
+    /// Calculates the `n`th root of the term for `n != 0` , see [Term::nth_root]
+    fn root(mut self,n : Number) -> Expression<N> {
+        if self.coefficient.is_negative() && !is_odd_root(&n) {
+            return Expression::new_pow(self.into(),exponent_of(&(Number::one() / n)));
+        }
+
+        let (absolute,variables) = std::mem::take(&mut self.variables).into_iter()
+            .partition::<crate::Variables,_>(|(_,exponent)| needs_absolute(exponent,&n));
+        self.variables = variables;
+
+        if !absolute.is_empty() {
+            let root = Expression::new_pow(Term::new_with_variable(N::one(),absolute).into(),exponent_of(&(Number::one() / n.clone())));
+
+            return match self.is_numeric_one() {
+                true => root,
+                false => Expression::new_mal(self.root(n),root)
+            };
+        }
+
+        let Some(coefficient) = root_of(self.coefficient.clone(),&n) else {
+            return Expression::new_pow(self.into(),exponent_of(&(Number::one() / n)));
+        };
+        self.coefficient = coefficient;
+
+        for value in self.variables.values_mut() {
+            *value /= n.clone();
+        }
+
+        self.into()
+    }
+
+    /// Calculates the square root of the term, see [Term::nth_root]
+    pub fn sqrt(self) -> Expression<N> {
+        self.root(Number::Decimal(2.0))
+    }
+}
+
+impl<N : Numeric> Pow<Number> for Expression<N> {
+    type Output = Expression<N>;
+
+    /// Raises the expression to the power, expanding sums using the binomial theorem if the exponent is a whole number
+    fn pow(self,exponent : Number) -> Self::Output {
+        if exponent.is_zero() {
+            return Expression::constant(N::one());
+        }
+
+        if exponent.is_one() {
+            return self;
+        }
+
+        match self {
+            Expression::Term(term) => term.pow(exponent),
+            Expression::Function(func) => Expression::new_pow(func.into(),exponent_of(&exponent)),
+            // (ab)^n = a^n * b^n
+            Expression::Binary { operation : ArithmeticOperation::Mal , left , right } =>
+                left.pow(exponent.clone()) * right.pow(exponent),
+            // (a/b)^n = a^n / b^n
+            Expression::Binary { operation : ArithmeticOperation::Durch , left , right } =>
+                left.pow(exponent.clone()) / right.pow(exponent),
+            // (a^m)^n = a^(mn) , unless it is |a|^(mn) like ((x + 1)^2)^0.5 which is kept as it is
+            Expression::Binary { operation : ArithmeticOperation::Pow , ref right , .. } if matches!(right.as_ref(),
+                Expression::Term(term) if term.variables.is_empty() && needs_absolute(&term.coefficient.to_number(),&(Number::one() / exponent.clone()))
+            ) => Expression::new_pow(self,exponent_of(&exponent)),
+            Expression::Binary { operation : ArithmeticOperation::Pow , left , right } =>
+                left.pow(*right * exponent_of(&exponent)),
+            Expression::Binary { operation , left , right } => as_expandable(&exponent)
+                .and_then(|n| Expression::binomial_expansion(operation.clone(),(*left).clone(),(*right).clone(),n))
+                .unwrap_or_else(|| Expression::new_pow(Expression::new_binary(operation,*left,*right),exponent_of(&exponent)))
+        }
+    }
+}
+
+impl<N : Numeric> Pow<Expression<N>> for Expression<N> {
+    type Output = Expression<N>;
+
+    fn pow(self,exponent : Expression<N>) -> Self::Output {
+        match exponent {
+            Expression::Term(term) if term.variables.is_empty() => self.pow(term.coefficient.to_number()),
+            _ => match self {
+                Expression::Binary { operation : ArithmeticOperation::Pow , left , right } =>
+                    Expression::new_pow(*left,*right * exponent),
+                _ => Expression::new_pow(self,exponent)
+            }
+        }
+    }
+}
+
+/// A term `C(n,k) * a^(n-k) * b^k` of a binomial expansion , stored as `(C(n,k),a^(n-k),b^k)`
+pub(super) type BinomialTerm<N> = (N,Expression<N>,Expression<N>);
+
+impl<N : Numeric> Expression<N> {
+    /// Calculates the `n`th root of the expression.
+    ///
+    /// Products and quotients have the root taken of each part and powers have their exponent divided by `n`,
+    /// anything else is kept as a power of `1/n`. Even roots of even powers like `((x + 1)^2)^0.5` are kept too ,
+    /// as they are `|x + 1|` rather than `x + 1`.
+    /// [AlgebraError::DivisionByZero] is returned for `n = 0` , as the exponent `1/n` is undefined
+    pub fn nth_root(self,n : Number) -> Result<Expression<N>,AlgebraError<N>> {
+        match n.is_zero() {
+            true => Err(AlgebraError::DivisionByZero(Expression::constant(N::one()))),
+            false => Ok(self.root(n))
+        }
+    }
+
+    /// Calculates the `n`th root of the expression for `n != 0` , see [Expression::nth_root]
+    fn root(self,n : Number) -> Expression<N> {
+        match self {
+            Expression::Term(term) => term.root(n),
+            Expression::Binary { operation : ArithmeticOperation::Mal , left , right } =>
+                left.root(n.clone()) * right.root(n),
+            Expression::Binary { operation : ArithmeticOperation::Durch , left , right } =>
+                left.root(n.clone()) / right.root(n),
+            Expression::Binary { operation : ArithmeticOperation::Pow , ref right , .. } if matches!(right.as_ref(),
+                Expression::Term(term) if term.variables.is_empty() && needs_absolute(&term.coefficient.to_number(),&n)
+            ) => Expression::new_pow(self,exponent_of(&(Number::one() / n))),
+            Expression::Binary { operation : ArithmeticOperation::Pow , left , right } =>
+                left.pow(*right * exponent_of(&(Number::one() / n))),
+            _ => Expression::new_pow(self,exponent_of(&(Number::one() / n)))
+        }
+    }
+
+    /// Calculates the square root of the expression, see [Expression::nth_root]
+    pub fn sqrt(self) -> Expression<N> {
+        self.root(Number::Decimal(2.0))
+    }
+
+    /// Returns the terms `C(n,k) * a^(n-k) * b^k` of `(a ± b)^n` for `k = 0..=n`
+    ///
+    /// **Note** : `None` is returned if the numeric type can not represent a binomial coefficient (like on overflow)
+    pub(super) fn binomial_terms(operation : ArithmeticOperation,left : Expression<N>,right : Expression<N>,n : u32) -> Option<Vec<BinomialTerm<N>>> {
+        let right = match operation == ArithmeticOperation::Minus {
+            true => -right,
+            false => right
+        };
+
+        let mut coefficient = N::one();
+        let mut terms = Vec::new();
+
+        for k in 0..=n {
+            let a = left.clone().pow(Number::Decimal((n - k) as f64));
+            let b = right.clone().pow(Number::Decimal(k as f64));
+            terms.push((coefficient.clone(),a,b));
+
+            // C(n,k + 1) = C(n,k) * (n - k)/(k + 1) , which is always a whole number
+            coefficient = coefficient.exact_mul(&N::from_u32(n - k)?)?.exact_div(&N::from_u32(k + 1)?)?;
+        }
+
+        Some(terms)
+    }
+
+    /// Expands `(a ± b)^n` using the binomial theorem , `n` is at most [MAX_EXPANDED_EXPONENT]
+    fn binomial_expansion(operation : ArithmeticOperation,left : Expression<N>,right : Expression<N>,n : u32) -> Option<Expression<N>> {
+        Expression::binomial_terms(operation,left,right,n)?
+            .into_iter()
+            .map(|(coefficient,a,b)| (a * Term::new(coefficient)) * b)
+            .reduce(|acc,expr| acc + expr)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Variables;
+
+    fn term(coeff : f64,variables : &[(char,f64)]) -> Term {
+        let variables : Variables = variables.iter().map(|(c,e)| (*c,Number::Decimal(*e))).collect();
+        Term::new_with_variable(Number::Decimal(coeff),variables)
+    }
+
+    #[test]
+    fn term_exponent_laws() {
+        let result = term(2.0,&[('x',2.0),('y',1.0)]).pow(Number::Decimal(3.0));
+        assert_eq!(result.to_string(),"8x^6y^3");
+
+        let result = term(5.0,&[('x',1.0)]).pow(Number::Decimal(0.0));
+        assert_eq!(result.to_string(),"1");
+    }
+
+    #[test]
+    fn term_roots() {
+        assert_eq!(term(9.0,&[('x',4.0)]).sqrt().to_string(),"3x^2");
+        assert_eq!(term(-8.0,&[('x',3.0)]).nth_root(Number::Decimal(3.0)).unwrap().to_string(),"-2x");
+        assert_eq!(term(-4.0,&[]).sqrt().to_string(),"(-4)^0.5");
+        assert_eq!(term(4.0,&[('x',2.0),('y',4.0)]).sqrt().to_string(),"2y^2((x^2)^0.5)");
+        assert_eq!(term(1.0,&[('x',2.0)]).nth_root(Number::Decimal(4.0)).unwrap().to_string(),"(x^2)^0.25");
+        assert!(matches!(term(1.0,&[('x',1.0)]).nth_root(Number::Decimal(0.0)),Err(AlgebraError::DivisionByZero(_))));
+    }
+
+    #[test]
+    fn fractional_powers_of_negative_numbers() {
+        assert_eq!(term(-1.0,&[('y',1.0)]).pow(Number::Decimal(0.5)).to_string(),"(-1y)^0.5");
+        assert_eq!(term(-8.0,&[]).pow(Number::Decimal(1.0 / 3.0)).to_string(),"-2");
+        assert_eq!(term(-4.0,&[]).pow(Number::Decimal(0.5)).to_string(),"(-4)^0.5");
+
+        let quotient = Expression::new_durch(term(1.0,&[('y',1.0)]).into(),term(-2.0,&[]).into());
+        assert_eq!(quotient.pow(Number::Decimal(0.5)).to_string(),"y^0.5/(-2)^0.5");
+    }
+
+    #[test]
+    fn binomial_expansion() {
+        let expr = Expression::new_plus(term(1.0,&[('x',1.0)]).into(),term(1.0,&[]).into());
+        assert_eq!(expr.pow(Number::Decimal(2.0)).to_string(),"1 + 2x + x^2");
+
+        let expr = Expression::new_minus(term(1.0,&[('x',1.0)]).into(),term(2.0,&[]).into());
+        assert_eq!(expr.pow(Number::Decimal(3.0)).to_string(),"-8 + 12x - 6x^2 + x^3");
+    }
+
+    #[test]
+    fn powers_of_products_and_powers() {
+        let expr = Expression::new_durch(term(2.0,&[('x',1.0)]).into(),term(1.0,&[('y',1.0)]).into());
+        assert_eq!(expr.pow(Number::Decimal(2.0)).to_string(),"4x^2/y^2");
+
+        let sum = Expression::new_plus(term(1.0,&[('x',1.0)]).into(),term(1.0,&[]).into());
+        let expr = sum.clone().pow(Expression::from('y'));
+        assert_eq!(expr.to_string(),"(x + 1)^(y)");
+        assert_eq!(expr.sqrt().to_string(),"(x + 1)^(0.5y)");
+
+        assert_eq!(sum.clone().pow(Number::Decimal(0.5)).to_string(),"(x + 1)^0.5");
+
+        // √((x + 1)^2) = |x + 1|
+        let squared = Expression::new_pow(sum.clone(),Expression::from(2));
+        assert_eq!(squared.clone().sqrt().to_string(),"((x + 1)^2)^0.5");
+        let fourth = Expression::new_pow(sum.clone(),Expression::from(4));
+        assert_eq!(fourth.sqrt().to_string(),"1 + 2x + x^2");
+
+        // as does raising them to the power of 0.5
+        assert_eq!(squared.pow(Number::Decimal(0.5)).to_string(),"((x + 1)^2)^0.5");
+        assert_eq!(term(1.0,&[('x',2.0)]).pow(Number::Decimal(0.5)).to_string(),"(x^2)^0.5");
+        assert_eq!(term(9.0,&[('x',2.0),('y',4.0)]).pow(Number::Decimal(0.5)).to_string(),"3y^2((x^2)^0.5)");
+    }
+
+    #[test]
+    fn large_exponents_are_not_expanded() {
+        let sum = Expression::new_plus(term(1.0,&[('x',1.0)]).into(),term(1.0,&[]).into());
+        assert_eq!(sum.pow(Number::Decimal(1000.0)).to_string(),"(x + 1)^1000");
+    }
+}
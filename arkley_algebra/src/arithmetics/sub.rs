@@ -1,9 +1,9 @@
-use crate::{Term,Expression};
+use crate::{Term,Expression,Numeric};
 
-impl std::ops::Sub for Term {
-    type Output = Expression;
+impl<N : Numeric> std::ops::Sub for Term<N> {
+    type Output = Expression<N>;
 
-    fn sub(self,other : Term) -> Self::Output {
+    fn sub(self,other : Term<N>) -> Self::Output {
         if self.is_combinable_with(&other) {
             let coefficient = self.coefficient - other.coefficient;
             let variables = self.variables;
@@ -14,26 +14,26 @@ impl std::ops::Sub for Term {
 }
 
 
-impl std::ops::Sub for Expression {
-    type Output = Expression;
+impl<N : Numeric> std::ops::Sub for Expression<N> {
+    type Output = Expression<N>;
 
-    fn sub(self,other : Expression) -> Self::Output {
+    fn sub(self,other : Expression<N>) -> Self::Output {
         Expression::new_minus(self,other).combine_terms()
     }
 }
 
-impl std::ops::Sub<Term> for Expression {
-    type Output = Expression;
+impl<N : Numeric> std::ops::Sub<Term<N>> for Expression<N> {
+    type Output = Expression<N>;
 
-    fn sub(self,other : Term) -> Self::Output {
+    fn sub(self,other : Term<N>) -> Self::Output {
         Expression::new_minus(self,other.into()).combine_terms()
     }
 }
 
 #[cfg(feature="function")]
-impl std::ops::Sub<Term> for Function  {
-    type Output = Expression; 
-    fn sub(self, rhs: Term) -> Self::Output {
+impl<N : Numeric> std::ops::Sub<Term<N>> for Function<N> {
+    type Output = Expression<N>; 
+    fn sub(self, rhs: Term<N>) -> Self::Output {
         Expression::new_minus(self.into(),rhs.into())
     }
 }
@@ -43,11 +43,11 @@ impl std::ops::Sub<Term> for Function  {
 use crate::Function;
 
 #[cfg(feature="function")]
-impl std::ops::Sub<Function> for Function  {
-    type Output = Expression; 
-    fn sub(self, rhs: Function ) -> Self::Output {
+impl<N : Numeric> std::ops::Sub<Function<N>> for Function<N> {
+    type Output = Expression<N>; 
+    fn sub(self, rhs: Function<N>) -> Self::Output {
         match self.same(&rhs) && self.arguments_empty(&rhs) {
-            true => 0.0.into(),
+            true => Expression::constant(N::zero()),
             false => Expression::new_minus(
                 self.into(), 
                 rhs.into()
@@ -57,12 +57,12 @@ impl std::ops::Sub<Function> for Function  {
 }
 
 #[cfg(feature="function")]
-impl std::ops::Sub<Function > for Expression {
-    type Output = Expression; 
-    fn sub(self, rhs: Function ) -> Self::Output {
+impl<N : Numeric> std::ops::Sub<Function<N>> for Expression<N> {
+    type Output = Expression<N>; 
+    fn sub(self, rhs: Function<N>) -> Self::Output {
         match self {
             Self::Function(ref func) if func.arguments_empty(&rhs) => match func.same(&rhs) {
-                true => Expression::new_minus(0.0.into(), self.into()),
+                true => Expression::new_minus(Expression::constant(N::zero()), self.into()),
                 false => Expression::new_minus(self.into(),rhs.into()),
             },
             Self::Function(_) => Expression::new_minus(self.into(),rhs.into()),
@@ -1,17 +1,19 @@
 use num_notation::Number;
 
 use crate::{
-    Term, ArithmeticOperation, Variables, Function
+    Term, ArithmeticOperation, Variables, Function, Numeric
 };
 
 /// An enum representing a mathematical expression.
 ///
 /// The `Expression` enum allows building complex mathematical expressions
+///
+/// Like [Term] the coefficients are [Number]s by default but can be any type implementing [Numeric].
 #[derive(Clone,Hash)]
 #[cfg_attr(test, derive(PartialEq))]
-pub enum Expression {
+pub enum Expression<N = Number> {
     /// Represents a basic unit in a mathematical expression.
-    Term(Term),
+    Term(Term<N>),
 
     /// Represents a binary operation between two expressions.
     ///
@@ -19,14 +21,14 @@ pub enum Expression {
     /// and right operands as boxed expressions.
     Binary {
         /// - `operation`: The type of mathematical operation being performed, such as
-        ///    addition, subtraction, multiplication, or division. It is of type `ArithmeticOperation`.
+        ///    addition, subtraction, multiplication, division or exponentiation. It is of type `ArithmeticOperation`.
         operation: ArithmeticOperation,
 
         /// - `left`: The left operand of the binary operation, represented as a boxed `Expression`.
-        left: Box<Expression>,
+        left: Box<Expression<N>>,
 
         /// - `right`: The right operand of the binary operation, also represented as a boxed `Expression`.
-        right: Box<Expression>,
+        right: Box<Expression<N>>,
     },
 
     /// Represents a mathematical expression that corresponds to a function.
@@ -37,20 +39,20 @@ pub enum Expression {
     /// - `name`: The name of the function, represented as a string. This can be used to
     ///   identify the specific mathematical function being applied.
     #[cfg(feature="function")]
-    Function(Function)
+    Function(Function<N>)
 }
 
 // To create Self
-impl Expression {
+impl<N> Expression<N> {
     /// Create a new `Expression` containing a single `Term`.
     ///
     /// The `new_term` function wraps the provided `Term` into an `Expression::Term` variant.
-    pub const fn new_term(term: Term) -> Self {
+    pub const fn new_term(term: Term<N>) -> Self {
         Expression::Term(term)
     }
 
     #[inline]
-    pub(crate) fn new_binary(operation: ArithmeticOperation,left: Expression,right: Expression) -> Self {
+    pub(crate) fn new_binary(operation: ArithmeticOperation,left: Expression<N>,right: Expression<N>) -> Self {
         Expression::Binary { operation , left : Box::new(left) , right : Box::new(right) }
     }
 
@@ -58,7 +60,7 @@ impl Expression {
     ///
     /// The `new_plus` function constructs an `Expression` with the `Expression::Plus` variant,
     /// combining two expressions as operands in an addition operation (`+`).
-    pub fn new_plus(left: Expression, right: Expression) -> Self {
+    pub fn new_plus(left: Expression<N>, right: Expression<N>) -> Self {
         Self::new_binary(ArithmeticOperation::Plus,left,right)
     }
 
@@ -66,7 +68,7 @@ impl Expression {
     ///
     /// The `new_minus` function constructs an `Expression` with the `Expression::Minus` variant,
     /// combining two expressions as operands in a subtraction operation (`-`).
-    pub fn new_minus(left: Expression, right: Expression) -> Self {
+    pub fn new_minus(left: Expression<N>, right: Expression<N>) -> Self {
         Self::new_binary(ArithmeticOperation::Minus,left,right)
     }
 
@@ -74,7 +76,7 @@ impl Expression {
     ///
     /// The `new_mal` function constructs an `Expression` with the `Expression::Mal` variant,
     /// combining two expressions as operands in a multiplication operation (`*`).
-    pub fn new_mal(left: Expression, right: Expression) -> Self {
+    pub fn new_mal(left: Expression<N>, right: Expression<N>) -> Self {
         Self::new_binary(ArithmeticOperation::Mal,left,right)
     }
 
@@ -82,29 +84,44 @@ impl Expression {
     ///
     /// The `new_durch` function constructs an `Expression` with the `Expression::Durch` variant,
     /// combining two expressions as operands in a division operation (`/`).
-    pub fn new_durch(left: Expression, right: Expression) -> Self {
+    pub fn new_durch(left: Expression<N>, right: Expression<N>) -> Self {
         Self::new_binary(ArithmeticOperation::Durch,left,right)
     }
 
+    /// Create a new `Expression` representing the exponentiation of two expressions.
+    ///
+    /// The `new_pow` function constructs an `Expression` with the `Expression::Pow` variant,
+    /// raising the `base` to the power of the `exponent` (`^`).
+    pub fn new_pow(base: Expression<N>, exponent: Expression<N>) -> Self {
+        Self::new_binary(ArithmeticOperation::Pow,base,exponent)
+    }
+
     /// Creates a new `Expression` representing a mathematical function.
     ///
     /// This function creates a new `Expression` of the `Function` variant with the provided function name
     ///
     #[cfg(feature="function")]
-    pub fn new_function(func : Function) -> Self {
+    pub fn new_function(func : Function<N>) -> Self {
         Self::Function(func)
     }
 }
 
-impl From<Term> for Expression {
-    fn from(value : Term) -> Self {
+impl<N : Numeric> Expression<N> {
+    /// Creates a new `Expression` containing a constant
+    pub(crate) fn constant(value : N) -> Self {
+        Expression::new_term(Term::new(value))
+    }
+}
+
+impl<N> From<Term<N>> for Expression<N> {
+    fn from(value : Term<N>) -> Self {
         Expression::new_term(value)
     }
 }
 
-impl From<Number> for Expression {
-    fn from(value : Number) -> Self {
-        Expression::new_term(value.into())
+impl<N : Numeric> From<N> for Expression<N> {
+    fn from(value : N) -> Self {
+        Expression::new_term(Term::new(value))
     }
 }
 
@@ -120,8 +137,8 @@ impl From<char> for Expression {
     }
 }
 
-impl From<Function> for Expression {
-    fn from(value: Function) -> Self {
+impl<N> From<Function<N>> for Expression<N> {
+    fn from(value: Function<N>) -> Self {
         Self::new_function(value)
     }
 }
@@ -140,25 +157,22 @@ macro_rules! from {
 
 from!(u8,u16,u32,u64,i8,i16,i32,i64,f32,f64,usize);
 
-impl std::fmt::Display for Expression {
+impl<N : Numeric> std::fmt::Display for Expression<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f,"{:?}",self)
     }
 }
 
-impl std::fmt::Debug for Expression {
+impl<N : Numeric> std::fmt::Debug for Expression<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         use ArithmeticOperation::*;
         match self {
             Expression::Term(term) => write!(f, "{term}"),
             #[cfg(feature="function")]
             Expression::Function(func) => write!(f,"{func}"),
-            Expression::Binary { operation , left , right } 
-                if operation == &Plus => write!(f,"{left} + {right}"),
-            Expression::Binary { operation , left , right } 
-                if operation == &Minus => write!(f,"{left} - {right}"),
-            Expression::Binary { operation , left , right } 
-                if operation == &Mal => {
+            Expression::Binary { operation : Plus , left , right } => write!(f,"{left} + {right}"),
+            Expression::Binary { operation : Minus , left , right } => write!(f,"{left} - {right}"),
+            Expression::Binary { operation : Mal , left , right } => {
                     // Note : NO FUCKING CLUE WHY IT WORKS EXCEPT I WROTE IT AND NOW HAVE NO CLUE
                     let s = format!("{left}");
 
@@ -175,22 +189,32 @@ impl std::fmt::Debug for Expression {
                         _ => write!(f,"({right})")
                     }
                 },
-            Expression::Binary { operation , left , right } 
-                if operation == &Durch => {
+            Expression::Binary { operation : Durch , left , right } => {
                     match **left {
-                        Expression::Term(_) => write!(f,"{left}"),
+                        Expression::Term(_) | Expression::Binary { operation : Pow, .. } => write!(f,"{left}"),
                         _ => write!(f,"({left})")
                     }?;
 
                     write!(f,"/")?;
 
                     match **right {
-                        Expression::Term(_) => write!(f,"{right}"),
+                        Expression::Term(_) | Expression::Binary { operation : Pow, .. } => write!(f,"{right}"),
                         _ => write!(f,"({right})")
                     }
                 },
+            Expression::Binary { operation : Pow , left , right } => {
+                    match **left {
+                        Expression::Term(ref term) if term.is_plain_base() => write!(f,"{left}"),
+                        _ => write!(f,"({left})")
+                    }?;
 
-            Expression::Binary { .. } => unreachable!()
+                    write!(f,"^")?;
+
+                    match **right {
+                        Expression::Term(ref term) if term.variables.is_empty() && !term.coefficient.is_negative() => write!(f,"{right}"),
+                        _ => write!(f,"({right})")
+                    }
+                },
         }
     }
 }
@@ -1,21 +1,58 @@
 use std::{fmt::{Debug, Display}, collections::{BTreeMap, HashMap}};
 
-use crate::{Expression, manipulation::{VariableSubstitution, Find}};
+use num_notation::Number;
+
+use crate::{Expression, Term, Numeric, manipulation::{VariableSubstitution, Evaluate, Find, number_to_f64}};
+
+/// A function known without being defined in a context , with its name and how it is evaluated for a number
+type BuiltIn = (&'static str,fn(f64) -> f64);
+
+/// The functions which are known without being defined in a context , `log` is the logarithm to base 10
+const BUILT_IN : [BuiltIn;9] = [
+    ("sin",f64::sin),
+    ("cos",f64::cos),
+    ("tan",f64::tan),
+    ("asin",f64::asin),
+    ("acos",f64::acos),
+    ("atan",f64::atan),
+    ("ln",f64::ln),
+    ("log",f64::log10),
+    ("exp",f64::exp),
+];
+
+/// Evaluates one of [BUILT_IN] , staying unevaluated if the argument is not a number or the result is not finite
+fn evaluate_built_in(function : Function) -> Expression {
+    let value = match function.arguments.values().flatten().next().map(|argument| argument.clone().evaluate().find()) {
+        Some(Expression::Term(term)) if term.variables.is_empty() => number_to_f64(&term.coefficient),
+        _ => return Expression::Function(function)
+    };
+
+    let result = BUILT_IN.iter()
+        .find(|(name,_)| *name == function.name)
+        .map(|(_,evaluate)| evaluate(value));
+
+    match result {
+        Some(result) if result.is_finite() => Term::new(Number::Decimal(result)).into(),
+        _ => Expression::Function(function)
+    }
+}
 
 /// Represents a mathematical function with a name and a set of arguments.
 // TODO : Create functions to validate arguemnts given to functions + corrcet number + corrcte ones etc
 #[derive(Clone,Hash)]
-pub struct Function {
+pub struct Function<N = Number> {
     // TODO : Rn use string into future change to maybe Cow<'a,str>
     pub(crate) name: String,//&'static str,
-    pub(crate) arguments : FunctionArguments,
-    pub(crate) expression : Option<Box<Expression>>,
-    pub(crate) closure : fn(Function) -> Expression,
+    pub(crate) arguments : FunctionArguments<N>,
+    // The parameters in the order they were defined as `arguments` is sorted by name
+    pub(crate) parameters : Vec<char>,
+    pub(crate) expression : Option<Box<Expression<N>>>,
+    pub(crate) closure : fn(Function<N>) -> Expression<N>,
 }
 
-impl Eq for Function {}
+impl<N : PartialEq> Eq for Function<N> {}
 
-impl PartialEq for Function {
+impl<N : PartialEq> PartialEq for Function<N> {
     // If both instances are expressions (expr),
     // and if both expressions are terms (term),
     // then it tries to compare them for equality (eq).
@@ -52,15 +89,15 @@ impl PartialEq for Function {
 }
 
 
-pub(crate) type FunctionArguments = BTreeMap<char,Option<Expression>>;
+pub(crate) type FunctionArguments<N = Number> = BTreeMap<char,Option<Expression<N>>>;
 
-impl Display for Function {
+impl<N : Numeric> Display for Function<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "{name}({args})",
             name = self.name,
-            args = self.arguments.iter().map(|(c,v)| match v {
+            args = self.parameters().into_iter().map(|c| match self.arguments.get(&c).and_then(Option::as_ref) {
                 None => c.to_string(),
                 Some(value) => value.to_string()
             })
@@ -70,71 +107,104 @@ impl Display for Function {
     }
 }
 
-impl Debug for Function {
+impl<N : Numeric> Debug for Function<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f,"{:?}",self)
+        write!(f,"{self}")
     }
 }
 
-impl Function {
+impl<N> Function<N> {
     /// Gets the name of the function.
     pub fn name(&self) -> &str {
         &self.name
     }
 
     /// Gets the arguments of the function.
-    pub const fn arguments(&self) -> &FunctionArguments {
+    pub const fn arguments(&self) -> &FunctionArguments<N> {
         &self.arguments
     }
 
+    /// Gets the parameters of the function in the order they were defined , so `y, x` for `f(y, x) = y - x`
+    pub fn parameters(&self) -> Vec<char> {
+        match self.parameters.len() == self.arguments.len() {
+            true => self.parameters.clone(),
+            // parameters added to `arguments` directly are ordered by name
+            false => self.arguments.keys().copied().collect()
+        }
+    }
+
     /// Gets the expression of the function, if available.
-    pub const fn expression(&self) -> &Option<Box<Expression>> {
+    pub const fn expression(&self) -> &Option<Box<Expression<N>>> {
         &self.expression
     }
 
     /// Gets a mutable reference to the expression of the function, if available.
-    pub fn expression_mut(&mut self) -> &mut Option<Box<Expression>> {
+    pub fn expression_mut(&mut self) -> &mut Option<Box<Expression<N>>> {
         &mut self.expression
     }
 
 }
 
-impl Function {
+impl<N : Numeric> Function<N> {
     /// Creates a new `Function` instance with a default closure function.
     ///
     /// This constructor creates a `Function` with the given `name`, `expression`, and `arguments`.
     /// It sets a default closure that processes the function's expression and arguments.
-    pub fn new_default(name: String,expression : Expression,arguments : FunctionArguments) -> Self {
-        let closure = |func: Function| {
-            let mut arguments : HashMap<char,Expression> = func.arguments.into_iter()
-                .filter(|(_,expr)| expr.is_some())
-                .map(|(k,expr)| (k,expr.unwrap()))
+    pub fn new_default(name: String,expression : Expression<N>,arguments : FunctionArguments<N>) -> Self {
+        let closure = |func: Function<N>| {
+            let Some(expression) = func.expression.clone() else {
+                return Expression::Function(func);
+            };
+
+            let mut arguments : HashMap<char,Expression<N>> = func.arguments.into_iter()
+                .filter_map(|(k,expr)| expr.map(|expr| (k,expr)))
                 .collect();
 
-            func.expression.unwrap().replace_variables(&mut arguments).find()
+            expression.replace_variables(&mut arguments).find()
         };
 
-        Self { name , arguments , expression : Some(Box::new(expression)) , closure }
+        let parameters = arguments.keys().copied().collect();
+        Self { name , arguments , parameters , expression : Some(Box::new(expression)) , closure }
     }
     
     /// Creates a new `Function` instance with a custom closure function.
-    pub const fn new(name: String,closure: fn(Function) -> Expression) -> Self {
-        Self { name , arguments : BTreeMap::new() , expression : None , closure }
+    pub const fn new(name: String,closure: fn(Function<N>) -> Expression<N>) -> Self {
+        Self { name , arguments : BTreeMap::new() , parameters : Vec::new() , expression : None , closure }
+    }
+
+    /// Sets the order of the parameters , which the arguments of a call are bound to
+    pub(crate) fn with_parameters(mut self, parameters: Vec<char>) -> Self {
+        self.parameters = parameters;
+        self
     }
 
     /// Sets the expression of the function.
-    pub fn with_expression(mut self, expression: Expression) -> Self {
+    pub fn with_expression(mut self, expression: Expression<N>) -> Self {
         self.expression = Some(Box::new(expression));
         self
     }
 }
 
 impl Function {
-    pub(crate) fn same(&self,other : &Function) -> bool {
+    /// Checks if this is one of the built-in functions like `sin` or `ln` , and not a function of a context with the same name
+    pub(crate) fn is_built_in(&self) -> bool {
+        self.expression.is_none() && BUILT_IN.iter().any(|(name,_)| *name == self.name)
+    }
+
+    /// Creates the built-in function called `name` , like `sin` or `ln` , which is evaluated once its argument `x` is a number
+    pub(crate) fn built_in(name : &str,argument : Option<Expression>) -> Self {
+        let mut function = Self::new(name.to_string(),evaluate_built_in);
+        function.arguments.insert('x',argument);
+        function
+    }
+}
+
+impl<N> Function<N> {
+    pub(crate) fn same(&self,other : &Function<N>) -> bool {
         self.name == other.name
     }
 
-    pub(crate) fn arguments_empty(&self,other : &Function) -> bool {
+    pub(crate) fn arguments_empty(&self,other : &Function<N>) -> bool {
         self.arguments.is_empty() == other.arguments.is_empty()
     }
 }
\ No newline at end of file
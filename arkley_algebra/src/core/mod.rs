@@ -1,8 +1,10 @@
 mod expression;
 mod term;
 mod op;
+mod numeric;
 
 pub use self::op::*;
+pub use self::numeric::*;
 pub use self::term::*;
 pub use self::expression::*;
 
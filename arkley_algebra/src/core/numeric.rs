@@ -0,0 +1,214 @@
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{AddAssign, SubAssign, MulAssign, DivAssign};
+
+use num_notation::{Number, Num, Signed, Pow, FromPrimitive, ToPrimitive, CheckedMul, CheckedDiv, fraction::Ratio};
+
+use crate::root_of;
+use crate::manipulation::{number_to_f64, EPSILON};
+
+/// A trait for the numeric types which can be used as the coefficient of a [crate::Term] (and so an [crate::Expression]).
+///
+/// By default [Number] is used but any type implementing this trait can be plugged in (eg big integers ,
+/// arbitrary-precision decimals or a custom fixed-point type) so that `Term<MyNumber>` respects its arithmetic.
+///
+/// Exponents of variables are always stored as [Number], so the provided methods are only used as a fallback
+/// for converting between exponents and coefficients or when the exponent is not a whole number (like `x^0.5`).
+/// If the numeric type can not represent a result exactly (like `2/3` or `√2` for integers) it is kept symbolic instead.
+///
+/// # Example
+/// ```
+/// use arkley_algebra::{Term, Expression, Numeric};
+///
+/// // i64 implements Numeric so this is exact
+/// let term : Term<i64> = Term::new(4);
+/// let expr : Expression<i64> = term.clone() * Term::new(3);
+/// assert_eq!(expr.to_string(), "12");
+///
+/// // which can not be written as an integer
+/// let expr : Expression<i64> = Term::new(2) / Term::new(3);
+/// assert_eq!(expr.to_string(), "2/3");
+/// ```
+pub trait Numeric : Num + Signed + Clone + Eq + PartialOrd + Hash + Display + Debug
+    + AddAssign + SubAssign + MulAssign + DivAssign
+    + FromPrimitive + ToPrimitive {
+    /// Converts the number into a `f64` , which may lose precision
+    fn to_float(&self) -> f64 {
+        self.to_f64().unwrap_or(f64::NAN)
+    }
+
+    /// Creates the number from a `f64`
+    ///
+    /// **Note** : `None` is returned if the value can not be represented exactly , like `NaN` or `0.5` for integers
+    fn from_float(value : f64) -> Option<Self> {
+        Self::from_f64(value).filter(|number| number.to_float() == value)
+    }
+
+    /// Converts the number into a [Number] , which may lose precision
+    fn to_number(&self) -> Number {
+        Number::Decimal(self.to_float())
+    }
+
+    /// Creates the number from a [Number] , see [Numeric::from_float]
+    fn from_number(value : Number) -> Option<Self> {
+        Self::from_float(number_to_f64(&value))
+    }
+
+    /// Raises the number to a power which is not a whole number , see [Numeric::from_float]
+    fn powf(self,exponent : Number) -> Option<Self> {
+        Self::from_float(self.to_float().powf(number_to_f64(&exponent)))
+    }
+
+    /// Multiplies the numbers , returning `None` if the result can not be represented (like on overflow)
+    fn exact_mul(&self,other : &Self) -> Option<Self> {
+        Some(self.clone() * other.clone())
+    }
+
+    /// Divides the numbers , returning `None` if the result can not be represented exactly (like `2/3` for integers)
+    fn exact_div(&self,other : &Self) -> Option<Self> {
+        let quotient = self.clone() / other.clone();
+        (quotient.clone() * other.clone() == *self).then_some(quotient)
+    }
+}
+
+impl Numeric for Number {
+    fn to_float(&self) -> f64 {
+        number_to_f64(self)
+    }
+
+    fn from_float(value : f64) -> Option<Self> {
+        Some(value.into())
+    }
+
+    fn to_number(&self) -> Number {
+        self.clone()
+    }
+
+    fn from_number(value : Number) -> Option<Self> {
+        Some(value)
+    }
+
+    fn powf(self,exponent : Number) -> Option<Self> {
+        Some(self.pow(exponent)).filter(|result| number_to_f64(result).is_finite())
+    }
+
+    fn exact_div(&self,other : &Self) -> Option<Self> {
+        Some(self.clone() / other.clone())
+    }
+}
+
+macro_rules! impl_numeric {
+    ($($t : ty),*) => {
+        $(
+            impl Numeric for $t {
+                fn exact_mul(&self,other : &Self) -> Option<Self> {
+                    CheckedMul::checked_mul(self,other)
+                }
+
+                fn exact_div(&self,other : &Self) -> Option<Self> {
+                    let quotient = CheckedDiv::checked_div(self,other)?;
+                    (CheckedMul::checked_mul(&quotient,other)? == *self).then_some(quotient)
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric!(i8, i16, i32, i64, i128, isize, Ratio<i32>, Ratio<i64>);
+
+/// The largest whole exponent which is expanded by repeated multiplication , like `(x + 1)^3` into `x^3 + 3x^2 + 3x + 1`.
+///
+/// Larger powers like `(x + 1)^1000` are kept as they are , as expanding them would create a term for every power.
+pub(crate) const MAX_EXPANDED_EXPONENT : u32 = 64;
+
+/// Raises `base` to the power of `exponent` , using repeated multiplication for whole exponents (up to [MAX_EXPANDED_EXPONENT])
+/// so that the result stays exact , the `n`th root for odd `1/n` exponents and [Numeric::powf] otherwise
+///
+/// **Note** : `None` is returned if `base` is `0` and `exponent` is negative or the numeric type can not represent the result
+pub(crate) fn raise<N : Numeric>(base : N,exponent : &Number) -> Option<N> {
+    let value = number_to_f64(exponent);
+
+    if value < 0.0 && base.is_zero() {
+        return None;
+    }
+
+    if value.fract() != 0.0 || value.abs() > f64::from(MAX_EXPANDED_EXPONENT) {
+        // Odd roots of negative numbers are real , like (-8)^(1/3) = -2
+        let index = 1.0 / value;
+        if base.is_negative() && (index - index.round()).abs() < EPSILON && index.round() % 2.0 == 1.0 {
+            return root_of(base,&Number::Decimal(index.round()));
+        }
+
+        return base.powf(exponent.clone());
+    }
+
+    let result = (0..value.abs() as u32).try_fold(N::one(),|acc,_| acc.exact_mul(&base))?;
+
+    match value < 0.0 {
+        true => N::one().exact_div(&result),
+        false => Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Term, Expression, Variables};
+    use crate::manipulation::{Evaluate, EvaluteWithValues, Find};
+
+    fn term<N>(coefficient : N,variables : &[(char,f64)]) -> Term<N> {
+        let variables : Variables = variables.iter().map(|(c,e)| (*c,Number::Decimal(*e))).collect();
+        Term::new_with_variable(coefficient,variables)
+    }
+
+    #[test]
+    fn integer_backend() {
+        let result : Expression<i64> = term(2,&[('x',1.0)]) + term(3,&[('x',1.0)]);
+        assert_eq!(result.to_string(),"5x");
+
+        let result = term(4i64,&[('x',2.0)]) * term(-3,&[('x',1.0)]);
+        assert_eq!(result.to_string(),"-12x^3");
+
+        let expr = Expression::new_plus(term(2i64,&[('x',2.0)]).into(),term(1,&[]).into());
+        let result = expr.evaluate_with_single_value(&'x',3).find();
+        assert_eq!(result,Expression::constant(19));
+    }
+
+    #[test]
+    fn rational_backend_stays_exact() {
+        // x^2/3 with x = 1/2
+        let expr = Expression::new_durch(term(Ratio::from_integer(1i64),&[('x',2.0)]).into(),Expression::constant(Ratio::from_integer(3)));
+        let result = expr.evaluate_with_single_value(&'x',Ratio::new(1,2)).find();
+        assert_eq!(result,Expression::constant(Ratio::new(1,12)));
+
+        let result = Expression::new_durch(Expression::constant(Ratio::from_integer(1i64)),Expression::constant(Ratio::from_integer(3))).evaluate().find();
+        assert_eq!(result.to_string(),"1/3");
+    }
+
+    #[test]
+    fn raise_to_whole_and_negative_exponents() {
+        assert_eq!(raise(Ratio::new(2i64,3),&Number::Decimal(3.0)),Some(Ratio::new(8,27)));
+        assert_eq!(raise(Ratio::new(2i64,3),&Number::Decimal(-2.0)),Some(Ratio::new(9,4)));
+        assert_eq!(raise(7i64,&Number::Decimal(0.0)),Some(1));
+    }
+
+    #[test]
+    fn unrepresentable_results() {
+        assert_eq!(raise(0i64,&Number::Decimal(-1.0)),None);
+        assert_eq!(raise(Ratio::<i64>::from_integer(0),&Number::Decimal(-1.0)),None);
+        assert_eq!(raise(2i64,&Number::Decimal(-1.0)),None);
+        assert_eq!(raise(10i64,&Number::Decimal(30.0)),None);
+        assert_eq!(raise(-4i64,&Number::Decimal(0.5)),None);
+        assert_eq!(i64::from_float(f64::NAN),None);
+        assert_eq!(i64::from_float(2.0),Some(2));
+
+        // the results are kept symbolic instead
+        assert_eq!(Term::<i64>::new(-4).pow(Number::Decimal(0.5)).to_string(),"(-4)^(1/2)");
+        assert_eq!(Term::<i64>::new(2).pow(Number::Decimal(-1.0)).to_string(),"2^(-1)");
+        assert_eq!((Term::<i64>::new(2) / Term::new(3)).to_string(),"2/3");
+        assert_eq!((Term::<i64>::new(6) / Term::new(3)).to_string(),"2");
+        assert!(Term::<i64>::new(0).checked_pow(Number::Decimal(-1.0)).is_err());
+        assert!(Term::<Ratio<i64>>::new(Ratio::from_integer(0)).checked_pow(Number::Decimal(-1.0)).is_err());
+    }
+}
@@ -2,7 +2,7 @@ use strum::Display;
 /// An enum representing basic arithmetic operations.
 ///
 /// The `ArithmeticOperation` enum includes variants for common arithmetic operations
-/// such as addition, subtraction, multiplication, and division.
+/// such as addition, subtraction, multiplication, division and exponentiation.
 #[derive(PartialEq,Clone,Display,Hash)]
 pub enum ArithmeticOperation {
     /// Represents the addition operation (+).
@@ -19,7 +19,11 @@ pub enum ArithmeticOperation {
 
     /// Represents the division operation (/).
     #[strum(serialize = "/")]
-    Durch
+    Durch,
+
+    /// Represents the exponentiation operation (^).
+    #[strum(serialize = "^")]
+    Pow
 }
 
 impl std::fmt::Debug for ArithmeticOperation {
@@ -1,6 +1,8 @@
 use std::collections::BTreeMap;
 use num_notation::{Number, One};
 
+use super::Numeric;
+
 /// Represents a collection of variables, each associated with a numerical value.
 /// The `Variables` type is an alias for `BTreeMap<char, Number>`.
 pub type Variables = BTreeMap<char,Number>;
@@ -9,36 +11,63 @@ pub type Variables = BTreeMap<char,Number>;
 ///
 /// A `Term` is a basic unit in a mathematical expression. It consists of a coefficient and variables represented
 /// as `BTreeMap<char,Number>` .
+///
+/// The coefficient is a [Number] by default but can be any type implementing [Numeric].
 #[derive(Clone,Hash)]
 #[cfg_attr(test, derive(PartialEq))]
-pub struct Term {
+pub struct Term<N = Number> {
     /// The coefficient of the term.
-    pub(crate) coefficient: Number,
+    pub(crate) coefficient: N,
 
     /// The variables and their exponents in the term.
     pub(crate) variables: Variables,
 }
 
-impl Term {
+impl<N> Term<N> {
     /// Creates new instance of Term using coefficient and variable
-    pub const fn new_with_variable(coefficient: Number,variables: Variables) -> Self {
+    pub const fn new_with_variable(coefficient: N,variables: Variables) -> Self {
         Self { coefficient , variables }
     }
 
     /// Creates new instance of Term using coefficient
-    pub const fn new(coefficient: Number) -> Self {
+    pub const fn new(coefficient: N) -> Self {
         Self { coefficient , variables : Variables::new() }
     }
 
     /// Method to create a Term with a single variable.
-    pub fn create_single_variable_term(coeff: Number, var: char, exp: Number) -> Self {
+    pub fn create_single_variable_term(coeff: N, var: char, exp: Number) -> Self {
         let variables = Variables::from([(var,exp)]);
         Term::new_with_variable(coeff, variables)
     }
+
+    /// Gets the coefficient of the term.
+    pub const fn coefficient(&self) -> &N {
+        &self.coefficient
+    }
+
+    /// Gets the variables and their exponents of the term.
+    pub const fn variables(&self) -> &Variables {
+        &self.variables
+    }
+}
+
+impl<N : Numeric> Term<N> {
+    /// Whether the term can be written as the base of a power without brackets (eg `2` or `x` but not `2x` or `x^2`)
+    pub(crate) fn is_plain_base(&self) -> bool {
+        match self.variables.is_empty() {
+            true => !self.coefficient.is_negative(),
+            false => self.coefficient.is_one() && self.variables.len() == 1 && self.variables.values().all(One::is_one)
+        }
+    }
+
+    /// Converts the coefficient into another [Numeric] type using `f`
+    pub fn map_coefficient<M,F : FnOnce(N) -> M>(self,f : F) -> Term<M> {
+        Term::new_with_variable(f(self.coefficient),self.variables)
+    }
 }
 
-impl From<Number> for Term {
-    fn from(value : Number) -> Self {
+impl<N : Numeric> From<N> for Term<N> {
+    fn from(value : N) -> Self {
         Term::new(value)
     }
 }
@@ -56,7 +85,7 @@ impl From<char> for Term {
 }
 
 
-impl std::fmt::Display for Term {
+impl<N : Numeric> std::fmt::Display for Term<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self.coefficient.is_one() {
             false => write!(f, "{}", self.coefficient)?,
@@ -68,7 +97,7 @@ impl std::fmt::Display for Term {
 
         for (name,exponent) in self.variables.iter() {
             write!(f,"{name}")?;
-            if exponent > &1 {
+            if !exponent.is_one() {
                 write!(f,"^{exponent}")?;
             }
         }
@@ -76,7 +105,7 @@ impl std::fmt::Display for Term {
     }
 }
 
-impl std::fmt::Debug for Term {
+impl<N : Numeric> std::fmt::Debug for Term<N> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f,"{self}")
     }
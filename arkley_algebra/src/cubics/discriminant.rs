@@ -0,0 +1,81 @@
+use num_notation::Num;
+
+use crate::manipulation::Find;
+
+use super::IntegerCubic;
+
+/// A utility struct for calculating the discriminant of a cubic equation.
+///
+/// The sign of the discriminant tells the nature of the roots , see [super::Nature].
+///
+/// It is created by [super::Cubic::roots] or [super::Cubic::discriminant] method.
+#[derive(Debug,Clone)]
+pub struct Discriminant<T>(pub(super) T);
+
+impl<T> Find for Discriminant<IntegerCubic<T>> where T : Num + Clone + From<u8> {
+    type Output = T;
+
+    // D = 18abcd - 4b^3d + b^2c^2 - 4ac^3 - 27a^2d^2
+    fn find(self) -> T {
+        let IntegerCubic { a, b, c, d } = self.0;
+
+        let n = |value : u8| T::from(value);
+
+        n(18) * a.clone() * b.clone() * c.clone() * d.clone()
+            - n(4) * b.clone() * b.clone() * b.clone() * d.clone()
+            + b.clone() * b * c.clone() * c.clone()
+            - n(4) * a.clone() * c.clone() * c.clone() * c
+            - n(27) * a.clone() * a * d.clone() * d
+    }
+}
+
+impl<T,O> From<T> for Discriminant<IntegerCubic<O>> where T : Into<IntegerCubic<O>> , O : Num + Clone {
+    fn from(value: T) -> Self {
+        Self(value.into())
+    }
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    Describe,
+    Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+impl<T> Describe for Discriminant<IntegerCubic<T>> where T : Num + Clone + From<u8> + std::fmt::Display {
+    fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        let haupteil = resources.lookup_single_language::<&str>(lang, "discriminant-integercubic.haupttiel", None)?;
+
+        let nebenteil = {
+            let mut args = std::collections::HashMap::from([
+                ("a",self.0.a.to_string().into()),
+                ("b",self.0.b.to_string().into()),
+                ("c",self.0.c.to_string().into()),
+                ("d",self.0.d.to_string().into()),
+            ]);
+
+            let result = self.find();
+            args.insert("result", result.to_string().into());
+
+            resources.lookup_single_language(lang, "discriminant-integercubic.subsitiute",Some(&args))?
+        };
+
+        vec![format!("{haupteil}\n{nebenteil}")].into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_of_discriminant() {
+        // (x - 1)(x - 2)(x - 3)
+        assert_eq!(Discriminant(IntegerCubic::new(1,-6,11,-6)).find(),4);
+        // (x - 1)^2 (x + 2)
+        assert_eq!(Discriminant(IntegerCubic::new(1,0,-3,2)).find(),0);
+        // x^3 + x + 1
+        assert_eq!(Discriminant(IntegerCubic::new(1,0,1,1)).find(),-31);
+    }
+}
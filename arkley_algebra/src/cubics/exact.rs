@@ -0,0 +1,184 @@
+use num_notation::{Num, Zero, CheckedAdd, CheckedMul, fraction::{Ratio, Integer}};
+
+use crate::manipulation::Find;
+use crate::radicals::Surd;
+use crate::quadratics::{self, IntegerQuadratic, Quadratic};
+
+use super::*;
+
+/// A utility struct for finding the exact roots of a cubic equation as surds so `1 + √2`
+/// instead of `2.414213562373095`.
+///
+/// A rational root is searched for using the rational root theorem , after which the remaining quadratic
+/// is solved exactly. Cube roots can not be written as surds , so this only works if the cubic has a rational root.
+///
+/// It is created by [Roots::exact] method.
+#[derive(Debug,Clone)]
+pub struct ExactRoots<T>(pub(super) T);
+
+impl<T> Roots<IntegerCubic<T>> where T : Num + Clone {
+    /// Finds the roots using surds instead of floating point numbers , see [ExactRoots]
+    pub fn exact(self) -> ExactRoots<IntegerCubic<T>> {
+        ExactRoots(self.0.0)
+    }
+}
+
+impl<T> Find for ExactRoots<IntegerCubic<T>> where T : Num + Clone + TryInto<Surd> {
+    /// `None` is returned if any of the coefficients can not be written as an exact rational number (eg `NaN`)
+    /// or if there is no rational root (eg `x^3 - 2`) , in which case [Roots] can be used to find the roots numerically
+    type Output = Option<Nature<Surd>>;
+
+    fn find(self) -> Self::Output {
+        let IntegerCubic { a, b, c, d } = self.0;
+        let coefficients = [to_rational(a)?,to_rational(b)?,to_rational(c)?,to_rational(d)?];
+
+        let root = rational_root(&coefficients)?;
+
+        let mut roots = exact_quadratic_roots(&deflate(&coefficients,root))?;
+        roots.push(Surd::from(root));
+        roots.sort_by(|r1,r2| r1.to_f64().total_cmp(&r2.to_f64()));
+
+        let mut roots = roots.into_iter();
+
+        Some(match (roots.next(),roots.next(),roots.next()) {
+            (Some(r1),Some(r2),Some(r3)) => Nature::from_three_roots(r1,r2,r3),
+            (Some(root),_,_) => Nature::OneRealRoot(root),
+            _ => return None
+        })
+    }
+}
+
+/// Converts the coefficient into an exact fraction , returning `None` if it is not rational (eg `NaN`)
+pub(crate) fn to_rational<T : TryInto<Surd>>(value : T) -> Option<Ratio<i64>> {
+    value.try_into().ok()?.as_rational()
+}
+
+/// Scales the rational coefficients by the lowest common multiple of their denominators so all of them are integers
+fn integer_coefficients(coefficients : &[Ratio<i64>]) -> Option<Vec<i64>> {
+    let lcm = coefficients.iter().fold(1i64,|acc,coefficient| acc.lcm(coefficient.denom()));
+
+    coefficients.iter()
+        .map(|coefficient| coefficient.numer().checked_mul(&(lcm / coefficient.denom())))
+        .collect()
+}
+
+/// Evaluates the polynomial (highest power first) at `x` , returning `None` on overflow
+fn evaluate(coefficients : &[i64],x : Ratio<i64>) -> Option<Ratio<i64>> {
+    coefficients.iter().try_fold(Ratio::zero(),|acc : Ratio<i64>,coefficient| acc.checked_mul(&x)?.checked_add(&Ratio::from_integer(*coefficient)))
+}
+
+fn divisors(n : u64) -> Vec<u64> {
+    let mut divisors = Vec::new();
+    let mut factor = 1;
+
+    while factor * factor <= n {
+        if n.is_multiple_of(factor) {
+            divisors.push(factor);
+            if factor * factor != n {
+                divisors.push(n / factor);
+            }
+        }
+        factor += 1;
+    }
+
+    divisors
+}
+
+/// Coefficients larger than this are not searched for rational roots as finding their divisors would take too long
+pub(crate) const MAX_SEARCHED_COEFFICIENT : u64 = 1 << 40;
+
+/// Finds a rational root of the polynomial (highest power first) using the rational root theorem ,
+/// so every rational root `p/q` has `p` dividing the constant term and `q` dividing the leading coefficient
+pub(crate) fn rational_root(coefficients : &[Ratio<i64>]) -> Option<Ratio<i64>> {
+    let integers = integer_coefficients(coefficients)?;
+
+    let constant = *integers.last()?;
+    let leading = *integers.first()?;
+
+    if constant == 0 {
+        return Some(Ratio::zero());
+    }
+
+    if constant.unsigned_abs() > MAX_SEARCHED_COEFFICIENT || leading.unsigned_abs() > MAX_SEARCHED_COEFFICIENT {
+        return None;
+    }
+
+    let denominators = divisors(leading.unsigned_abs());
+
+    divisors(constant.unsigned_abs()).into_iter()
+        .flat_map(|p| denominators.iter().map(move |q| Ratio::new(p as i64,*q as i64)))
+        .flat_map(|candidate| [candidate,-candidate])
+        .find(|candidate| evaluate(&integers,*candidate).is_some_and(|value| value.is_zero()))
+}
+
+/// Divides the polynomial (highest power first) by `x - root` using synthetic division
+pub(crate) fn deflate(coefficients : &[Ratio<i64>],root : Ratio<i64>) -> Vec<Ratio<i64>> {
+    let mut quotient : Vec<Ratio<i64>> = Vec::with_capacity(coefficients.len().saturating_sub(1));
+
+    for coefficient in coefficients.iter().take(coefficients.len().saturating_sub(1)) {
+        let previous = quotient.last().map_or(Ratio::zero(),|last| last * root);
+        quotient.push(previous + coefficient);
+    }
+
+    quotient
+}
+
+/// Solves the quadratic `[a,b,c]` (where `a != 0`) exactly , returning its real roots with multiplicity
+pub(crate) fn exact_quadratic_roots(coefficients : &[Ratio<i64>]) -> Option<Vec<Surd>> {
+    let [a,b,c] = integer_coefficients(coefficients)?[..] else {
+        return None;
+    };
+
+    Some(match IntegerQuadratic::new(a,b,c).roots().exact().find().ok()? {
+        quadratics::Nature::DistinctRealRoots(r1,r2) => vec![r1,r2],
+        quadratics::Nature::SingleRoot(root) => vec![root.clone(),root],
+        quadratics::Nature::NoRealRoots => vec![]
+    })
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    Describe, Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+impl<T> Describe for ExactRoots<IntegerCubic<T>> where T : Num + Clone + From<u8> + std::fmt::Display + TryInto<Surd> {
+    fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        let discriminant_description = Discriminant(self.0.clone()).describe(resources,lang)?;
+
+        let description = super::roots::describe_nature(self.find()?,resources,lang)?;
+
+        let mut vec = Vec::new();
+
+        vec.extend(discriminant_description);
+        vec.push(description);
+
+        vec.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roots(a : i32,b : i32,c : i32,d : i32) -> Option<Vec<String>> {
+        let nature = IntegerCubic::new(a,b,c,d).roots().exact().find()?;
+        Some(nature.into_vec().into_iter().map(|root| root.to_string()).collect())
+    }
+
+    #[test]
+    fn rational_and_irrational_roots() {
+        // (x - 3)(x^2 - 2x - 1)
+        assert_eq!(roots(1,-5,5,3).unwrap(),["1 - √2","1 + √2","3"]);
+        // (2x - 1)(x + 1)^2
+        assert_eq!(roots(2,3,0,-1).unwrap(),["-1","-1","1/2"]);
+        // (x - 2)(x^2 + 1)
+        assert_eq!(roots(1,-2,1,-2).unwrap(),["2"]);
+    }
+
+    #[test]
+    fn no_rational_root() {
+        assert!(roots(1,0,0,-2).is_none());
+    }
+}
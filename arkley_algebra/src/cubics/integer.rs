@@ -0,0 +1,108 @@
+use num_notation::{Num, Signed};
+
+use super::*;
+
+/// Represents an integer cubic equation of the form `a*x^3 + b*x^2 + c*x + d`.
+///
+/// This struct allows you to work with cubic equations where `a`, `b`, `c` and `d` can be any numeric type,
+/// such as integers (`i32`, `i64`, etc.) or floating-point numbers (`f32`, `f64`).
+///
+/// **Note** : `a` should not be `0` , otherwise the equation is not cubic
+#[derive(Clone)]
+pub struct IntegerCubic<T> where T: Num + Clone {
+    pub(super) a: T,
+    pub(super) b: T,
+    pub(super) c: T,
+    pub(super) d: T,
+}
+
+impl<T> IntegerCubic<T> where T: Num + Clone {
+    /// Creates a new `IntegerCubic` instance with the provided coefficients.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The coefficient for the cubic term (`a*x^3`).
+    /// * `b` - The coefficient for the quadratic term (`b*x^2`).
+    /// * `c` - The coefficient for the linear term (`c*x`).
+    /// * `d` - The constant term (`d`).
+    ///
+    /// # Returns
+    ///
+    /// A new `IntegerCubic` instance with the given coefficients
+    pub const fn new(a: T,b: T,c: T,d: T) -> Self {
+        Self { a, b, c, d }
+    }
+}
+
+/// Writes `coefficients` (highest power first) as a polynomial in `x` , like `x^3 - 2x + 1 = 0`
+pub(crate) fn write_polynomial<T>(f: &mut std::fmt::Formatter<'_>,coefficients: &[T]) -> std::fmt::Result where T : std::fmt::Display + Signed + Clone {
+    let degree = coefficients.len().saturating_sub(1);
+    let mut first = true;
+
+    for (index,item) in coefficients.iter().enumerate() {
+        if item.is_zero() {
+            continue;
+        }
+
+        let power = degree - index;
+        let x = match power {
+            0 => String::new(),
+            1 => "x".to_string(),
+            _ => format!("x^{power}")
+        };
+
+        let sign = match (first,item.is_negative()) {
+            (true,true) => "-",
+            (true,false) => "",
+            (false,true) => " - ",
+            (false,false) => " + ",
+        };
+
+        let value = item.abs();
+        match value.is_one() && power != 0 {
+            true => write!(f,"{sign}{x}")?,
+            false => write!(f,"{sign}{value}{x}")?
+        }
+
+        first = false;
+    }
+
+    if first {
+        f.write_str("0")?;
+    }
+
+    f.write_str(" = 0")
+}
+
+impl<T> std::fmt::Display for IntegerCubic<T> where T : std::fmt::Display + Signed + Clone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_polynomial(f,&[self.a.clone(),self.b.clone(),self.c.clone(),self.d.clone()])
+    }
+}
+
+impl<T> std::fmt::Debug for IntegerCubic<T> where T : std::fmt::Display + Signed + Clone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+impl<T> Cubic<Self> for IntegerCubic<T> where T: Num + Clone + From<u8> {
+    fn discriminant(self) -> Discriminant<Self> {
+        Discriminant(self)
+    }
+
+    fn roots(self) -> Roots<Self> {
+        Roots(self.discriminant())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(IntegerCubic::new(1,0,-2,1).to_string(),"x^3 - 2x + 1 = 0");
+        assert_eq!(IntegerCubic::new(-2,3,1,0).to_string(),"-2x^3 + 3x^2 + x = 0");
+    }
+}
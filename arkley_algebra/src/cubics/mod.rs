@@ -0,0 +1,41 @@
+mod integer;
+
+mod discriminant;
+mod roots;
+mod exact;
+
+pub use integer::*;
+
+pub use discriminant::*;
+pub use roots::*;
+pub use exact::*;
+
+pub(crate) use roots::{real_roots, scale};
+pub(crate) use exact::{rational_root, deflate, exact_quadratic_roots, to_rational, MAX_SEARCHED_COEFFICIENT};
+
+/// A trait for working with cubic equations and their roots.
+///
+/// The `Cubic` trait mirrors [crate::quadratics::Quadratic] for equations of the form `ax^3 + bx^2 + cx + d = 0`
+/// providing methods for calculating the discriminant and determining the nature of the roots. It allows for
+/// easy extension with custom types.
+pub trait Cubic<T> : Sized {
+    /// Calculates the discriminant of the cubic equation.
+    ///
+    /// The discriminant of a cubic equation `ax^3 + bx^2 + cx + d` is given by
+    /// `D = 18abcd - 4b^3d + b^2c^2 - 4ac^3 - 27a^2d^2`
+    ///
+    /// # Returns
+    ///
+    /// The discriminant value of the cubic equation.
+    fn discriminant(self) -> Discriminant<T>;
+
+    /// Calculates the roots of the cubic equation using the discriminant.
+    ///
+    /// If the discriminant is positive there are three distinct real roots , if it is `0` some roots repeat
+    /// and if it is negative there is one real root (and two complex roots).
+    ///
+    /// # Returns
+    ///
+    /// A `Roots` struct representing the roots of the cubic equation.
+    fn roots(self) -> Roots<T>;
+}
@@ -0,0 +1,218 @@
+use std::f64::consts::PI;
+
+use num_notation::Num;
+
+use crate::manipulation::{Find, tidy, EPSILON};
+
+use super::*;
+
+/// A utility struct for finding the roots of a cubic equation.
+///
+/// The roots are found numerically using Cardano's formula , or the trigonometric method if there
+/// are three distinct real roots. Use [Roots::exact] to find them as surds instead.
+///
+/// It is created by [super::Cubic::roots] method.
+#[derive(Debug,Clone)]
+pub struct Roots<T>(pub(super) Discriminant<T>);
+
+impl<T,O> From<T> for Roots<O> where T : Into<Discriminant<O>> , O : Num + Clone {
+    fn from(value: T) -> Self {
+        Self(value.into())
+    }
+}
+
+/// Represents the nature of the real roots of a cubic equation.
+///
+/// A cubic equation always has at least one real root. The roots are given in ascending order.
+#[derive(Debug)]
+pub enum Nature<T> {
+    /// Indicates that the cubic equation has three distinct real roots (the discriminant is positive).
+    DistinctRealRoots(T,T,T),
+    /// Indicates that the cubic equation has a double root and a single root (in that order).
+    RepeatedRoot(T,T),
+    /// Indicates that the cubic equation has a single root repeated three times.
+    TripleRoot(T),
+    /// Indicates that the cubic equation has one real root , implying two complex roots (the discriminant is negative).
+    OneRealRoot(T),
+}
+
+impl<T> Nature<T> {
+    /// Gets the real roots with multiplicity , so a [Nature::RepeatedRoot] gives its double root twice
+    pub fn into_vec(self) -> Vec<T> where T : Clone {
+        match self {
+            Nature::DistinctRealRoots(r1,r2,r3) => vec![r1,r2,r3],
+            Nature::RepeatedRoot(double,single) => vec![double.clone(),double,single],
+            Nature::TripleRoot(root) => vec![root.clone(),root.clone(),root],
+            Nature::OneRealRoot(root) => vec![root],
+        }
+    }
+
+    /// Classifies three real roots given in ascending order
+    pub(crate) fn from_three_roots(r1 : T,r2 : T,r3 : T) -> Self where T : PartialEq {
+        match (r1 == r2,r2 == r3) {
+            (true,true) => Nature::TripleRoot(r1),
+            (true,false) => Nature::RepeatedRoot(r1,r3),
+            (false,true) => Nature::RepeatedRoot(r2,r1),
+            (false,false) => Nature::DistinctRealRoots(r1,r2,r3)
+        }
+    }
+}
+
+/// Gets the size `max |c_i|^(1/i)` of the roots of a monic polynomial with the coefficients `c_1,c_2,...` after the leading one ,
+/// which is `1` if they are all `0`
+pub(crate) fn scale(coefficients : &[f64]) -> f64 {
+    let k = coefficients.iter()
+        .enumerate()
+        .map(|(index,coefficient)| coefficient.abs().powf(1.0 / (index + 1) as f64))
+        .fold(0.0,f64::max);
+
+    match k > 0.0 && k.is_finite() {
+        true => k,
+        false => 1.0
+    }
+}
+
+/// Finds the real roots of `ax^3 + bx^2 + cx + d` (where `a != 0`)
+pub(crate) fn real_roots(a : f64,b : f64,c : f64,d : f64) -> Nature<f64> {
+    let (b,c,d) = (b / a,c / a,d / a);
+
+    // The roots are found for x = ku where k is about as large as the roots , so that the tolerances
+    // also work for small roots like 0.001 and 0.002 instead of treating them as equal
+    let k = scale(&[b,c,d]);
+    let (b,c,d) = (b / k,c / (k * k),d / (k * k * k));
+
+    // x = t - b/3 gives the depressed cubic t^3 + pt + q
+    let shift = b / 3.0;
+    let p = c - b * b / 3.0;
+    let q = 2.0 * b * b * b / 27.0 - b * c / 3.0 + d;
+
+    let discriminant = -(4.0 * p * p * p + 27.0 * q * q);
+
+    let x = |t : f64| tidy((t - shift) * k);
+
+    if p.abs() < EPSILON && q.abs() < EPSILON {
+        return Nature::TripleRoot(x(0.0));
+    }
+
+    if discriminant.abs() < EPSILON {
+        let (double,single) = (x(-3.0 * q / (2.0 * p)),x(3.0 * q / p));
+        return Nature::RepeatedRoot(double,single);
+    }
+
+    if discriminant < 0.0 {
+        // Cardano's formula
+        let sqrt = (q * q / 4.0 + p * p * p / 27.0).sqrt();
+        return Nature::OneRealRoot(x((-q / 2.0 + sqrt).cbrt() + (-q / 2.0 - sqrt).cbrt()));
+    }
+
+    // Trigonometric method as Cardano's formula would need complex numbers here
+    let r = 2.0 * (-p / 3.0).sqrt();
+    let phi = ((3.0 * q / (2.0 * p)) * (-3.0 / p).sqrt()).clamp(-1.0,1.0).acos() / 3.0;
+
+    let mut roots = [0.0,1.0,2.0].map(|k : f64| x(r * (phi - 2.0 * PI * k / 3.0).cos()));
+    roots.sort_by(f64::total_cmp);
+
+    let [r1,r2,r3] = roots;
+    Nature::from_three_roots(r1,r2,r3)
+}
+
+impl<T> Find for Roots<IntegerCubic<T>> where T : Num + Clone + Into<f64> {
+    type Output = Nature<f64>;
+
+    fn find(self) -> Nature<f64> {
+        let IntegerCubic { a, b, c, d } = self.0.0;
+        real_roots(a.into(),b.into(),c.into(),d.into())
+    }
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    Describe, Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+pub(super) fn describe_nature<T : std::fmt::Display>(nature : Nature<T>,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<String> {
+    use std::collections::HashMap;
+
+    match nature {
+        Nature::DistinctRealRoots(r1,r2,r3) => {
+            let args = HashMap::from([
+                ("r1",r1.to_string().into()),
+                ("r2",r2.to_string().into()),
+                ("r3",r3.to_string().into())
+            ]);
+            resources.lookup_single_language::<&str>(lang, "roots-integercubic.three",Some(&args))
+        },
+        Nature::RepeatedRoot(double,single) => {
+            let args = HashMap::from([
+                ("double",double.to_string().into()),
+                ("single",single.to_string().into())
+            ]);
+            resources.lookup_single_language::<&str>(lang, "roots-integercubic.repeated",Some(&args))
+        },
+        Nature::TripleRoot(root) => {
+            let args = HashMap::from([("root",root.to_string().into())]);
+            resources.lookup_single_language::<&str>(lang, "roots-integercubic.triple",Some(&args))
+        },
+        Nature::OneRealRoot(root) => {
+            let args = HashMap::from([("root",root.to_string().into())]);
+            resources.lookup_single_language::<&str>(lang, "roots-integercubic.one",Some(&args))
+        },
+    }
+}
+
+#[cfg(feature="describe")]
+impl<T> Describe for Roots<IntegerCubic<T>> where T : Num + Clone + From<u8> + Into<f64> + std::fmt::Display {
+    fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        let discriminant_description = self.0.clone().describe(resources,lang)?;
+
+        let description = describe_nature(self.find(),resources,lang)?;
+
+        let mut vec = Vec::new();
+
+        vec.extend(discriminant_description);
+        vec.push(description);
+
+        vec.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roots(a : i32,b : i32,c : i32,d : i32) -> Nature<f64> {
+        IntegerCubic::new(a,b,c,d).roots().find()
+    }
+
+    #[test]
+    fn three_distinct_roots() {
+        assert!(matches!(roots(1,-6,11,-6),Nature::DistinctRealRoots(r1,r2,r3) if (r1,r2,r3) == (1.0,2.0,3.0)));
+        assert!(matches!(roots(2,0,-2,0),Nature::DistinctRealRoots(r1,r2,r3) if (r1,r2,r3) == (-1.0,0.0,1.0)));
+    }
+
+    #[test]
+    fn repeated_roots() {
+        assert!(matches!(roots(1,0,-3,2),Nature::RepeatedRoot(double,single) if (double,single) == (1.0,-2.0)));
+        assert!(matches!(roots(1,-3,3,-1),Nature::TripleRoot(root) if root == 1.0));
+    }
+
+    #[test]
+    fn one_real_root() {
+        // (x - 2)(x^2 + 1)
+        assert!(matches!(roots(1,-2,1,-2),Nature::OneRealRoot(root) if root == 2.0));
+        assert!(matches!(roots(1,0,1,1),Nature::OneRealRoot(root) if (root + 0.6823278038).abs() < 1e-9));
+    }
+
+    #[test]
+    fn small_roots() {
+        // (x - 0.001)(x - 0.002)(x - 0.003)
+        let nature = IntegerCubic::new(1.0,-0.006,0.000011,-0.000000006).roots().find();
+        assert!(matches!(nature,Nature::DistinctRealRoots(r1,r2,r3) if (r1,r2,r3) == (0.001,0.002,0.003)));
+
+        // (x - 0.001)^2 (x - 0.002)
+        let nature = IntegerCubic::new(1.0,-0.004,0.000005,-0.000000002).roots().find();
+        assert!(matches!(nature,Nature::RepeatedRoot(double,single) if (double,single) == (0.001,0.002)));
+    }
+}
@@ -0,0 +1,333 @@
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+use crate::{
+    Equation, Expression, RelationalOperator,
+    manipulation::{Evaluate, Find},
+};
+
+#[cfg(feature="function")]
+use std::collections::HashMap;
+
+#[cfg(feature="function")]
+use num_notation::Number;
+
+#[cfg(feature="function")]
+use crate::{Function, Term};
+
+use super::{RearrangeError, rearrange::SignChanges};
+
+/// Built-in functions which keep the order of their arguments , so they can be applied to both sides of an inequality
+#[cfg(feature="function")]
+const INCREASING_FUNCTIONS : [&str;4] = ["exp","ln","log","atan"];
+
+/// The domains of the built-in functions which are not defined for every number , like `x > 0` for `ln(x)`
+#[cfg(feature="function")]
+const DOMAINS : [(&str,&[(RelationalOperator,f64)]);4] = [
+    ("ln",&[(RelationalOperator::GreaterThan,0.0)]),
+    ("log",&[(RelationalOperator::GreaterThan,0.0)]),
+    ("asin",&[(RelationalOperator::GreaterThanOrEqual,-1.0),(RelationalOperator::LessThanOrEqual,1.0)]),
+    ("acos",&[(RelationalOperator::GreaterThanOrEqual,-1.0),(RelationalOperator::LessThanOrEqual,1.0)]),
+];
+
+impl Equation {
+    /// Swaps both sides of the equation , flipping the relation so `3 < x` becomes `x > 3`
+    pub fn swap_sides(self) -> Self {
+        Self::new(self.right,self.relation.flipped(),self.left)
+    }
+
+    /// Adds the expression to both sides of the equation , so `2x - 3 < 7` becomes `2x < 10` when adding `3`
+    pub fn add_both<T : Into<Expression>>(self,expression : T) -> Self {
+        let expression = expression.into();
+        let left = (self.left + expression.clone()).evaluate().find();
+        let right = (self.right + expression).evaluate().find();
+        Self::new(left,self.relation,right)
+    }
+
+    /// Subtracts the expression from both sides of the equation , so `2x + 3 = 7` becomes `2x = 4` when subtracting `3`
+    pub fn sub_both<T : Into<Expression>>(self,expression : T) -> Self {
+        let expression = expression.into();
+        let left = (self.left - expression.clone()).evaluate().find();
+        let right = (self.right - expression).evaluate().find();
+        Self::new(left,self.relation,right)
+    }
+
+    /// Multiplies both sides of the equation by the expression , flipping an inequality if it is negative
+    ///
+    /// Returns an error of type `RearrangeError::MultipliedByZero` if the expression is `0` and `RearrangeError::UnknownSign`
+    /// if the sign of the expression is not known for an inequality , like `y` in `x < 2`
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{Equation, Expression};
+    ///
+    /// let equation = Equation::try_from("-x/2 < 3").unwrap().mul_both(Expression::from(-2)).unwrap();
+    /// assert_eq!(equation.to_string(),"x > -6");
+    /// ```
+    pub fn mul_both<T : Into<Expression>>(self,expression : T) -> Result<Self,RearrangeError> {
+        let expression = expression.into();
+
+        if expression.is_zero() {
+            return Err(RearrangeError::MultipliedByZero(Box::new(self)));
+        }
+
+        let mut signs = SignChanges::default();
+        signs.record(&expression);
+
+        let left = self.left.checked_mul(expression.clone())?.evaluate().find();
+        let right = self.right.checked_mul(expression)?.evaluate().find();
+
+        Self::new(left,self.relation,right).apply_sign_changes(signs)
+    }
+
+    /// Divides both sides of the equation by the expression , flipping an inequality if it is negative
+    ///
+    /// Returns an error of type `RearrangeError::Algebra` if the expression is `0` and `RearrangeError::UnknownSign`
+    /// if the sign of the expression is not known for an inequality
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{Equation, Expression};
+    ///
+    /// let equation = Equation::try_from("-2x >= 4").unwrap().div_both(Expression::from(-2)).unwrap();
+    /// assert_eq!(equation.to_string(),"x <= -2");
+    /// ```
+    pub fn div_both<T : Into<Expression>>(self,expression : T) -> Result<Self,RearrangeError> {
+        let expression = expression.into();
+
+        let mut signs = SignChanges::default();
+        signs.record(&expression);
+
+        let left = self.left.checked_div(expression.clone())?.evaluate().find();
+        let right = self.right.checked_div(expression)?.evaluate().find();
+
+        Self::new(left,self.relation,right).apply_sign_changes(signs)
+    }
+
+    /// Applies the function to both sides of the equation , so `x^0.5 = 3` becomes `sqrt(x^0.5) = sqrt(3)`
+    ///
+    /// The function has to take exactly one argument. Any function can be applied to an equation using `=` ,
+    /// but other relations only stay true for the built-in increasing functions `exp` , `ln` , `log` and `atan`
+    /// (a function of the context with the same name may not be increasing). Returns an error of type `RearrangeError::FunctionNotApplicable` otherwise.
+    ///
+    /// Built-in functions like `ln` are only defined for some numbers , so the conditions on both sides (like `x > 0` for `ln(x) < ln(3)`)
+    /// are returned with the result. An error of type `RearrangeError::FunctionNotApplicable` is returned if a side is a number outside of the domain.
+    #[cfg(feature="function")]
+    pub fn apply_function(self,function : Function) -> Result<(Self,Vec<Equation>),RearrangeError> {
+        let not_applicable = |equation : Self| RearrangeError::FunctionNotApplicable(Box::new(equation),function.name().to_string());
+        let is_increasing = function.is_built_in() && INCREASING_FUNCTIONS.contains(&function.name());
+
+        let argument = match function.arguments().keys().collect::<Vec<_>>()[..] {
+            [argument] if self.relation == RelationalOperator::Equal || is_increasing => *argument,
+            _ => return Err(not_applicable(self))
+        };
+
+        let domain = DOMAINS.iter()
+            .find(|(name,_)| function.is_built_in() && *name == function.name())
+            .map_or(&[][..],|(_,domain)| *domain);
+
+        let mut conditions = Vec::new();
+        for side in [self.left.clone(),self.right.clone()] {
+            for (relation,limit) in domain {
+                let condition = Self::new(side.clone(),relation.clone(),Term::new(Number::Decimal(*limit)).into());
+
+                // Conditions on numbers like `3 > 0` are checked right away
+                match condition.holds(&HashMap::<char,f64>::new()) {
+                    Some(true) => (),
+                    Some(false) => return Err(not_applicable(self)),
+                    None => conditions.push(condition)
+                }
+            }
+        }
+
+        let apply = |side : Expression| {
+            let mut function = function.clone();
+            function.arguments.insert(argument,Some(side));
+            Expression::Function(function).evaluate().find()
+        };
+
+        let left = apply(self.left);
+        let right = apply(self.right);
+
+        Ok((Self::new(left,self.relation,right),conditions))
+    }
+}
+
+impl<T : Into<Expression>> Add<T> for Equation {
+    type Output = Equation;
+
+    fn add(self,other : T) -> Self::Output {
+        self.add_both(other)
+    }
+}
+
+impl<T : Into<Expression>> Sub<T> for Equation {
+    type Output = Equation;
+
+    fn sub(self,other : T) -> Self::Output {
+        self.sub_both(other)
+    }
+}
+
+impl<T : Into<Expression>> Mul<T> for Equation {
+    type Output = Result<Equation,RearrangeError>;
+
+    fn mul(self,other : T) -> Self::Output {
+        self.mul_both(other)
+    }
+}
+
+impl<T : Into<Expression>> Div<T> for Equation {
+    type Output = Result<Equation,RearrangeError>;
+
+    fn div(self,other : T) -> Self::Output {
+        self.div_both(other)
+    }
+}
+
+impl Neg for Equation {
+    type Output = Equation;
+
+    /// Negates both sides , flipping the relation so `-x < 2` becomes `x > -2`
+    fn neg(self) -> Self::Output {
+        let negate = |side : Expression| (side * Expression::from(-1)).evaluate().find();
+        Self::new(negate(self.left),self.relation.flipped(),negate(self.right))
+    }
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    DescribeAdd, DescribeSub, DescribeMul, DescribeDiv, Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+impl Equation {
+    /// Describes the step from `self` to `result` using the translation `key` , mentioning if the relation was flipped
+    fn describe_step(&self,result : &Equation,key : &str,expression : Option<String>,resources : &StaticLoader,lang : &LanguageIdentifier) -> Option<Steps> {
+        use std::collections::HashMap;
+
+        let mut args = HashMap::from([
+            ("equation",self.to_string().into()),
+            ("result",result.to_string().into()),
+        ]);
+
+        if let Some(expression) = expression {
+            args.insert("expression",expression.into());
+        }
+
+        let mut steps = vec![resources.lookup_single_language(lang,key,Some(&args))?];
+
+        if self.relation != result.relation && key != "both-sides.swap" {
+            let args = HashMap::from([("relation",result.relation.to_string().into())]);
+            steps.push(resources.lookup_single_language(lang,"both-sides.flip",Some(&args))?);
+        }
+
+        Some(steps)
+    }
+
+    /// Describes swapping both sides of the equation
+    pub fn describe_swap_sides(self,resources : &StaticLoader,lang : &LanguageIdentifier) -> Option<Steps> {
+        let result = self.clone().swap_sides();
+        self.describe_step(&result,"both-sides.swap",None,resources,lang)
+    }
+
+    /// Describes applying the function to both sides of the equation , returning `None` if it can not be applied
+    #[cfg(feature="function")]
+    pub fn describe_apply_function(self,function : Function,resources : &StaticLoader,lang : &LanguageIdentifier) -> Option<Steps> {
+        let name = function.name().to_string();
+        let (result,_) = self.clone().apply_function(function).ok()?;
+        self.describe_step(&result,"both-sides.apply",Some(name),resources,lang)
+    }
+}
+
+#[cfg(feature="describe")]
+impl<T : Into<Expression>> DescribeAdd<T> for Equation {
+    fn describe_add(self,other : T,resources : &StaticLoader,lang : &LanguageIdentifier) -> Option<Steps> {
+        let other = other.into();
+        let result = self.clone().add_both(other.clone());
+        self.describe_step(&result,"both-sides.add",Some(other.to_string()),resources,lang)
+    }
+}
+
+#[cfg(feature="describe")]
+impl<T : Into<Expression>> DescribeSub<T> for Equation {
+    fn describe_sub(self,other : T,resources : &StaticLoader,lang : &LanguageIdentifier) -> Option<Steps> {
+        let other = other.into();
+        let result = self.clone().sub_both(other.clone());
+        self.describe_step(&result,"both-sides.sub",Some(other.to_string()),resources,lang)
+    }
+}
+
+#[cfg(feature="describe")]
+impl<T : Into<Expression>> DescribeMul<T> for Equation {
+    fn describe_mul(self,other : T,resources : &StaticLoader,lang : &LanguageIdentifier) -> Option<Steps> {
+        let other = other.into();
+        let result = self.clone().mul_both(other.clone()).ok()?;
+        self.describe_step(&result,"both-sides.mul",Some(other.to_string()),resources,lang)
+    }
+}
+
+#[cfg(feature="describe")]
+impl<T : Into<Expression>> DescribeDiv<T> for Equation {
+    fn describe_div(self,other : T,resources : &StaticLoader,lang : &LanguageIdentifier) -> Option<Steps> {
+        let other = other.into();
+        let result = self.clone().div_both(other.clone()).ok()?;
+        self.describe_step(&result,"both-sides.div",Some(other.to_string()),resources,lang)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn equation(input : &str) -> Equation {
+        Equation::try_from(input).unwrap()
+    }
+
+    #[test]
+    fn add_and_sub() {
+        assert_eq!(equation("2x - 3 < 7").add_both(3).to_string(),"2x < 10");
+        assert_eq!((equation("2x + 3 = 7") - 3).to_string(),"2x = 4");
+        assert_eq!(equation("3 < x").swap_sides().to_string(),"x > 3");
+    }
+
+    #[test]
+    fn mul_and_div() {
+        assert_eq!(equation("2x = 4").div_both(2).unwrap().to_string(),"x = 2");
+        assert_eq!((equation("-2x < 4") / -2).unwrap().to_string(),"x > -2");
+        assert_eq!((equation("x/3 >= 1") * 3).unwrap().to_string(),"x >= 3");
+        assert_eq!((-equation("-x < 2")).to_string(),"x > -2");
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(equation("x < 2").mul_both('y'),Err(RearrangeError::UnknownSign(..))));
+        assert!(matches!(equation("x = 2").mul_both(0),Err(RearrangeError::MultipliedByZero(_))));
+        assert!(matches!(equation("x = 2").div_both(0),Err(RearrangeError::Algebra(_))));
+        assert!(equation("x = 2").mul_both('y').is_ok());
+    }
+
+    #[cfg(feature="function")]
+    #[test]
+    fn apply_function() {
+        use std::collections::BTreeMap;
+
+        let function = |name : &str| Function::new_default(name.into(),'t'.into(),BTreeMap::from([('t',None)]));
+        let apply = |input : &str,function : Function| equation(input).apply_function(function)
+            .map(|(result,conditions)| (result.to_string(),conditions.iter().map(ToString::to_string).collect::<Vec<_>>()));
+
+        assert_eq!(apply("x = 3",function("f")).unwrap().0,"x = 3");
+        assert!(matches!(apply("x < 3",function("f")),Err(RearrangeError::FunctionNotApplicable(..))));
+
+        // Only the built-in functions are known to be increasing , not functions of the context with the same name
+        assert!(matches!(apply("x < 3",function("ln")),Err(RearrangeError::FunctionNotApplicable(..))));
+        assert!(matches!(apply("x < 3",Function::built_in("sqrt",None)),Err(RearrangeError::FunctionNotApplicable(..))));
+
+        let (result,conditions) = apply("x < 3",Function::built_in("ln",None)).unwrap();
+        assert_eq!(result,format!("ln(x) < {}",Number::Decimal(3f64.ln())));
+        assert_eq!(conditions,["x > 0"]);
+
+        assert_eq!(apply("x < 3",Function::built_in("exp",None)).unwrap().1,Vec::<String>::new());
+        assert!(matches!(apply("x = -1",Function::built_in("ln",None)),Err(RearrangeError::FunctionNotApplicable(..))));
+    }
+}
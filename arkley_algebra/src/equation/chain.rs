@@ -0,0 +1,223 @@
+use crate::{
+    Expression, Term, Equation, RelationalOperator,
+    manipulation::{Evaluate, Find, VariableAnalysis, VariableSubstitution, number_to_f64},
+};
+
+use super::RearrangeError;
+
+/// A struct representing a chain of relations between expressions , like `2 < 3x + 1 <= 10` or `-1 < x < 1`
+///
+/// Each relation applies to the expressions directly next to it , so `a < b <= c` means `a < b` and `b <= c`
+#[derive(Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct ChainedRelation {
+    /// The first expression of the chain
+    pub(crate) first : Expression,
+    /// The relations and the expressions following them
+    pub(crate) rest : Vec<(RelationalOperator,Expression)>,
+}
+
+impl ChainedRelation {
+    /// Create a new chain starting with `first` , followed by each relation and expression in `rest`
+    pub const fn new(first : Expression,rest : Vec<(RelationalOperator,Expression)>) -> Self {
+        Self { first , rest }
+    }
+
+    /// Gets the expressions of the chain in order
+    pub fn parts(&self) -> impl Iterator<Item = &Expression> {
+        std::iter::once(&self.first).chain(self.rest.iter().map(|(_,expression)| expression))
+    }
+
+    /// Gets the relations of the chain in order
+    pub fn relations(&self) -> impl Iterator<Item = &RelationalOperator> {
+        self.rest.iter().map(|(relation,_)| relation)
+    }
+
+    /// Splits the chain into the single equations it is made of , so `a < b <= c` into `a < b` and `b <= c`
+    pub fn equations(&self) -> Vec<Equation> {
+        self.parts().zip(self.rest.iter())
+            .map(|(left,(relation,right))| Equation::new(left.clone(),relation.clone(),right.clone()))
+            .collect()
+    }
+
+    /// Converts a chain with a single relation into an [Equation] , returning the chain back otherwise
+    pub fn into_equation(mut self) -> Result<Equation,Self> {
+        match self.rest.len() {
+            1 => {
+                let (relation,right) = self.rest.remove(0);
+                Ok(Equation::new(self.first,relation,right))
+            },
+            _ => Err(self)
+        }
+    }
+
+    /// Checks if every relation of the chain holds after evaluating all expressions
+    ///
+    /// Returns `None` if any expression does not evaluate to a number , like when it still contains variables
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{ChainedRelation, manipulation::{EvaluteWithValues, Find}};
+    ///
+    /// let chain = ChainedRelation::try_from("-1 < 2x <= 4").unwrap();
+    /// assert_eq!(chain.clone().evaluate_with_single_value(&'x',1.0).find().holds(),Some(true));
+    /// assert_eq!(chain.clone().evaluate_with_single_value(&'x',3.0).find().holds(),Some(false));
+    /// assert_eq!(chain.holds(),None);
+    /// ```
+    pub fn holds(&self) -> Option<bool> {
+        let values = self.parts()
+            .map(|part| match part.clone().evaluate().find() {
+                Expression::Term(term) if term.variables.is_empty() => Some(number_to_f64(&term.coefficient)),
+                _ => None
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(values.windows(2).zip(self.relations()).all(|(values,relation)| relation.compare(&values[0],&values[1])))
+    }
+
+    /// Makes the specified term the subject of the part of the chain containing it , applying the same
+    /// operations to all other parts so the chain stays consistent.
+    ///
+    /// So `5 < 3x + 2 <= 11` becomes `1 < x <= 3`. If both sides were multiplied or divided by a negative number , the relations are
+    /// flipped and the chain is reversed so it still reads from left to right , like `-4 <= -2x < 6` into `-3 < x <= 2`.
+    ///
+    /// Returns an error of type `RearrangeError::ChainNotIsolatable` if the term is not contained in exactly one part
+    /// and `RearrangeError::ChainNotRearrangeable` if that part can not be rearranged (like `1 < x^2 < 4`)
+    pub fn try_make_subject(self,target : Term) -> Result<Self,RearrangeError> {
+        let contains_all = |part : &Expression| target.variables.keys().all(|variable| part.contains_variable(variable));
+        let contains_any = |part : &Expression| target.variables.keys().any(|variable| part.contains_variable(variable));
+
+        if self.parts().filter(|part| contains_all(part)).count() != 1 || self.parts().filter(|part| contains_any(part)).count() != 1 {
+            return Err(RearrangeError::ChainNotIsolatable(self));
+        }
+
+        let original = self.clone();
+
+        let mut parts : Vec<Expression> = std::iter::once(self.first).chain(self.rest.iter().map(|(_,part)| part.clone())).collect();
+        let mut relations : Vec<RelationalOperator> = self.rest.into_iter().map(|(relation,_)| relation).collect();
+
+        let index = parts.iter().position(contains_all).unwrap_or_default();
+
+        // The middle part is rearranged against a placeholder , which is replaced by each of the other parts afterwards
+        // `<` is used so that the rearrangement reports whether the relations have to be flipped.
+        // Errors would show the placeholder , so the original chain is returned instead
+        let placeholder = Term::from(Self::PLACEHOLDER);
+        let equation = Equation::new(parts[index].clone(),RelationalOperator::LessThan,placeholder.into())
+            .try_make_subject(target)
+            .map_err(|_| RearrangeError::ChainNotRearrangeable(original))?;
+
+        let flipped = equation.relation == RelationalOperator::GreaterThan;
+
+        for (i,part) in parts.iter_mut().enumerate() {
+            *part = match i == index {
+                true => equation.left.clone(),
+                false => equation.right.clone().replace_single_variable(&Self::PLACEHOLDER,part.clone()).find().evaluate().find()
+            };
+        }
+
+        // Flipping every relation and reversing the chain so it reads from left to right again cancel out , so only the order changes
+        if flipped {
+            parts.reverse();
+            relations.reverse();
+        }
+
+        let mut parts = parts.into_iter();
+        let first = parts.next().unwrap_or_else(|| 0.into());
+
+        Ok(Self::new(first,relations.into_iter().zip(parts).collect()))
+    }
+
+    /// Used as the other side while rearranging , which is never a variable of a parsed chain as the lexer only reads
+    /// letters and `_` as variables
+    const PLACEHOLDER : char = '?';
+}
+
+impl std::fmt::Display for ChainedRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{}",self.first)?;
+
+        for (relation,expression) in &self.rest {
+            write!(f," {relation} {expression}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for ChainedRelation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+impl From<Equation> for ChainedRelation {
+    fn from(equation : Equation) -> Self {
+        Self::new(equation.left,vec![(equation.relation,equation.right)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::manipulation::EvaluteWithValues;
+
+    fn make_subject(input : &str) -> String {
+        ChainedRelation::try_from(input).unwrap().try_make_subject(Term::from('x')).unwrap().to_string()
+    }
+
+    #[test]
+    fn display_and_equations() {
+        let chain = ChainedRelation::try_from("2 < 3x + 1 <= 10").unwrap();
+        assert_eq!(chain.to_string(),"2 < 3x + 1 <= 10");
+
+        let equations = chain.equations();
+        assert_eq!(equations.len(),2);
+        assert_eq!(equations[1].to_string(),"3x + 1 <= 10");
+
+        assert!(chain.into_equation().is_err());
+        assert!(ChainedRelation::try_from("x < 1").unwrap().into_equation().is_ok());
+    }
+
+    #[test]
+    fn holds() {
+        let chain = ChainedRelation::try_from("-1 < x < 1").unwrap();
+        assert_eq!(chain.clone().evaluate_with_single_value(&'x',0.5).find().holds(),Some(true));
+        assert_eq!(chain.clone().evaluate_with_single_value(&'x',1.0).find().holds(),Some(false));
+        assert_eq!(chain.holds(),None);
+    }
+
+    #[test]
+    fn isolate_middle() {
+        assert_eq!(make_subject("2 < 3x + 1 <= 10"),"0.3333333333333333 < x <= 3");
+        assert_eq!(make_subject("-1 < x - 2 < 1"),"1 < x < 3");
+        assert_eq!(make_subject("-4 <= -2x < 6"),"-3 < x <= 2");
+        assert_eq!(make_subject("5 < 3x + 2 <= 11"),"1 < x <= 3");
+        assert_eq!(make_subject("1 < 3 - x < 2"),"1 < x < 2");
+    }
+
+    #[test]
+    fn other_variables_are_kept() {
+        let omega = || Expression::from(Term::from('ω'));
+        let chain = ChainedRelation::new(0.into(),vec![
+            (RelationalOperator::LessThan,Expression::new_minus(Term::from('x').into(),omega())),
+            (RelationalOperator::LessThan,omega()),
+        ]);
+
+        assert_eq!(chain.try_make_subject(Term::from('x')).unwrap().to_string(),"ω < x < 2ω");
+    }
+
+    #[test]
+    fn not_isolatable() {
+        let chain = ChainedRelation::try_from("x < 2x < 3").unwrap();
+        assert!(matches!(chain.try_make_subject(Term::from('x')),Err(RearrangeError::ChainNotIsolatable(_))));
+
+        let chain = ChainedRelation::try_from("1 < y < 3").unwrap();
+        assert!(matches!(chain.try_make_subject(Term::from('x')),Err(RearrangeError::ChainNotIsolatable(_))));
+
+        let chain = ChainedRelation::try_from("1 < x^2 < 4").unwrap();
+        let error = chain.clone().try_make_subject(Term::from('x')).unwrap_err();
+        assert!(matches!(&error,RearrangeError::ChainNotRearrangeable(original) if *original == chain));
+        assert_eq!(error.to_string(),"The variable can not be made the subject of 1 < x^2 < 4");
+    }
+}
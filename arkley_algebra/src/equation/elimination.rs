@@ -0,0 +1,461 @@
+use num_notation::{Zero, One, Signed, CheckedMul, CheckedSub, Number, fraction::Ratio};
+
+use crate::{
+    Equation, Expression, Term, RelationalOperator,
+    manipulation::{Evaluate, Find, TryFind, VariableAnalysis},
+    radicals::{Surd, ratio_to_number},
+};
+
+use super::{EquationSystem, RearrangeError};
+
+/// Represents the solutions of a system of linear equations
+#[derive(Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum SystemSolution {
+    /// The system has exactly one solution , like `x = 2, y = 1` for `{ x + y = 3; x - y = 1 }`
+    Unique(Vec<(char,Expression)>),
+    /// The system has no solution (it is inconsistent) , like `{ x + y = 1; x + y = 2 }`
+    Inconsistent,
+    /// The system has infinitely many solutions (it is underdetermined) , so some variables are free parameters
+    /// and the others are given in terms of them , like `x = 1 - y` for `{ x + y = 1; 2x + 2y = 2 }`
+    Parametric {
+        /// The variables which are not free in terms of the free ones
+        values : Vec<(char,Expression)>,
+        /// The variables which can take any value
+        free : Vec<char>,
+    },
+}
+
+fn write_values(f: &mut std::fmt::Formatter<'_>,values : &[(char,Expression)]) -> std::fmt::Result {
+    let values = values.iter().map(|(variable,value)| format!("{variable} = {value}")).collect::<Vec<_>>();
+    f.write_str(&values.join(", "))
+}
+
+impl std::fmt::Display for SystemSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unique(values) => write_values(f,values),
+            Self::Inconsistent => write!(f,"no solution"),
+            Self::Parametric { values, free } => {
+                write_values(f,values)?;
+                let free = free.iter().map(ToString::to_string).collect::<Vec<_>>();
+                write!(f," for any {}",free.join(", "))
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for SystemSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+/// A utility struct for solving a system of linear equations using Gaussian elimination.
+///
+/// The system is written as an augmented matrix which is reduced into reduced row echelon form using exact rational
+/// pivots , so `1/3` stays `1/3` instead of becoming `0.3333333333333333`. Every variable of the system is an unknown.
+///
+/// It is created by [EquationSystem::gaussian_elimination].
+#[derive(Debug, Clone)]
+pub struct GaussianElimination {
+    system : EquationSystem,
+}
+
+impl EquationSystem {
+    /// Solves the system of linear equations using Gaussian elimination , see [GaussianElimination]
+    ///
+    /// Use [TryFind::try_find] on the result to obtain the [SystemSolution].
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{EquationSystem, manipulation::TryFind};
+    ///
+    /// let system = EquationSystem::try_from("{ x + y = 3; x - y = 1 }").unwrap();
+    /// assert_eq!(system.gaussian_elimination().try_find().unwrap().to_string(),"x = 2, y = 1");
+    /// ```
+    pub fn gaussian_elimination(self) -> GaussianElimination {
+        GaussianElimination { system : self }
+    }
+}
+
+type Row = Vec<Ratio<i64>>;
+
+/// An elementary row operation , where rows are counted from `0`
+#[cfg(feature="describe")]
+#[derive(Debug, Clone)]
+enum RowOperation {
+    /// Swaps both rows
+    Swap(usize,usize),
+    /// Multiplies the row by the factor
+    Scale(usize,Ratio<i64>),
+    /// Subtracts `factor` times the `source` row from the `target` row
+    Eliminate { target : usize, source : usize, factor : Ratio<i64> },
+}
+
+/// Writes the factor in front of a row , so `2R1` , `-R1` or `(1/3)R1`
+#[cfg(feature="describe")]
+fn multiple(factor : &Ratio<i64>,row : usize) -> String {
+    let row = row + 1;
+    match factor.is_integer() {
+        true if factor.is_one() => format!("R{row}"),
+        true if (-factor).is_one() => format!("-R{row}"),
+        true => format!("{factor}R{row}"),
+        false => format!("({factor})R{row}")
+    }
+}
+
+#[cfg(feature="describe")]
+impl std::fmt::Display for RowOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Swap(first,second) => write!(f,"R{} ↔ R{}",first + 1,second + 1),
+            Self::Scale(row,factor) => write!(f,"R{} → {}",row + 1,multiple(factor,*row)),
+            Self::Eliminate { target, source, factor } => {
+                let sign = match factor.is_negative() {
+                    true => "+",
+                    false => "-"
+                };
+                write!(f,"R{} → R{} {sign} {}",target + 1,target + 1,multiple(&factor.abs(),*source))
+            }
+        }
+    }
+}
+
+/// The augmented matrix `[A | b]` of the system
+#[derive(Debug, Clone)]
+struct Matrix {
+    rows : Vec<Row>,
+}
+
+#[cfg(feature="describe")]
+impl std::fmt::Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self.rows.iter()
+            .map(|row| {
+                let (rhs,coefficients) = row.split_last().map_or((String::new(),&[][..]),|(rhs,coefficients)| (rhs.to_string(),coefficients));
+                let coefficients = coefficients.iter().map(ToString::to_string).collect::<Vec<_>>();
+                format!("[{} | {rhs}]",coefficients.join(" "))
+            })
+            .collect::<Vec<_>>();
+        f.write_str(&rows.join("; "))
+    }
+}
+
+/// The result of reducing , kept around so `describe` can show the intermediate steps
+struct Reduction {
+    #[cfg(feature="describe")]
+    initial : Matrix,
+    /// Each row operation together with the matrix after applying it
+    #[cfg(feature="describe")]
+    steps : Vec<(RowOperation,Matrix)>,
+    /// The row reading `0 = b` where `b != 0` if the system is inconsistent
+    #[cfg(feature="describe")]
+    contradiction : Option<usize>,
+    solution : SystemSolution,
+}
+
+impl GaussianElimination {
+    /// Writes the equation as a row of the coefficients of `variables` followed by the constant on the right hand side
+    fn row(equation : &Equation,variables : &[char]) -> Result<Row,RearrangeError> {
+        if equation.relation != RelationalOperator::Equal {
+            return Err(RearrangeError::NotAnEquation(Box::new(equation.clone())));
+        }
+
+        let mut rest = Expression::new_minus(equation.left.clone(),equation.right.clone());
+        let mut row = Vec::with_capacity(variables.len() + 1);
+
+        for variable in variables {
+            let not_linear = || RearrangeError::NotLinear(Box::new(equation.clone()),*variable);
+
+            let mut coefficients = rest.coefficients_in(variable)
+                .filter(|coefficients| coefficients.len() <= 2)
+                .ok_or_else(not_linear)?
+                .into_iter();
+
+            rest = coefficients.next().unwrap_or_else(|| 0.into());
+            let coefficient = coefficients.next().unwrap_or_else(|| 0.into());
+
+            row.push(constant(coefficient).ok_or_else(not_linear)?);
+        }
+
+        // Left over variables would have been unknowns themselves , so `rest` has to be a constant
+        let constant = constant(rest).ok_or_else(|| RearrangeError::UnknownVariablesFound(Box::new(equation.clone())))?;
+        row.push(-constant);
+
+        Ok(row)
+    }
+
+    fn reduce(&self) -> Result<Reduction,RearrangeError> {
+        let variables : Vec<char> = self.system.get_unique_variables().into_iter().copied().collect();
+
+        let rows = self.system.equations.iter()
+            .map(|equation| Self::row(equation,&variables))
+            .collect::<Result<Vec<_>,_>>()?;
+
+        let overflow = || RearrangeError::CoefficientOverflow(Box::new(self.system.clone()));
+
+        let mut matrix = Matrix { rows };
+        #[cfg(feature="describe")]
+        let initial = matrix.clone();
+        #[cfg(feature="describe")]
+        let mut steps = Vec::new();
+        let mut pivots = Vec::new();
+
+        for column in 0..variables.len() {
+            let row = pivots.len();
+
+            let Some(found) = (row..matrix.rows.len()).find(|index| !matrix.rows[*index][column].is_zero()) else {
+                continue;
+            };
+
+            if found != row {
+                matrix.rows.swap(found,row);
+                #[cfg(feature="describe")]
+                steps.push((RowOperation::Swap(row,found),matrix.clone()));
+            }
+
+            let pivot = matrix.rows[row][column];
+            if !pivot.is_one() {
+                let factor = pivot.recip();
+                matrix.rows[row] = scale(&matrix.rows[row],&factor).ok_or_else(overflow)?;
+                #[cfg(feature="describe")]
+                steps.push((RowOperation::Scale(row,factor),matrix.clone()));
+            }
+
+            for target in 0..matrix.rows.len() {
+                let factor = matrix.rows[target][column];
+                if target == row || factor.is_zero() {
+                    continue;
+                }
+
+                let subtrahend = scale(&matrix.rows[row],&factor).ok_or_else(overflow)?;
+                matrix.rows[target] = matrix.rows[target].iter().zip(subtrahend)
+                    .map(|(value,subtrahend)| value.checked_sub(&subtrahend))
+                    .collect::<Option<_>>()
+                    .ok_or_else(overflow)?;
+
+                #[cfg(feature="describe")]
+                steps.push((RowOperation::Eliminate { target, source : row, factor },matrix.clone()));
+            }
+
+            pivots.push(column);
+        }
+
+        let contradiction = matrix.rows.iter()
+            .position(|row| row.split_last().is_some_and(|(rhs,coefficients)| !rhs.is_zero() && coefficients.iter().all(Zero::is_zero)));
+
+        let solution = match contradiction {
+            Some(_) => SystemSolution::Inconsistent,
+            None => {
+                let free : Vec<char> = variables.iter().enumerate()
+                    .filter(|(column,_)| !pivots.contains(column))
+                    .map(|(_,variable)| *variable)
+                    .collect();
+
+                let values = pivots.iter().zip(matrix.rows.iter())
+                    .map(|(column,row)| (variables[*column],value(row,&variables,&free)))
+                    .collect();
+
+                match free.is_empty() {
+                    true => SystemSolution::Unique(values),
+                    false => SystemSolution::Parametric { values, free }
+                }
+            }
+        };
+
+        Ok(Reduction {
+            #[cfg(feature="describe")]
+            initial,
+            #[cfg(feature="describe")]
+            steps,
+            #[cfg(feature="describe")]
+            contradiction,
+            solution
+        })
+    }
+}
+
+/// Gets the value of the expression if it does not contain any variables as an exact fraction
+fn constant(expression : Expression) -> Option<Ratio<i64>> {
+    match expression.evaluate().find() {
+        Expression::Term(term) if term.variables.is_empty() => Surd::try_from(term.coefficient).ok()?.as_rational(),
+        _ => None
+    }
+}
+
+fn scale(row : &[Ratio<i64>],factor : &Ratio<i64>) -> Option<Row> {
+    row.iter().map(|value| value.checked_mul(factor)).collect()
+}
+
+/// Reads off the value of the pivot variable of a fully reduced `row` in terms of the `free` variables
+fn value(row : &[Ratio<i64>],variables : &[char],free : &[char]) -> Expression {
+    let rhs = row.last().copied().unwrap_or_else(Ratio::zero);
+
+    let terms = variables.iter().zip(row.iter())
+        .filter(|(variable,coefficient)| free.contains(variable) && !coefficient.is_zero())
+        .map(|(variable,coefficient)| Term::create_single_variable_term(ratio_to_number(-coefficient),*variable,Number::one()));
+
+    let mut value : Option<Expression> = match rhs.is_zero() {
+        true => None,
+        false => Some(Term::new(ratio_to_number(rhs)).into())
+    };
+
+    for term in terms {
+        value = Some(match value {
+            None => term.into(),
+            Some(value) => match term.coefficient.is_negative() {
+                true => Expression::new_minus(value,(-term).into()),
+                false => Expression::new_plus(value,term.into()),
+            }
+        });
+    }
+
+    value.unwrap_or_else(|| 0.into())
+}
+
+impl TryFind for GaussianElimination {
+    type Output = SystemSolution;
+    type Error = RearrangeError;
+
+    fn try_find(self) -> Result<SystemSolution,RearrangeError> {
+        self.reduce().map(|reduction| reduction.solution)
+    }
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    Describe, Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+impl Describe for GaussianElimination {
+    fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        use std::collections::HashMap;
+
+        let reduction = self.reduce().ok()?;
+        let mut steps = Vec::new();
+
+        let args = HashMap::from([("matrix",reduction.initial.to_string().into())]);
+        steps.push(resources.lookup_single_language(lang,"gaussian-elimination.matrix",Some(&args))?);
+
+        for (operation,matrix) in reduction.steps {
+            let key = match operation {
+                RowOperation::Swap(..) => "gaussian-elimination.swap",
+                RowOperation::Scale(..) => "gaussian-elimination.scale",
+                RowOperation::Eliminate { .. } => "gaussian-elimination.eliminate",
+            };
+
+            let args = HashMap::from([
+                ("operation",operation.to_string().into()),
+                ("matrix",matrix.to_string().into()),
+            ]);
+            steps.push(resources.lookup_single_language(lang,key,Some(&args))?);
+        }
+
+        let description = match (&reduction.solution,reduction.contradiction) {
+            (SystemSolution::Inconsistent,Some(row)) => {
+                let args = HashMap::from([("row",(row + 1).to_string().into())]);
+                resources.lookup_single_language(lang,"gaussian-elimination.inconsistent",Some(&args))?
+            },
+            (SystemSolution::Parametric { free, .. },_) => {
+                let free = free.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+                let args = HashMap::from([
+                    ("free",free.into()),
+                    ("solution",reduction.solution.to_string().into()),
+                ]);
+                resources.lookup_single_language(lang,"gaussian-elimination.parametric",Some(&args))?
+            },
+            _ => {
+                let args = HashMap::from([("solution",reduction.solution.to_string().into())]);
+                resources.lookup_single_language(lang,"gaussian-elimination.unique",Some(&args))?
+            }
+        };
+
+        steps.push(description);
+
+        steps.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(input : &str) -> Result<SystemSolution,RearrangeError> {
+        EquationSystem::try_from(input).unwrap().gaussian_elimination().try_find()
+    }
+
+    #[test]
+    fn unique() {
+        assert_eq!(solve("{ x + y = 3; x - y = 1 }").unwrap().to_string(),"x = 2, y = 1");
+        assert_eq!(solve("{ x + y + z = 6; 2y + 5z = -4; 2x + 5y - z = 27 }").unwrap().to_string(),"x = 5, y = 3, z = -2");
+        assert_eq!(solve("{ 3x = 1; y = x + 1 }").unwrap().to_string(),"x = 1/3, y = 4/3");
+        assert_eq!(solve("{ x/3 + y = 1; x = 0.333333333 }").unwrap().to_string(),"x = 333333333/1000000000, y = 888888889/1000000000");
+    }
+
+    #[test]
+    fn inconsistent() {
+        assert_eq!(solve("{ x + y = 1; x + y = 2 }").unwrap(),SystemSolution::Inconsistent);
+        assert_eq!(solve("{ x + y = 1; x - y = 1; 2x + y = 5 }").unwrap(),SystemSolution::Inconsistent);
+    }
+
+    #[test]
+    fn parametric() {
+        assert_eq!(solve("{ x + y = 1; 2x + 2y = 2 }").unwrap().to_string(),"x = 1 - y for any y");
+        assert_eq!(solve("{ x + y + z = 2; y - z = 0 }").unwrap().to_string(),"x = 2 - 2z, y = z for any z");
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(solve("{ xy = 1; x = 2 }"),Err(RearrangeError::NotLinear(_,'x'))));
+        assert!(matches!(solve("{ x < 1; x = 2 }"),Err(RearrangeError::NotAnEquation(_))));
+    }
+
+    #[test]
+    #[cfg(feature="describe")]
+    fn row_operations() {
+        assert_eq!(RowOperation::Swap(0,1).to_string(),"R1 ↔ R2");
+        assert_eq!(RowOperation::Scale(1,Ratio::new(-1,2)).to_string(),"R2 → (-1/2)R2");
+        assert_eq!(RowOperation::Eliminate { target : 1, source : 0, factor : Ratio::from_integer(2) }.to_string(),"R2 → R2 - 2R1");
+        assert_eq!(RowOperation::Eliminate { target : 0, source : 1, factor : Ratio::from_integer(-1) }.to_string(),"R1 → R1 + R2");
+    }
+
+    #[cfg(feature="describe")]
+    use arkley_describe::fluent_templates;
+
+    #[cfg(feature="describe")]
+    fluent_templates::static_loader! {
+        static LOCALES = {
+            locales: "./translations",
+            fallback_language: "en-US",
+        };
+    }
+
+    #[test]
+    #[cfg(feature="describe")]
+    fn describe() {
+        // Fluent wraps every argument in unicode isolation marks
+        let describe = |input : &str| EquationSystem::try_from(input).unwrap()
+            .gaussian_elimination()
+            .describe(&LOCALES,&"en-US".parse().unwrap())
+            .unwrap()
+            .into_iter()
+            .map(|step| step.replace(['\u{2068}','\u{2069}'],""))
+            .collect::<Vec<_>>();
+
+        assert_eq!(describe("{ y = 1; x + y = 3 }"),[
+            r"Write the system as an augmented matrix with one row for each equation: \textbf { [0 1 | 1]; [1 1 | 3] }",
+            r"Swap the rows so the pivot is not 0 using \textbf { R1 ↔ R2 }, which gives \textbf { [1 1 | 3]; [0 1 | 1] }",
+            r"Eliminate the variable from the other row using \textbf { R1 → R1 - R2 }, which gives \textbf { [1 0 | 2]; [0 1 | 1] }",
+            r"Every variable has a pivot, so the system has exactly one solution: \textbf { x = 2, y = 1 }",
+        ]);
+
+        assert_eq!(describe("{ x + y = 1; x + y = 2 }").last().unwrap(),"Row 2 reads 0 = b where b is not 0, so the system has no solution");
+        assert_eq!(
+            describe("{ x + y = 1; 2x + 2y = 2 }").last().unwrap(),
+            r"The variables \textbf { y } have no pivot and can take any value, so the system has infinitely many solutions: \textbf { x = 1 - y for any y }"
+        );
+    }
+}
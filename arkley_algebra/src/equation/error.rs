@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::{Equation, Term};
+use crate::{Equation, EquationSystem, ChainedRelation, Term, Expression, AlgebraError};
 
 /// Represents errors that can occur when rearranging an equation.
 #[derive(Debug,Error)]
@@ -10,12 +10,73 @@ pub enum RearrangeError {
     /// 
     /// Provides the original equation where unknown variables were discovered.
     #[error("Given variable was not found in {}",.0)]
-    UnknownVariablesFound(Equation),
+    UnknownVariablesFound(Box<Equation>),
 
     /// Indicates that the resulting term cannot be converted into the target term
     /// 
     /// Provides the rearranged equation and the target term
     #[error("Resulting equation {} can not be rearranged into {}",.0,.1)]
    
-    ImpossibleSolution(Equation,Term),
+    ImpossibleSolution(Box<Equation>,Term),
+
+    /// Indicates that both sides of an inequality had to be multiplied or divided by a quantity whose sign is not known
+    ///
+    /// Provides the rearranged inequality (whose relation was not flipped) and the quantity
+    #[error("Can not tell if {} has to be flipped as the sign of {} is unknown",.0,.1)]
+    UnknownSign(Box<Equation>,Expression),
+
+    /// Indicates that both sides were multiplied by `0` , which loses all information about the equation
+    ///
+    /// Provides the original equation
+    #[error("Multiplying both sides of {} by 0 gives 0 = 0",.0)]
+    MultipliedByZero(Box<Equation>),
+
+    /// Indicates that the function can not be applied to both sides , as it does not take exactly one argument
+    /// or does not keep the order of the sides of an inequality
+    ///
+    /// Provides the original equation and the name of the function
+    #[error("{} can not be applied to both sides of {}",.1,.0)]
+    FunctionNotApplicable(Box<Equation>,String),
+
+    /// Indicates that the variable to isolate is not contained in exactly one part of a chain of relations
+    ///
+    /// Provides the original chain
+    #[error("The variable has to be in exactly one part of {} to be isolated",.0)]
+    ChainNotIsolatable(ChainedRelation),
+
+    /// Indicates that the part of a chain of relations containing the variable can not be rearranged , like `x^2` in `1 < x^2 < 4`
+    ///
+    /// Provides the original chain
+    #[error("The variable can not be made the subject of {}",.0)]
+    ChainNotRearrangeable(ChainedRelation),
+
+    /// Indicates that the variable to solve for appears in a non linear way , like `x^2` or `x * x`
+    ///
+    /// Provides the original equation and the variable
+    #[error("{} is not linear in {}",.0,.1)]
+    NotLinear(Box<Equation>,char),
+
+    /// Indicates that only equations using `=` can be solved this way
+    ///
+    /// Provides the original equation
+    #[error("{} is not an equation as it does not use `=`",.0)]
+    NotAnEquation(Box<Equation>),
+
+    /// Indicates that the inequality can not be written as a fraction of polynomials in the variable , like `2^x > 3` or `x + y < 1`
+    ///
+    /// Provides the original inequality and the variable
+    #[error("{} is not a polynomial or rational inequality in {}",.0,.1)]
+    NotRational(Box<Equation>,char),
+
+    /// Indicates that the coefficients became too large to be represented exactly while solving a system of equations
+    ///
+    /// Provides the original system
+    #[error("The coefficients of {} are too large to be solved exactly",.0)]
+    CoefficientOverflow(Box<EquationSystem>),
+
+    /// Indicates that the rearrangement required an invalid operation like dividing by `0`
+    ///
+    /// Provides the underlying [AlgebraError]
+    #[error(transparent)]
+    Algebra(#[from] AlgebraError),
 }
\ No newline at end of file
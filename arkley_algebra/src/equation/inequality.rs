@@ -0,0 +1,389 @@
+use num_notation::{FromPrimitive, fraction::Ratio};
+
+use crate::{
+    Equation, Expression, ArithmeticOperation, MAX_EXPANDED_EXPONENT,
+    manipulation::{Polynomial, number_to_f64, EPSILON},
+    cubics::{rational_root, deflate, exact_quadratic_roots},
+    radicals::Surd,
+};
+
+use super::RearrangeError;
+
+/// A value where the sign of an inequality can change , kept as an exact surd like `√2` if it is the root of a quadratic factor
+#[derive(Debug, Clone, PartialEq)]
+pub struct Boundary {
+    value : f64,
+    exact : Option<Surd>,
+}
+
+impl Boundary {
+    /// Gets the (approximate) value
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Gets the exact value , or `None` if it can not be written as a surd
+    pub const fn exact(&self) -> Option<&Surd> {
+        self.exact.as_ref()
+    }
+}
+
+impl std::fmt::Display for Boundary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.exact {
+            Some(exact) => write!(f,"{exact}"),
+            None => write!(f,"{}",self.value)
+        }
+    }
+}
+
+/// Represents one end of a [SolutionInterval]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Endpoint {
+    /// The value itself is not included , like `2` in `(2, 3]`
+    Open(Boundary),
+    /// The value itself is included , like `3` in `(2, 3]`
+    Closed(Boundary),
+    /// The interval goes on forever in this direction , like `∞` in `[3, ∞)`
+    Unbounded,
+}
+
+/// Represents a connected piece of the real line like `(-∞, -2)` , `[1, 3)` or the single point `{2}`
+#[derive(Clone, PartialEq)]
+pub struct SolutionInterval {
+    lower : Endpoint,
+    upper : Endpoint,
+}
+
+impl SolutionInterval {
+    /// Gets the lower end of the interval
+    pub const fn lower(&self) -> &Endpoint {
+        &self.lower
+    }
+
+    /// Gets the upper end of the interval
+    pub const fn upper(&self) -> &Endpoint {
+        &self.upper
+    }
+
+    /// Checks if `value` lies within the interval
+    pub fn contains(&self,value : f64) -> bool {
+        let above_lower = match &self.lower {
+            Endpoint::Open(lower) => value > lower.value,
+            Endpoint::Closed(lower) => value >= lower.value,
+            Endpoint::Unbounded => true,
+        };
+
+        let below_upper = match &self.upper {
+            Endpoint::Open(upper) => value < upper.value,
+            Endpoint::Closed(upper) => value <= upper.value,
+            Endpoint::Unbounded => true,
+        };
+
+        above_lower && below_upper
+    }
+}
+
+impl std::fmt::Display for SolutionInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.lower,&self.upper) {
+            (Endpoint::Closed(lower),Endpoint::Closed(upper)) if lower == upper => write!(f,"{{{lower}}}"),
+            (lower,upper) => {
+                match lower {
+                    Endpoint::Open(value) => write!(f,"({value}, ")?,
+                    Endpoint::Closed(value) => write!(f,"[{value}, ")?,
+                    Endpoint::Unbounded => write!(f,"(-∞, ")?,
+                };
+                match upper {
+                    Endpoint::Open(value) => write!(f,"{value})"),
+                    Endpoint::Closed(value) => write!(f,"{value}]"),
+                    Endpoint::Unbounded => write!(f,"∞)"),
+                }
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for SolutionInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+/// Represents the values for which an inequality holds as a union of disjoint intervals in ascending order ,
+/// like `(-∞, -2) ∪ [3, ∞)`
+#[derive(Clone, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct SolutionSet {
+    intervals : Vec<SolutionInterval>,
+}
+
+impl SolutionSet {
+    /// Gets the intervals making up the set in ascending order
+    pub fn intervals(&self) -> &[SolutionInterval] {
+        &self.intervals
+    }
+
+    /// Checks if the set contains no values at all
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Checks if the set contains every real number
+    pub fn is_all(&self) -> bool {
+        matches!(self.intervals[..],[SolutionInterval { lower : Endpoint::Unbounded, upper : Endpoint::Unbounded }])
+    }
+
+    /// Checks if `value` is in the set
+    pub fn contains(&self,value : f64) -> bool {
+        self.intervals.iter().any(|interval| interval.contains(value))
+    }
+}
+
+impl std::fmt::Display for SolutionSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.intervals.is_empty() {
+            return write!(f,"∅");
+        }
+
+        let intervals = self.intervals.iter().map(ToString::to_string).collect::<Vec<_>>();
+        write!(f,"{}",intervals.join(" ∪ "))
+    }
+}
+
+impl std::fmt::Debug for SolutionSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+/// Writes the expression as a fraction of two polynomials in `variable` , returning `(numerator,denominator)`
+fn rational_function(expression : &Expression,variable : &char) -> Option<(Polynomial,Polynomial)> {
+    if let Some(polynomial) = Polynomial::try_from_expression(expression,variable) {
+        return Some((polynomial,Polynomial::constant(1.0)));
+    }
+
+    match expression {
+        // Like `2x^-1`
+        Expression::Term(term) if term.variables.len() == 1 => {
+            let exponent = number_to_f64(term.variables.get(variable)?);
+            match exponent < 0.0 {
+                true => Some((Polynomial::constant(number_to_f64(&term.coefficient)),Polynomial::monomial(1.0,expanded_exponent(exponent)?))),
+                false => None
+            }
+        },
+        Expression::Binary { operation : ArithmeticOperation::Pow, left, right } => {
+            let (numerator,denominator) = rational_function(left,variable)?;
+
+            let exponent = Polynomial::try_from_expression(right,variable).filter(|exponent| exponent.degree() == 0)?;
+            let value = exponent.coefficient(0);
+            let power = expanded_exponent(value)?;
+
+            match value >= 0.0 {
+                true => Some((numerator.pow(power),denominator.pow(power))),
+                false if !numerator.is_zero() => Some((denominator.pow(power),numerator.pow(power))),
+                false => None
+            }
+        },
+        Expression::Binary { operation, left, right } => {
+            let (n1,d1) = rational_function(left,variable)?;
+            let (n2,d2) = rational_function(right,variable)?;
+
+            match operation {
+                ArithmeticOperation::Plus => Some((n1 * d2.clone() + n2 * d1.clone(),d1 * d2)),
+                ArithmeticOperation::Minus => Some((n1 * d2.clone() - n2 * d1.clone(),d1 * d2)),
+                ArithmeticOperation::Mal => Some((n1 * n2,d1 * d2)),
+                ArithmeticOperation::Durch if !n2.is_zero() => Some((n1 * d2,d1 * n2)),
+                _ => None
+            }
+        },
+        _ => None
+    }
+}
+
+/// Gets the size of a whole exponent , which is at most [MAX_EXPANDED_EXPONENT] as powers are expanded by repeated multiplication
+fn expanded_exponent(value : f64) -> Option<usize> {
+    match value.fract() == 0.0 && value.abs() <= f64::from(MAX_EXPANDED_EXPONENT) {
+        true => Some(value.abs() as usize),
+        false => None
+    }
+}
+
+/// Finds the roots of the polynomial which can be written exactly , which are the rational roots and the roots of a remaining quadratic
+fn exact_roots(polynomial : &Polynomial) -> Vec<Surd> {
+    // The coefficients from highest power to lowest as exact fractions
+    let coefficients = polynomial.coefficients.iter().rev()
+        .map(|c| Ratio::<i64>::from_f64(*c).filter(|ratio| (*ratio.numer() as f64 / *ratio.denom() as f64 - c).abs() < EPSILON))
+        .collect::<Option<Vec<_>>>();
+
+    let Some(mut coefficients) = coefficients else {
+        return vec![];
+    };
+
+    let mut roots = Vec::new();
+
+    while coefficients.len() > 3 {
+        let Some(root) = rational_root(&coefficients) else {
+            return roots;
+        };
+
+        coefficients = deflate(&coefficients,root);
+        roots.push(Surd::rational(root));
+    }
+
+    match coefficients.len() {
+        3 => roots.extend(exact_quadratic_roots(&coefficients).unwrap_or_default()),
+        2 => roots.push(Surd::rational(-coefficients[1] / coefficients[0])),
+        _ => {}
+    }
+
+    roots
+}
+
+/// Merges both lists of sorted values into one sorted list without (near) duplicates
+fn merge(mut values : Vec<f64>,other : Vec<f64>) -> Vec<f64> {
+    values.extend(other);
+    values.sort_by(f64::total_cmp);
+    values.dedup_by(|a,b| (*a - *b).abs() < EPSILON);
+    values
+}
+
+impl Equation {
+    /// Solves a polynomial or rational inequality in `variable` like `x^2 - x >= 6` or `(x + 1)/(x - 2) < 0`
+    /// using a sign chart
+    ///
+    /// Both sides are moved to one side and written as a fraction `p(x)/q(x)`. The roots of `p` and `q` split the real line into pieces
+    /// where the sign does not change , so checking a single value of each piece is enough. Values making the denominator `0` are never included.
+    /// This also works for `=` and `!=` giving the roots or everything but the roots.
+    /// Roots of rational and quadratic factors are written exactly , so `x^2 < 2` gives `(-√2, √2)`.
+    ///
+    /// Returns an error of type `RearrangeError::NotRational` if the inequality can not be written as a fraction of polynomials in `variable` ,
+    /// for example if it contains other variables or functions
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::Equation;
+    ///
+    /// let set = Equation::try_from("x^2 - x >= 6").unwrap().solve_inequality('x').unwrap();
+    /// assert_eq!(set.to_string(),"(-∞, -2] ∪ [3, ∞)");
+    ///
+    /// let set = Equation::try_from("-2x < 4").unwrap().solve_inequality('x').unwrap();
+    /// assert_eq!(set.to_string(),"(-2, ∞)");
+    /// ```
+    pub fn solve_inequality(&self,variable : char) -> Result<SolutionSet,RearrangeError> {
+        let expression = Expression::new_minus(self.left.clone(),self.right.clone());
+
+        let (numerator,denominator) = rational_function(&expression,&variable)
+            .filter(|(_,denominator)| !denominator.is_zero())
+            .ok_or_else(|| RearrangeError::NotRational(Box::new(self.clone()),variable))?;
+
+        let poles = denominator.real_roots();
+        let critical = merge(numerator.real_roots(),poles.clone());
+
+        let exact = [exact_roots(&numerator),exact_roots(&denominator)].concat();
+        let boundary = |value : f64| Boundary {
+            value,
+            exact : exact.iter().find(|root| (root.to_f64() - value).abs() < EPSILON).cloned()
+        };
+
+        let holds = |x : f64| self.relation.compare(&(numerator.evaluate(x) / denominator.evaluate(x)),&0.0);
+
+        // The sign chart alternates between the open pieces and the critical points
+        let mut pieces = Vec::with_capacity(2 * critical.len() + 1);
+
+        for (index,point) in critical.iter().enumerate() {
+            let lower = match index {
+                0 => Endpoint::Unbounded,
+                _ => Endpoint::Open(boundary(critical[index - 1]))
+            };
+            let sample = match index {
+                0 => point - 1.0,
+                _ => (critical[index - 1] + point) / 2.0
+            };
+
+            pieces.push((lower,Endpoint::Open(boundary(*point)),holds(sample)));
+
+            let is_pole = poles.iter().any(|pole| (pole - point).abs() < EPSILON);
+            pieces.push((Endpoint::Closed(boundary(*point)),Endpoint::Closed(boundary(*point)),!is_pole && self.relation.compare(&0.0,&0.0)));
+        }
+
+        let (lower,sample) = match critical.last() {
+            Some(last) => (Endpoint::Open(boundary(*last)),last + 1.0),
+            None => (Endpoint::Unbounded,0.0)
+        };
+        pieces.push((lower,Endpoint::Unbounded,holds(sample)));
+
+        // Neighbouring pieces which are both included are joined into one interval
+        let mut intervals = Vec::new();
+        let mut start = None;
+        let mut previous_upper = Endpoint::Unbounded;
+
+        for (lower,upper,included) in pieces {
+            match (included,start.take()) {
+                (true,None) => start = Some(lower),
+                (false,Some(lower)) => intervals.push(SolutionInterval { lower, upper : previous_upper }),
+                (_,lower) => start = lower
+            }
+            previous_upper = upper;
+        }
+
+        if let Some(lower) = start {
+            intervals.push(SolutionInterval { lower, upper : previous_upper });
+        }
+
+        Ok(SolutionSet { intervals })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(input : &str) -> String {
+        Equation::try_from(input).unwrap().solve_inequality('x').unwrap().to_string()
+    }
+
+    #[test]
+    fn linear() {
+        assert_eq!(solve("2x + 3 < 7"),"(-∞, 2)");
+        assert_eq!(solve("-2x <= 4"),"[-2, ∞)");
+        assert_eq!(solve("x + 1 > x"),"(-∞, ∞)");
+        assert_eq!(solve("x + 1 < x"),"∅");
+    }
+
+    #[test]
+    fn quadratic() {
+        assert_eq!(solve("x^2 - x - 6 > 0"),"(-∞, -2) ∪ (3, ∞)");
+        assert_eq!(solve("x^2 <= 4"),"[-2, 2]");
+        assert_eq!(solve("(x - 1)^2 <= 0"),"{1}");
+        assert_eq!(solve("x^2 + 1 < 0"),"∅");
+        assert_eq!(solve("x^2 != 1"),"(-∞, -1) ∪ (-1, 1) ∪ (1, ∞)");
+    }
+
+    #[test]
+    fn exact_boundaries() {
+        assert_eq!(solve("x^2 < 2"),"(-√2, √2)");
+        assert_eq!(solve("3x > 1"),"(1/3, ∞)");
+        assert_eq!(solve("(x - 1)(x^2 - 2x - 1) >= 0"),"[1 - √2, 1] ∪ [1 + √2, ∞)");
+        assert_eq!(solve("x^3 - 2 < 0"),"(-∞, 1.2599210499)");
+    }
+
+    #[test]
+    fn rational() {
+        assert_eq!(solve("(x + 2)/(x - 3) >= 0"),"(-∞, -2] ∪ (3, ∞)");
+        assert_eq!(solve("1/x < 1"),"(-∞, 0) ∪ (1, ∞)");
+        assert_eq!(solve("(x^2 - 1)/(x - 1) > 0"),"(-1, 1) ∪ (1, ∞)");
+    }
+
+    #[test]
+    fn contains_and_errors() {
+        let set = Equation::try_from("x^2 - x >= 6").unwrap().solve_inequality('x').unwrap();
+        assert!(set.contains(-2.0) && set.contains(10.0) && !set.contains(0.0));
+
+        assert!(matches!(Equation::try_from("x + y < 1").unwrap().solve_inequality('x'),Err(RearrangeError::NotRational(_,'x'))));
+        assert!(matches!(Equation::try_from("1/x^100000 > 0").unwrap().solve_inequality('x'),Err(RearrangeError::NotRational(_,'x'))));
+        assert!(matches!(Equation::try_from("2x^-100000000000 > 1").unwrap().solve_inequality('x'),Err(RearrangeError::NotRational(_,'x'))));
+        assert!(matches!(Equation::try_from("(x + 1)^100000000 > 0").unwrap().solve_inequality('x'),Err(RearrangeError::NotRational(_,'x'))));
+        assert!(matches!(Equation::try_from("(x + 1)^-100000000 > 0").unwrap().solve_inequality('x'),Err(RearrangeError::NotRational(_,'x'))));
+    }
+}
@@ -0,0 +1,434 @@
+use num_notation::Number;
+
+use std::collections::HashMap;
+
+use crate::{
+    Equation, Expression, Term, ArithmeticOperation, RelationalOperator, AlgebraError,
+    manipulation::{Evaluate, Find, VariableSubstitution, Interval, number_to_f64, EPSILON},
+    radicals::{square_free, MAX_RADICAND},
+};
+
+#[cfg(feature="function")]
+use crate::Function;
+
+use super::RearrangeError;
+
+/// Counts how often `variable` appears in the expression , including inside the arguments of functions
+pub(super) fn occurrences(expression : &Expression,variable : &char) -> usize {
+    match expression {
+        Expression::Term(term) => usize::from(term.variables.contains_key(variable)),
+        Expression::Binary { left, right, .. } => occurrences(left,variable) + occurrences(right,variable),
+        #[cfg(feature="function")]
+        Expression::Function(function) => function.arguments.values()
+            .flatten()
+            .map(|argument| occurrences(argument,variable))
+            .sum(),
+    }
+}
+
+/// Checks if `variable` appears somewhere the linear rearrangement can not handle , like in a power , a denominator ,
+/// a function or with an exponent other than `1`
+pub(super) fn needs_isolation(expression : &Expression,variable : &char) -> bool {
+    match expression {
+        Expression::Term(term) => term.variables.get(variable).is_some_and(|exponent| number_to_f64(exponent) != 1.0),
+        Expression::Binary { operation : ArithmeticOperation::Pow, .. } => occurrences(expression,variable) > 0,
+        Expression::Binary { operation : ArithmeticOperation::Durch, left, right } => occurrences(right,variable) > 0 || needs_isolation(left,variable),
+        Expression::Binary { left, right, .. } => needs_isolation(left,variable) || needs_isolation(right,variable),
+        #[cfg(feature="function")]
+        Expression::Function(_) => occurrences(expression,variable) > 0,
+    }
+}
+
+/// Gets the value of the expression if it does not depend on any variables
+fn constant(expression : &Expression) -> Option<f64> {
+    match expression.clone().evaluate().find() {
+        Expression::Term(term) if term.variables.is_empty() => Some(number_to_f64(&term.coefficient)),
+        _ => None
+    }
+}
+
+/// Checks that the coefficients without variables have a finite value , as undefined ones like `1/(-3)^0.5` never cancel
+/// and would keep the rearranging going
+fn finite(coefficients : Vec<Expression>) -> Result<Vec<Expression>,RearrangeError> {
+    for coefficient in &coefficients {
+        match coefficient.clone().replace_variables(&HashMap::<char,Interval>::new()).find() {
+            Ok(value) if !value.lower().is_finite() || !value.upper().is_finite() => return Err(AlgebraError::NonFinite(coefficient.clone()).into()),
+            // Coefficients with variables like `2y` are only known once the variables are
+            Ok(_) | Err(AlgebraError::MissingValue(_)) => (),
+            Err(error) => return Err(error.into())
+        }
+    }
+
+    Ok(coefficients)
+}
+
+/// Negates the expression without distributing the sign , as `-(a - b)` should not become `-a - b`
+fn negate(expression : Expression) -> Expression {
+    match expression {
+        Expression::Term(term) => (-term).into(),
+        expression => Expression::new_mal((-1).into(),expression)
+    }
+}
+
+/// Finds every real `x` with `x^exponent = value` , giving `±` branches for even powers
+fn roots(value : Expression,exponent : &Expression) -> Vec<Expression> {
+    let Some(exponent) = constant(exponent) else {
+        return vec![Expression::new_pow(value,Expression::new_durch(1.into(),exponent.clone()))];
+    };
+
+    let is_integer = exponent.fract() == 0.0;
+    let is_even = is_integer && exponent % 2.0 == 0.0;
+
+    let root = |value : Expression| Expression::new_pow(value,Term::new(Number::Decimal(1.0 / exponent)).into());
+
+    match constant(&value) {
+        // No real even root of a negative number
+        Some(number) if number < 0.0 && is_even => vec![],
+        Some(number) if number.abs() < EPSILON => vec![0.into()],
+        // Odd roots of negative numbers are negative
+        Some(number) if number < 0.0 && is_integer => vec![negate(root(Term::new(Number::Decimal(-number)).into()))],
+        _ if is_even => {
+            let root = root(value);
+            vec![root.clone(),negate(root)]
+        },
+        _ => vec![root(value)]
+    }
+}
+
+/// Finds every real `x` with `x^exponent = numerator/denominator` like [roots] , but takes whole denominators out of square roots
+/// so `x^2 = -y/2` gives `x = (-2y)^0.5/2` instead of `x = (-1y)^0.5/1.4142135623730951`
+fn quotient_roots(numerator : Expression,denominator : Expression,exponent : &Expression) -> Result<Vec<Expression>,RearrangeError> {
+    let is_square_root = constant(exponent) == Some(2.0) && constant(&numerator).is_none();
+
+    match constant(&denominator) {
+        Some(value) if is_square_root && value >= 1.0 && value.fract() == 0.0 && value <= MAX_RADICAND as f64 => {
+            // (n/d)^0.5 = (n * inside)^0.5 / (outside * inside) where d = outside^2 * inside
+            let (outside,inside) = square_free(value as u64);
+            let numerator = Expression::new_mal(Term::new(Number::Decimal(inside as f64)).into(),numerator).evaluate().find();
+            let denominator : Expression = Term::new(Number::Decimal((outside * inside) as f64)).into();
+
+            Ok(roots(numerator,exponent).into_iter().map(|root| Expression::new_durch(root,denominator.clone())).collect())
+        },
+        _ => Ok(roots(numerator.checked_div(denominator)?,exponent))
+    }
+}
+
+/// Gets the name of the inverse of a known function , like `asin` for `sin`
+#[cfg(feature="function")]
+fn inverse_name(name : &str) -> Option<&'static str> {
+    Some(match name {
+        "sin" => "asin",
+        "asin" => "sin",
+        "cos" => "acos",
+        "acos" => "cos",
+        "tan" => "atan",
+        "atan" => "tan",
+        "exp" => "ln",
+        "ln" => "exp",
+        _ => return None
+    })
+}
+
+/// Checks if `value` can be the result of a known function , like `sin` which only reaches values from `-1` to `1`
+#[cfg(feature="function")]
+fn in_range(name : &str,value : f64) -> bool {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    match name {
+        "sin" | "cos" => value.abs() <= 1.0 + EPSILON,
+        "asin" => value.abs() <= FRAC_PI_2 + EPSILON,
+        "acos" => (-EPSILON..=PI + EPSILON).contains(&value),
+        "atan" => value.abs() < FRAC_PI_2,
+        "exp" => value > 0.0,
+        _ => true
+    }
+}
+
+/// Calls one of the built-in functions , like `ln(value)`
+#[cfg(feature="function")]
+fn call(name : &str,value : Expression) -> Expression {
+    Expression::Function(Function::built_in(name,Some(value))).evaluate().find()
+}
+
+impl Equation {
+    /// Isolates `variable` on the left side , returning one equation for each branch of the solution
+    pub(super) fn isolate(&self,variable : char) -> Result<Vec<Self>,RearrangeError> {
+        let impossible = || RearrangeError::ImpossibleSolution(Box::new(self.clone()),Term::from(variable));
+
+        if self.relation != RelationalOperator::Equal {
+            return Err(impossible());
+        }
+
+        let values = match self.isolate_polynomial(variable)? {
+            Some(values) => values,
+            None => {
+                let (left,right) = match (occurrences(&self.left,&variable),occurrences(&self.right,&variable)) {
+                    (0,0) => return Err(RearrangeError::UnknownVariablesFound(Box::new(self.clone()))),
+                    (_,0) => (self.left.clone(),self.right.clone()),
+                    (0,_) => (self.right.clone(),self.left.clone()),
+                    _ => return Err(impossible())
+                };
+
+                peel(left,right,&variable)?.ok_or_else(impossible)?
+            }
+        };
+
+        let values = values.into_iter().map(|value| value.evaluate().find()).collect::<Vec<_>>();
+
+        // The variable was not isolated if it still appears on the other side , like `x = 4^(1/x)` for `x^x = 4`
+        if values.iter().any(|value| occurrences(value,&variable) > 0) {
+            return Err(impossible());
+        }
+
+        // Roots which do not satisfy the original equation (like `x = 9` for `x^0.5 = -3`) are extraneous ,
+        // only numeric roots are checked as symbolic ones can not be decided anyway
+        let equations = values.into_iter()
+            .filter(|value| constant(value).is_none() || self.verify_solution(variable,value.clone()) != Some(false))
+            .map(|value| Equation::new(Term::from(variable).into(),RelationalOperator::Equal,value))
+            .collect::<Vec<_>>();
+
+        match equations.is_empty() {
+            true => Err(impossible()),
+            false => Ok(equations)
+        }
+    }
+
+    /// Collects both sides into a polynomial in `variable` and solves it if it is linear , quadratic (by completing the square)
+    /// or a single power like `ax^n + b` , returning `None` if it is not a polynomial at all
+    fn isolate_polynomial(&self,variable : char) -> Result<Option<Vec<Expression>>,RearrangeError> {
+        let expression = Expression::new_minus(self.left.clone(),self.right.clone());
+
+        let Some(coefficients) = expression.coefficients_in(&variable) else {
+            return Ok(None);
+        };
+
+        let mut coefficients = finite(coefficients)?;
+
+        // A negative leading coefficient is moved to the other side first , so -2x^2 = y is solved as x^2 = -y/2
+        // instead of taking roots of the negative denominator in x^2 = y/-2
+        if coefficients.last().and_then(constant).is_some_and(|leading| leading < 0.0) {
+            coefficients = coefficients.into_iter().map(negate).collect();
+        }
+
+        let values = match &coefficients[..] {
+            [] | [_] => return Err(RearrangeError::ImpossibleSolution(Box::new(self.clone()),Term::from(variable))),
+            [b,a] => vec![negate(b.clone()).checked_div(a.clone())?],
+            [c,b,a] if !b.is_zero() => {
+                // Completing the square gives (x + b/2a)^2 = (b^2 - 4ac)/4a^2
+                let two_a = Expression::new_mal(2.into(),a.clone());
+                let shift = negate(b.clone()).checked_div(two_a.clone())?;
+
+                let discriminant = Expression::new_minus(
+                    Expression::new_pow(b.clone(),2.into()),
+                    Expression::new_mal(Expression::new_mal(4.into(),a.clone()),c.clone())
+                );
+
+                let square = discriminant.checked_div(Expression::new_pow(two_a,2.into()))?;
+
+                // The ± branches are written as shift + root and shift - root , so x = -2 - (4 + y)^0.5 is not shown as -2 + -1((4 + y)^0.5)
+                match &roots(square,&2.into())[..] {
+                    [] => vec![],
+                    [root] => vec![Expression::new_plus(shift,root.clone())],
+                    [root,..] => vec![Expression::new_plus(shift.clone(),root.clone()),Expression::new_minus(shift,root.clone())],
+                }
+            },
+            [constant,middle @ ..,leading] if middle.iter().all(Expression::is_zero) => {
+                quotient_roots(negate(constant.clone()),leading.clone(),&(middle.len() as f64 + 1.0).into())?
+            },
+            _ => return Err(RearrangeError::ImpossibleSolution(Box::new(self.clone()),Term::from(variable)))
+        };
+
+        Ok(Some(values))
+    }
+
+    /// Collects `variable` into a single term for relations like `2x + 1 < x + 5` where it appears more than once ,
+    /// as rearranging term by term would keep moving it between both sides
+    pub(super) fn isolate_linear(&self,variable : char) -> Result<Vec<Self>,RearrangeError> {
+        let expression = Expression::new_minus(self.left.clone(),self.right.clone());
+
+        let coefficients = expression.coefficients_in(&variable).map(finite).transpose()?;
+
+        let (b,a) = match coefficients.as_deref() {
+            Some([b,a]) => (b.clone(),a.clone()),
+            Some([] | [_]) => return Err(RearrangeError::ImpossibleSolution(Box::new(self.clone()),Term::from(variable))),
+            _ => return Err(RearrangeError::NotLinear(Box::new(self.clone()),variable))
+        };
+
+        let value = match b.is_zero() {
+            true => 0.into(),
+            false => negate(b).checked_div(a.clone())?.evaluate().find()
+        };
+        let mut equation = Equation::new(Term::from(variable).into(),self.relation.clone(),value);
+
+        match constant(&a) {
+            Some(leading) if leading < 0.0 => equation.relation = equation.relation.flipped(),
+            Some(_) => (),
+            None if self.relation.is_inequality() => return Err(RearrangeError::UnknownSign(Box::new(equation),a)),
+            None => ()
+        }
+
+        Ok(vec![equation])
+    }
+}
+
+/// Undoes the operations around `variable` in `left = right` one at a time , returning `None` if one of them can not be undone
+fn peel(left : Expression,right : Expression,variable : &char) -> Result<Option<Vec<Expression>>,RearrangeError> {
+    let contains = |expression : &Expression| occurrences(expression,variable) > 0;
+
+    let branches = match left {
+        Expression::Term(mut term) => {
+            let Some(exponent) = term.variables.remove(variable) else {
+                return Ok(None);
+            };
+
+            match number_to_f64(&exponent) == 1.0 {
+                true => vec![right.checked_div(term.into())?],
+                false => quotient_roots(right,term.into(),&Term::new(exponent).into())?
+            }
+        },
+        Expression::Binary { operation, left, right : other } => {
+            let (inner,value) = match (operation,contains(&left)) {
+                (ArithmeticOperation::Plus,true) => (*left,right - *other),
+                (ArithmeticOperation::Plus,false) => (*other,right - *left),
+                (ArithmeticOperation::Minus,true) => (*left,right + *other),
+                (ArithmeticOperation::Minus,false) => (*other,*left - right),
+                (ArithmeticOperation::Mal,true) => (*left,right.checked_div(*other)?),
+                (ArithmeticOperation::Mal,false) => (*other,right.checked_div(*left)?),
+                (ArithmeticOperation::Durch,true) => (*left,right * *other),
+                // A reciprocal like `1/x` is never `0`
+                (ArithmeticOperation::Durch,false) if constant(&right).is_some_and(|value| value.abs() < EPSILON) => return Ok(Some(Vec::new())),
+                // Reciprocal like `a/x = b` so `x = a/b`
+                (ArithmeticOperation::Durch,false) => (*other,left.checked_div(right)?),
+                (ArithmeticOperation::Pow,true) => {
+                    let mut branches = Vec::new();
+                    for value in roots(right,&other) {
+                        match peel(*left.clone(),value,variable)? {
+                            Some(values) => branches.extend(values),
+                            None => return Ok(None)
+                        }
+                    }
+                    return Ok(Some(branches));
+                },
+                // A positive base like in `2^x` never reaches `0` or a negative number
+                (ArithmeticOperation::Pow,false) if constant(&left).is_some_and(|base| base > 0.0) && constant(&right).is_some_and(|value| value <= 0.0) => return Ok(Some(Vec::new())),
+                // Exponential like `2^x = 8` so `x = ln(8)/ln(2)`
+                #[cfg(feature="function")]
+                (ArithmeticOperation::Pow,false) => {
+                    (*other,call("ln",right).checked_div(call("ln",*left))?)
+                },
+                #[cfg(not(feature="function"))]
+                (ArithmeticOperation::Pow,false) => return Ok(None),
+            };
+
+            return peel(inner,value,variable);
+        },
+        #[cfg(feature="function")]
+        Expression::Function(function) => {
+            let mut arguments = function.arguments.values().flatten();
+
+            let (Some(argument),None) = (arguments.next(),arguments.next()) else {
+                return Ok(None);
+            };
+
+            let Some(inverse) = inverse_name(&function.name) else {
+                return Ok(None);
+            };
+
+            // Like `sin(x) = 2` , which has no solution instead of `x = asin(2)`
+            if constant(&right).is_some_and(|value| !in_range(&function.name,value)) {
+                return Ok(Some(Vec::new()));
+            }
+
+            return peel(argument.clone(),call(inverse,right),variable);
+        },
+    };
+
+    Ok(Some(branches))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn isolate(input : &str) -> Vec<String> {
+        Equation::try_from(input).unwrap().try_make_subject_branches(Term::from('x')).unwrap()
+            .into_iter()
+            .map(|equation| equation.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn powers_and_roots() {
+        assert_eq!(isolate("x^2 = 9"),["x = 3","x = -3"]);
+        assert_eq!(isolate("2x^3 + 4 = -12"),["x = -2"]);
+        assert_eq!(isolate("x^0.5 = 3"),["x = 9"]);
+        assert_eq!(isolate("(x + 1)^0.5 = 2"),["x = 3"]);
+    }
+
+    #[test]
+    fn completing_the_square() {
+        assert_eq!(isolate("x^2 + 4x = 5"),["x = 1","x = -5"]);
+        assert_eq!(isolate("x^2 - 2x + 1 = 0"),["x = 1"]);
+        assert_eq!(isolate("x^2 + 4x = y"),["x = -2 + (4 + y)^0.5","x = -2 - (4 + y)^0.5"]);
+        assert_eq!(isolate("2x^2 + 4x = y").len(),2);
+    }
+
+    #[test]
+    fn branches_hold() {
+        use std::collections::HashMap;
+        use crate::manipulation::EvaluteWithValues;
+
+        for input in ["(y + x)/2 = x^2 - 2","x^2 + 4x = y","2x^2 + 4x = y","-2x^2 = y - 9","x^2 - y/3 = 1","-2x^2 = -y","4x^2 = y + 1","-8x^2 = y - 3"] {
+            let equation = Equation::try_from(input).unwrap();
+
+            for branch in equation.clone().try_make_subject_branches(Term::from('x')).unwrap() {
+                let x = constant(&branch.right.clone().evaluate_with_single_value(&'y',1.0).find()).unwrap();
+                assert_eq!(equation.holds(&HashMap::from([('x',x),('y',1.0)])),Some(true),"{branch} for {input}");
+            }
+        }
+    }
+
+    #[test]
+    fn negative_leading_coefficients() {
+        assert_eq!(isolate("-x^2 = y"),["x = (-1y)^0.5","x = -1((-1y)^0.5)"]);
+        assert_eq!(isolate("-x^3 = y"),["x = -1y^0.3333333333333333"]);
+        assert_eq!(isolate("-2x^2 = y"),["x = (-2y)^0.5/2","x = (-1((-2y)^0.5))/2"]);
+        assert_eq!(isolate("-8x^2 = y"),["x = (-2y)^0.5/4","x = (-1((-2y)^0.5))/4"]);
+        assert_eq!(isolate("4x^2 = y + 1"),["x = (1 + y)^0.5/2","x = (-1((1 + y)^0.5))/2"]);
+        assert_eq!(isolate("-x^2 = -4"),["x = 2","x = -2"]);
+    }
+
+    #[test]
+    fn reciprocals_and_exponentials() {
+        assert_eq!(isolate("3/x = 6"),["x = 0.5"]);
+        assert_eq!(isolate("2^x = 8"),["x = 3"]);
+    }
+
+    #[test]
+    #[cfg(feature="function")]
+    fn outside_of_the_range() {
+        let make_subject = |name : &str,value : f64| {
+            let function = Function::built_in(name,Some(Term::from('x').into()));
+
+            Equation::new(Expression::Function(function),RelationalOperator::Equal,Term::new(Number::Decimal(value)).into())
+                .try_make_subject(Term::from('x'))
+        };
+
+        assert!(matches!(make_subject("sin",2.0),Err(RearrangeError::ImpossibleSolution(..))));
+        assert!(matches!(make_subject("cos",-1.5),Err(RearrangeError::ImpossibleSolution(..))));
+        assert!(matches!(make_subject("exp",0.0),Err(RearrangeError::ImpossibleSolution(..))));
+        assert_eq!(make_subject("sin",1.0).unwrap().to_string(),format!("x = {}",Number::Decimal(std::f64::consts::FRAC_PI_2)));
+    }
+
+    #[test]
+    fn impossible() {
+        let make_subject = |input : &str| Equation::try_from(input).unwrap().try_make_subject(Term::from('x'));
+
+        assert!(matches!(make_subject("x^2 = -4"),Err(RearrangeError::ImpossibleSolution(..))));
+        assert!(matches!(make_subject("x^0.5 = -3"),Err(RearrangeError::ImpossibleSolution(..))));
+        assert!(matches!(make_subject("x^x = 4"),Err(RearrangeError::ImpossibleSolution(..))));
+        assert!(matches!(make_subject("x^2 < 4"),Err(RearrangeError::ImpossibleSolution(..))));
+        assert!(matches!(make_subject("1/x = 0"),Err(RearrangeError::ImpossibleSolution(..))));
+        assert!(matches!(make_subject("2^x = -8"),Err(RearrangeError::ImpossibleSolution(..))));
+    }
+}
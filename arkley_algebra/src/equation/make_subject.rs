@@ -1,11 +1,13 @@
 use std::cmp::Ordering;
 
+use num_notation::One;
+
 use crate::{
-    Equation, Term, Variables, Expression,
+    Equation, Term, Variables, Expression, RelationalOperator,
     manipulation::VariableAnalysis, gcd, 
 };
 
-use super::RearrangeError;
+use super::{RearrangeError, rearrange::SignChanges, isolate::{occurrences, needs_isolation}};
 
 impl Equation {
     /// Makes the specified term the subject of the equation, rearranging it accordingly.
@@ -24,41 +26,87 @@ impl Equation {
     /// # Returns
     ///
     /// Returns a new equation with the specified term as the subject or an error if unknown
-    /// variables are encountered.
+    /// variables are encountered or the rearrangement would divide by `0` (eg `0x = 5`).
+    ///
+    /// Inequalities are flipped when both sides are multiplied or divided by a negative number (eg `-2x < 4` becomes `x > -2`)
+    /// and an error of type `RearrangeError::UnknownSign` is returned if the sign of the quantity is not known (eg `xy < 4` for `x`).
+    /// If the variable appears more than once in an inequality it is collected into a single term first (eg `2x + 1 < x + 5` becomes `x < 4`) ,
+    /// returning an error of type `RearrangeError::NotLinear` if it is not linear in it (eg `x < 2x * x`).
+    ///
+    /// A single variable can also be isolated from powers , roots , reciprocals and known invertible functions like `sin` or `ln` ,
+    /// and quadratics like `x^2 + 4x = y` are solved by completing the square. If this gives more than one equation only the principal
+    /// branch , the one taking the positive root , is returned (like `x = 3` for `x^2 = 9`) , use [Equation::try_make_subject_branches]
+    /// to get all of them. An error of type `RearrangeError::ImpossibleSolution` is returned if the variable can not be isolated.
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{Equation, Term};
+    ///
+    /// let equation = Equation::try_from("x^2 = 9").unwrap().try_make_subject(Term::from('x')).unwrap();
+    /// assert_eq!(equation.to_string(),"x = 3");
+    /// ```
     pub fn try_make_subject(self,target : Term) -> Result<Self,RearrangeError> {
+        // Branches are never empty as that is reported as RearrangeError::ImpossibleSolution
+        self.try_make_subject_branches(target).map(|mut branches| branches.remove(0))
+    }
+
+    /// Makes the specified term the subject of the equation like [Equation::try_make_subject] , returning every branch of the solution
+    ///
+    /// Even powers give `±` branches so `x^2 + 4x = 5` gives both `x = 1` and `x = -5` , the principal branch taking the positive root is always first.
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{Equation, Term};
+    ///
+    /// let branches = Equation::try_from("x^2 = 9").unwrap().try_make_subject_branches(Term::from('x')).unwrap();
+    /// assert_eq!(branches.iter().map(ToString::to_string).collect::<Vec<_>>(),["x = 3","x = -3"]);
+    /// ```
+    pub fn try_make_subject_branches(self,target : Term) -> Result<Vec<Self>,RearrangeError> {
+        // A single variable like `x` , which can be isolated from powers , functions and so on
+        let single = match (target.variables.len(),target.coefficient.is_one()) {
+            (1,true) => target.variables.iter()
+                .find(|(_,exponent)| exponent.is_one())
+                .map(|(variable,_)| *variable),
+            _ => None
+        };
+
+        if let Some(variable) = single {
+            let count = occurrences(&self.left,&variable) + occurrences(&self.right,&variable);
+            let nonlinear = needs_isolation(&self.left,&variable) || needs_isolation(&self.right,&variable);
+
+            if nonlinear || (count > 1 && self.relation == RelationalOperator::Equal) {
+                return self.isolate(variable);
+            }
+
+            if count > 1 {
+                return self.isolate_linear(variable);
+            }
+        }
+
         if !self.contains_all(&mut target.variables.keys()) {
-            return Err(RearrangeError::UnknownVariablesFound(self));
+            return Err(RearrangeError::UnknownVariablesFound(Box::new(self)));
         };
 
-        // TODO : Handle case where making it a subject is not possible
-        let mut equation = self.determine_side_and_rearrange(&target.variables);
+        let original = self.clone();
+        let mut signs = SignChanges::default();
+
+        let mut equation = self.determine_side_and_rearrange(&target.variables,&mut signs);
         
         match equation.left {
             Expression::Term(ref mut term) => {
                 let gcd_coefficient = gcd(term.coefficient.clone(),target.coefficient.clone());
 
-                let vars_to_move : Variables;
+                let vars_to_move : Variables = term.variables.iter()
+                    .filter(|(k,_)| !target.variables.contains_key(k))
+                    .map(|(k,v)| (*k,v.clone()))
+                    .collect();
 
-                #[cfg(nightly)]
-                {
-                    vars_to_move = term.variables.extract_if(|k,v| !target.variables.contains_key(k)).collect();
-                }
-
-                #[cfg(not(nightly))]
-                {
-                    vars_to_move = term.variables.iter()
-                        .filter(|(k,_)| !target.variables.contains_key(k))
-                        .map(|(k,v)| (*k,v.clone()))
-                        .collect();
-
-                    term.variables.retain(|k,_| !vars_to_move.contains_key(k));
-                }
+                term.variables.retain(|k,_| !vars_to_move.contains_key(k));
 
-                let to_divide_with =  Term::new_with_variable(term.coefficient.clone() / gcd_coefficient.clone(),vars_to_move);
+                let to_divide_with : Expression =  Term::new_with_variable(term.coefficient.clone() / gcd_coefficient.clone(),vars_to_move).into();
 
-                println!("{r} / {l}",r = equation.right,l = to_divide_with);
-                
-                equation.right = equation.right / to_divide_with;
+                signs.record(&to_divide_with);
+                equation.right = equation.right.checked_div(to_divide_with)?;
 
                 if term.coefficient != target.coefficient {
                     term.coefficient = gcd_coefficient;
@@ -66,19 +114,41 @@ impl Equation {
 
                 // TODO : Maybe remove this ; figure it out
                 if term.variables != target.variables {
-                    return Err(RearrangeError::ImpossibleSolution(equation, target));   
+                    return Err(RearrangeError::ImpossibleSolution(Box::new(equation), target));   
                 }
 
-                Ok(equation)
+                equation.apply_sign_changes(signs).map(|equation| vec![equation])
             },
-            _ => unimplemented!("Figure out how to make x subject for x^2 + 4x = y smth with factorization or check how to do this")
+            _ => match single {
+                Some(variable) => original.isolate(variable),
+                None => Err(RearrangeError::ImpossibleSolution(Box::new(original),target))
+            }
+        }
+    }
+
+    /// Flips the relation if both sides were multiplied or divided by a negative number an odd number of times
+    pub(super) fn apply_sign_changes(mut self,signs : SignChanges) -> Result<Self,RearrangeError> {
+        if !self.relation.is_inequality() {
+            return Ok(self);
+        }
+
+        if let Some(factor) = signs.unknown {
+            return Err(RearrangeError::UnknownSign(Box::new(self),factor));
+        }
+
+        if signs.flipped {
+            self.relation = self.relation.flipped();
         }
+
+        Ok(self)
     }
 
     /// Determines the side of the equation to rearrange based on the count of variable occurrences.
-    fn determine_side_and_rearrange(mut self,variables_to_count : &Variables) -> Self {
-        let lexpr_count = self.left.count_variable_occurrences(&variables_to_count);
-        let rexpr_count = self.right.count_variable_occurrences(&variables_to_count);
+    ///
+    /// The relation is flipped if the sides are swapped , so `3 < x` becomes `x > 3`
+    fn determine_side_and_rearrange(mut self,variables_to_count : &Variables,signs : &mut SignChanges) -> Self {
+        let lexpr_count = self.left.count_variable_occurrences(variables_to_count);
+        let rexpr_count = self.right.count_variable_occurrences(variables_to_count);
 
         let should_continue_rearranging = |_ : &Expression,rexpr : &Expression| rexpr.contains_any_variable(&mut variables_to_count.keys());
         
@@ -86,15 +156,18 @@ impl Equation {
             Ordering::Greater => self.left.rearrange(
                 self.right,
                 variables_to_count,
+                signs,
                 should_continue_rearranging
             ),
+            // The subject is on the right so the sides are swapped
             Ordering::Equal | Ordering::Less => {
-                let (right,left) = self.right.rearrange(
+                self.relation = self.relation.flipped();
+                self.right.rearrange(
                     self.left,
                     variables_to_count,
+                    signs,
                     should_continue_rearranging
-                );
-                (left,right)
+                )
             }
         };
 
@@ -141,7 +214,7 @@ mod tests {
         { q => 0 , "3p + 2q = 12",'q', "q = (12 - 3p)/2"},
         { q => 1,  "2qy + 3 = 1",'q', "q = -1/y"},
 
-        { a => 0 ,  "3b - 2a = 12", 'a', "a = (12 + 3b)/2" },
+        { a => 0 ,  "3b - 2a = 12", 'a', "a = (-12 - -3b)/2" },
         { a => 1 ,  "2a + 3 = 7", 'a', "a = 2" },
 
 
@@ -149,6 +222,86 @@ mod tests {
 
     );
 
+    #[test]
+    fn make_subject_divide_by_zero() {
+        use crate::{AlgebraError, RelationalOperator};
+        use num_notation::Number;
+
+        let zero_x = Term::new_with_variable(Number::Decimal(0.0),[('x',Number::Decimal(1.0))].into());
+        let equation = Equation::new(zero_x.into(),RelationalOperator::Equal,Term::from(5.0).into());
+        let result = equation.try_make_subject(Term::from('x'));
+        assert!(matches!(result,Err(RearrangeError::Algebra(AlgebraError::DivisionByZero(_)))));
+    }
+
+    #[test]
+    fn make_subject_flips_inequalities() {
+        let make_subject = |input : &str| Equation::try_from(input).unwrap().try_make_subject(Term::from('x')).unwrap().to_string();
+
+        assert_eq!(make_subject("-2x + 1 < 5"),"x > -2");
+        assert_eq!(make_subject("2x + 1 <= 7"),"x <= 3");
+        assert_eq!(make_subject("3 <= 2x + 1"),"x >= 1");
+        assert_eq!(make_subject("x / -4 >= 1"),"x <= -4");
+        assert_eq!(make_subject("-2x != 4"),"x != -2");
+    }
+
+    #[test]
+    fn make_subject_subtracted_from() {
+        let make_subject = |input : &str| Equation::try_from(input).unwrap().try_make_subject(Term::from('x')).unwrap().to_string();
+
+        assert_eq!(make_subject("1 - x < 0"),"x > 1");
+        assert_eq!(make_subject("5 - 2x > 1"),"x < 2");
+        assert_eq!(make_subject("3 - x != 2"),"x != 1");
+        assert_eq!(make_subject("3 - x = 2"),"x = 1");
+        assert_eq!(make_subject("1 - x/3 >= 2"),"x <= -3");
+    }
+
+    #[test]
+    fn make_subject_nested_inequalities() {
+        let make_subject = |input : &str| Equation::try_from(input).unwrap().try_make_subject(Term::from('x')).unwrap().to_string();
+
+        assert_eq!(make_subject("x/3 + 1 <= 2"),"x <= 3");
+        assert_eq!(make_subject("2 - 3(x + 1) < 5"),"x > -2");
+    }
+
+    #[test]
+    fn make_subject_repeated_in_inequalities() {
+        let make_subject = |input : &str| Equation::try_from(input).unwrap().try_make_subject(Term::from('x'));
+
+        assert_eq!(make_subject("2x + 1 < x + 5").unwrap().to_string(),"x < 4");
+        assert_eq!(make_subject("3x - 2 >= 5x").unwrap().to_string(),"x <= -1");
+        assert_eq!(make_subject("x + x != 4").unwrap().to_string(),"x != 2");
+
+        for input in ["x < 2x*x","x + 1 < x*x","x != 2x*x","x + 1 != x*x"] {
+            assert!(matches!(make_subject(input),Err(RearrangeError::NotLinear(_,'x'))),"{input}");
+        }
+
+        assert!(matches!(make_subject("x * y < x"),Err(RearrangeError::UnknownSign(..))));
+        assert!(matches!(make_subject("x + 1 < x + 3"),Err(RearrangeError::ImpossibleSolution(..))));
+    }
+
+    #[test]
+    fn make_subject_does_not_overflow() {
+        let make_subject = |input : &str| Equation::try_from(input).unwrap().try_make_subject(Term::from('x'));
+
+        for input in ["x = x^2/(-3)^0.5","x^2/(-3)^0.5 = 1","x + x/(-3)^0.5 < 1"] {
+            assert!(matches!(make_subject(input),Err(RearrangeError::Algebra(_))),"{input}");
+        }
+
+        for input in ["x < 2x*x","x + 1 < x*x","x * y < x","x != 2x*x","x + 1 != x*x","3x - 2 >= 5x"] {
+            let _ = make_subject(input);
+        }
+    }
+
+    #[test]
+    fn make_subject_unknown_sign() {
+        let equation = Equation::try_from("2xy < 4").unwrap();
+        let result = equation.try_make_subject(Term::from('x'));
+        assert!(matches!(result,Err(RearrangeError::UnknownSign(_,_))));
+
+        let equation = Equation::try_from("2xy = 4").unwrap();
+        assert!(equation.try_make_subject(Term::from('x')).is_ok());
+    }
+
     //impl_test!(make_y_the_subject_trivial_equation, "y = y",'y', "Infinite solutions");
     //impl_test!(multiple_solutions, "2x - 4 = 2x - 6",'x', "Infinite solutions");
 }
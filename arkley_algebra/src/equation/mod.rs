@@ -2,10 +2,23 @@ mod relation;
 mod utils;
 mod rearrange;
 mod make_subject;
+mod isolate;
 mod error;
+mod chain;
+mod system;
+mod solve;
+mod elimination;
+mod inequality;
+mod verify;
+mod both_sides;
 
 pub use relation::*;
 pub use error::*;
+pub use chain::*;
+pub use system::*;
+pub use solve::*;
+pub use elimination::*;
+pub use inequality::*;
 
 use crate::Expression;
 
@@ -16,7 +29,7 @@ pub struct Equation {
     /// The left-hand side expression.
     pub(crate) left: Expression,
     /// The relational operator.
-    relation: RelationalOperator,
+    pub(crate) relation: RelationalOperator,
     /// The right-hand side expression.
     pub(crate) right: Expression,
 }
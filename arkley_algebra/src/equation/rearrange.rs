@@ -4,9 +4,48 @@ use num_notation::Signed;
 
 use crate::{
     Expression, Term, Variables, ArithmeticOperation, 
-    manipulation::VariableAnalysis
+    manipulation::{VariableAnalysis, Evaluate, Find}
 };
 
+/// Tracks the quantities both sides of an equation were multiplied or divided by while rearranging ,
+/// which decides whether an inequality has to be flipped
+#[derive(Default)]
+pub(super) struct SignChanges {
+    /// Whether both sides were multiplied or divided by a negative quantity an odd number of times
+    pub(super) flipped : bool,
+    /// The first quantity whose sign is not known , like `y` , in which case an inequality can not be rearranged
+    pub(super) unknown : Option<Expression>,
+}
+
+impl SignChanges {
+    /// Records that both sides were multiplied or divided by `factor`
+    pub(super) fn record(&mut self,factor : &Expression) {
+        match Self::is_negative(factor) {
+            Some(negative) => self.flipped ^= negative,
+            None => {
+                self.unknown.get_or_insert_with(|| factor.clone());
+            }
+        }
+    }
+
+    /// Checks if the expression is negative , returning `None` if that depends on its variables or functions
+    fn is_negative(factor : &Expression) -> Option<bool> {
+        let constant = match factor {
+            Expression::Term(term) => term.clone(),
+            expression if expression.get_unique_variables().is_empty() => match expression.clone().evaluate().find() {
+                Expression::Term(term) => term,
+                _ => return None
+            },
+            _ => return None
+        };
+
+        match constant.variables.is_empty() {
+            true => Some(constant.coefficient.is_negative()),
+            false => None
+        }
+    }
+}
+
 impl Expression {
     /// Rearrange the equation to isolate specific variables on one side.
     ///
@@ -18,6 +57,7 @@ impl Expression {
     /// - `self`: A side of equation to rearrange.
     /// - `other`: Another side of equation to rearrange.
     /// - `variables_to_count`: A reference to the variables to isolate.
+    /// - `signs`: Records the quantities both sides were multiplied or divided by.
     ///
     /// # Returns
     ///
@@ -27,32 +67,55 @@ impl Expression {
         self,
         other : Self,
         variables_to_count : &Variables,
+        signs : &mut SignChanges,
         // TODO : Maybe should remove this can keep 'constant' checking
         should_continue_rearranging : F
     ) -> (Self,Self) 
     where F : Fn(&Self,&Self) -> bool {
+        let size = self.size();
+
         let (lexpr,rexpr) = match self {
-            Self::Binary { operation, left, right } 
-                if operation == ArithmeticOperation::Durch => Self::move_durch(other, *right, *left),
-            Self::Binary { operation, left, right } 
-                if operation == ArithmeticOperation::Mal => Self::move_mal(other, *right, *left, variables_to_count),
-            Self::Binary { operation, left, right } 
-                if operation == ArithmeticOperation::Plus => Self::move_add(other, *right, *left, variables_to_count),
-            Self::Binary { operation, left, right } 
-                if operation == ArithmeticOperation::Minus => Self::move_sub(other, *right, *left, variables_to_count),
+            Self::Binary { operation : ArithmeticOperation::Durch, left, right } => Self::move_durch(other, *right, *left, signs),
+            Self::Binary { operation : ArithmeticOperation::Mal, left, right } => Self::move_mal(other, *right, *left, variables_to_count, signs),
+            Self::Binary { operation : ArithmeticOperation::Plus, left, right } => Self::move_add(other, *right, *left, variables_to_count),
+            // a - b is moved as a + (-b) so the sign of b is kept , like in 3 - x = 2 which becomes -x = -1
+            Self::Binary { operation : ArithmeticOperation::Minus, left, right } => Self::move_add(other, Self::negated(*right), *left, variables_to_count),
 
             // Do nothing if contains variables from new subject (for now)
             Self::Term(ref term) if term.contains_all(&mut variables_to_count.keys()) => (self,other),
             Self::Term(term) => Self::move_term(other, term),
 
-            _ => unreachable!()
+            // Powers and functions only reach here if they do not contain the subject , so they are moved as a whole
+            expression => (0.into(),other - expression)
         };
 
-        match should_continue_rearranging(&lexpr,&rexpr) {
-            true => lexpr.rearrange(rexpr, variables_to_count,should_continue_rearranging),
+        // The subject can still be inside a sum , product or quotient like x/3 in x/3 + 1 , which is unwrapped
+        // as long as each step makes the side smaller
+        let is_unwrappable = matches!(lexpr,Self::Binary { operation : ArithmeticOperation::Plus | ArithmeticOperation::Minus | ArithmeticOperation::Mal | ArithmeticOperation::Durch, .. })
+            && lexpr.size() < size;
+
+        match is_unwrappable || should_continue_rearranging(&lexpr,&rexpr) {
+            true => lexpr.rearrange(rexpr, variables_to_count, signs, should_continue_rearranging),
             false => (lexpr,rexpr),
         }
     }
+
+    /// Counts the terms , operations and functions in the expression
+    fn size(&self) -> usize {
+        match self {
+            Self::Binary { left, right, .. } => 1 + left.size() + right.size(),
+            _ => 1
+        }
+    }
+
+    /// Negates the expression by only negating the first factor of products and quotients , so `-(x/3)` is `-x/3` rather than `-x/-3`
+    fn negated(self) -> Self {
+        match self {
+            Self::Term(term) => (-term).into(),
+            Self::Binary { operation : operation @ (ArithmeticOperation::Mal | ArithmeticOperation::Durch), left, right } => Expression::new_binary(operation,left.negated(),*right),
+            expression => Expression::new_mal((-1).into(),expression)
+        }
+    }
 }
 
 impl Expression {
@@ -65,17 +128,24 @@ impl Expression {
         (0.into(),Expression::new_binary(operation,other,term.into()))
     }
 
-    fn move_durch(other : Self,right : Self,left : Self) -> (Self,Self) {
+    fn move_durch(other : Self,right : Self,left : Self,signs : &mut SignChanges) -> (Self,Self) {
+        signs.record(&right);
         (left,other * right)
     } 
 
-    fn move_mal(other : Self,right : Self,left : Self,variables_to_count : &Variables) -> (Self,Self) {
-        let lexpr_count = left.count_variable_occurrences(&variables_to_count);
-        let rexpr_count = right.count_variable_occurrences(&variables_to_count);
+    fn move_mal(other : Self,right : Self,left : Self,variables_to_count : &Variables,signs : &mut SignChanges) -> (Self,Self) {
+        let lexpr_count = left.count_variable_occurrences(variables_to_count);
+        let rexpr_count = right.count_variable_occurrences(variables_to_count);
         
         match lexpr_count.cmp(&rexpr_count) {
-            Ordering::Less => (right,other / left),
-            Ordering::Greater => (left,other / right),
+            Ordering::Less => {
+                signs.record(&left);
+                (right,other / left)
+            },
+            Ordering::Greater => {
+                signs.record(&right);
+                (left,other / right)
+            },
             Ordering::Equal => (left * right,other)
         }
     } 
@@ -83,16 +153,8 @@ impl Expression {
     fn move_add(other : Self,left : Self,right : Self,variables_to_count : &Variables) -> (Self,Self) {
         Self::move_add_or_sub_inner(
             other, left, right, variables_to_count,
-            |left,right| Expression::new_plus(left, right),
+            Expression::new_plus,
             |left,right| left - right,
         )
     }
-
-    fn move_sub(other : Self,left : Self,right : Self,variables_to_count : &Variables) -> (Self,Self) {
-        Self::move_add_or_sub_inner(
-            other, left, right, variables_to_count,
-            |left,right| Expression::new_minus(left, right),
-            |left,right| left + right,
-        )
-    }
 }
\ No newline at end of file
@@ -1,16 +1,61 @@
 
 /// An enumeration representing different relational operators.
-#[derive(PartialEq, Clone, strum::Display)]
+///
+/// It can be parsed from both the ASCII and the Unicode form , like `<=` and `≤`
+#[derive(PartialEq, Clone, strum::Display, strum::EnumString)]
 pub enum RelationalOperator {
     /// The equal-to operator: `=`
     #[strum(serialize = "=")]
     Equal,
+    /// The not-equal-to operator: `!=`
+    #[strum(to_string = "!=", serialize = "≠")]
+    NotEqual,
     /// The greater-than operator: `>`
     #[strum(serialize = ">")]
     GreaterThan,
+    /// The greater-than-or-equal-to operator: `>=`
+    #[strum(to_string = ">=", serialize = "≥")]
+    GreaterThanOrEqual,
     /// The less-than operator: `<`
     #[strum(serialize = "<")]
     LessThan,
+    /// The less-than-or-equal-to operator: `<=`
+    #[strum(to_string = "<=", serialize = "≤")]
+    LessThanOrEqual,
+}
+
+impl RelationalOperator {
+    /// Gets the relation after swapping both sides or multiplying both sides by a negative number , so `<` becomes `>`
+    /// while `=` and `!=` stay the same
+    pub const fn flipped(&self) -> Self {
+        match self {
+            Self::Equal => Self::Equal,
+            Self::NotEqual => Self::NotEqual,
+            Self::GreaterThan => Self::LessThan,
+            Self::GreaterThanOrEqual => Self::LessThanOrEqual,
+            Self::LessThan => Self::GreaterThan,
+            Self::LessThanOrEqual => Self::GreaterThanOrEqual,
+        }
+    }
+
+    /// Checks if the relation is an inequality like `<` or `>=` , whose direction matters when rearranging
+    ///
+    /// `!=` is not counted as it does not have a direction
+    pub const fn is_inequality(&self) -> bool {
+        !matches!(self,Self::Equal | Self::NotEqual)
+    }
+
+    /// Checks if the relation holds between both values
+    pub fn compare<T : PartialOrd>(&self,left : &T,right : &T) -> bool {
+        match self {
+            Self::Equal => left == right,
+            Self::NotEqual => left != right,
+            Self::GreaterThan => left > right,
+            Self::GreaterThanOrEqual => left >= right,
+            Self::LessThan => left < right,
+            Self::LessThanOrEqual => left <= right,
+        }
+    }
 }
 
 impl std::fmt::Debug for RelationalOperator {
@@ -18,3 +63,35 @@ impl std::fmt::Debug for RelationalOperator {
         write!(f, "{self}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    #[test]
+    fn parse_and_display() {
+        assert_eq!(RelationalOperator::from_str("<=").unwrap(),RelationalOperator::LessThanOrEqual);
+        assert_eq!(RelationalOperator::from_str("≤").unwrap(),RelationalOperator::LessThanOrEqual);
+        assert_eq!(RelationalOperator::from_str("≠").unwrap(),RelationalOperator::NotEqual);
+        assert!(RelationalOperator::from_str("=>").is_err());
+
+        assert_eq!(RelationalOperator::GreaterThanOrEqual.to_string(),">=");
+        assert_eq!(RelationalOperator::NotEqual.to_string(),"!=");
+    }
+
+    #[test]
+    fn flipped() {
+        assert_eq!(RelationalOperator::LessThan.flipped(),RelationalOperator::GreaterThan);
+        assert_eq!(RelationalOperator::GreaterThanOrEqual.flipped(),RelationalOperator::LessThanOrEqual);
+        assert_eq!(RelationalOperator::NotEqual.flipped(),RelationalOperator::NotEqual);
+    }
+
+    #[test]
+    fn compare() {
+        assert!(RelationalOperator::LessThanOrEqual.compare(&1,&1));
+        assert!(!RelationalOperator::LessThan.compare(&1,&1));
+        assert!(RelationalOperator::NotEqual.compare(&1.0,&2.0));
+    }
+}
@@ -0,0 +1,134 @@
+use num_notation::Number;
+
+use crate::{
+    Equation, Expression, RelationalOperator,
+    manipulation::{Evaluate, Find, tidy},
+};
+
+use super::RearrangeError;
+
+/// Represents the solutions of an equation for a single variable
+#[derive(Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum Solution {
+    /// The equation holds for exactly one value of the variable , like `x = 2` for `2x + 3 = 7`
+    Unique(Expression),
+    /// The equation never holds , like `x + 1 = x + 2`
+    NoSolution,
+    /// The equation holds for every value of the variable (an identity) , like `2x = 2x`
+    Infinite,
+}
+
+impl std::fmt::Display for Solution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unique(value) => write!(f,"{value}"),
+            Self::NoSolution => write!(f,"no solution"),
+            Self::Infinite => write!(f,"infinitely many solutions"),
+        }
+    }
+}
+
+impl std::fmt::Debug for Solution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+impl Equation {
+    /// Solves a linear equation for `variable` by moving everything to one side and collecting it into `a * variable + b = 0`
+    ///
+    /// Other variables are treated as parameters , so `ax + b = c` is solved into `(c - b) / a`.
+    ///
+    /// Returns an error of type `RearrangeError::NotLinear` if `variable` appears in a non linear way (like `x^2` or `x * x`) ,
+    /// `RearrangeError::NotAnEquation` if the relation is not `=` and `RearrangeError::UnknownVariablesFound` if `variable`
+    /// cancels out while the rest still depends on other variables (like `x + y = x` for `x`)
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{Equation, Solution};
+    ///
+    /// let solution = Equation::try_from("2x + 3 = 7").unwrap().solve('x').unwrap();
+    /// assert_eq!(solution.to_string(),"2");
+    ///
+    /// assert!(matches!(Equation::try_from("x + 1 = x + 2").unwrap().solve('x'),Ok(Solution::NoSolution)));
+    /// assert!(matches!(Equation::try_from("2x = 2x").unwrap().solve('x'),Ok(Solution::Infinite)));
+    /// ```
+    pub fn solve(&self,variable : char) -> Result<Solution,RearrangeError> {
+        if self.relation != RelationalOperator::Equal {
+            return Err(RearrangeError::NotAnEquation(Box::new(self.clone())));
+        }
+
+        let expression = Expression::new_minus(self.left.clone(),self.right.clone());
+
+        let mut coefficients = expression.coefficients_in(&variable)
+            .filter(|coefficients| coefficients.len() <= 2)
+            .ok_or_else(|| RearrangeError::NotLinear(Box::new(self.clone()),variable))?
+            .into_iter();
+
+        let b = coefficients.next().unwrap_or_else(|| 0.into());
+        let a = coefficients.next().unwrap_or_else(|| 0.into());
+
+        if !a.is_zero() {
+            let value = match (-b).checked_div(a)?.evaluate().find() {
+                // Rounds floating point errors away , like 2.9999999999999996 for 0.1x = 0.3
+                Expression::Term(mut term) if term.variables.is_empty() => {
+                    if let Number::Decimal(value) = term.coefficient {
+                        term.coefficient = Number::Decimal(tidy(value));
+                    }
+                    term.into()
+                },
+                value => value
+            };
+
+            return Ok(Solution::Unique(value));
+        }
+
+        match b {
+            Expression::Term(term) if term.variables.is_empty() => match term.is_zero() {
+                true => Ok(Solution::Infinite),
+                false => Ok(Solution::NoSolution)
+            },
+            _ => Err(RearrangeError::UnknownVariablesFound(Box::new(self.clone())))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(input : &str) -> Result<Solution,RearrangeError> {
+        Equation::try_from(input).unwrap().solve('x')
+    }
+
+    #[test]
+    fn unique() {
+        assert_eq!(solve("2x + 3 = 7").unwrap().to_string(),"2");
+        assert_eq!(solve("3(x - 1) = x + 5").unwrap().to_string(),"4");
+        assert_eq!(solve("x / 4 = 2").unwrap().to_string(),"8");
+        assert_eq!(solve("5 = -x").unwrap().to_string(),"-5");
+        assert_eq!(solve("0.1x = 0.3").unwrap().to_string(),"3");
+        assert_eq!(solve("(x + 1)/2 = (x - 1)/3").unwrap().to_string(),"-5");
+    }
+
+    #[test]
+    fn no_solution_and_identity() {
+        assert_eq!(solve("x + 1 = x + 2"),Ok(Solution::NoSolution));
+        assert_eq!(solve("2x = 2x"),Ok(Solution::Infinite));
+        assert_eq!(solve("2(x + 1) = 2x + 2"),Ok(Solution::Infinite));
+    }
+
+    #[test]
+    fn parameters() {
+        assert_eq!(solve("2x + y = 3").unwrap().to_string(),Expression::try_from("(3 - y) / 2").unwrap().evaluate().find().to_string());
+        assert!(matches!(solve("x + y = x"),Err(RearrangeError::UnknownVariablesFound(_))));
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(solve("x^2 = 4"),Err(RearrangeError::NotLinear(_,'x'))));
+        assert!(matches!(solve("x * x = 4"),Err(RearrangeError::NotLinear(_,'x'))));
+        assert!(matches!(solve("2x < 4"),Err(RearrangeError::NotAnEquation(_))));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::Equation;
+
+/// A struct representing a system of equations which have to hold at the same time , like `{ 2x + y = 3; x - y = 1 }`
+#[derive(Clone, Default)]
+#[cfg_attr(test, derive(PartialEq))]
+pub struct EquationSystem {
+    /// The equations of the system in order
+    pub(crate) equations : Vec<Equation>,
+}
+
+impl EquationSystem {
+    /// Create a new system from the given equations
+    pub const fn new(equations : Vec<Equation>) -> Self {
+        Self { equations }
+    }
+
+    /// Gets the equations of the system
+    pub fn equations(&self) -> &[Equation] {
+        &self.equations
+    }
+
+    /// Gets a mutable reference to the equations of the system
+    pub fn equations_mut(&mut self) -> &mut Vec<Equation> {
+        &mut self.equations
+    }
+
+    /// Adds an equation to the system
+    pub fn push(&mut self,equation : Equation) {
+        self.equations.push(equation);
+    }
+
+    /// Gets the number of equations in the system
+    pub fn len(&self) -> usize {
+        self.equations.len()
+    }
+
+    /// Checks if the system has no equations
+    pub fn is_empty(&self) -> bool {
+        self.equations.is_empty()
+    }
+}
+
+impl FromIterator<Equation> for EquationSystem {
+    fn from_iter<I : IntoIterator<Item = Equation>>(iter : I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl IntoIterator for EquationSystem {
+    type Item = Equation;
+    type IntoIter = std::vec::IntoIter<Equation>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.equations.into_iter()
+    }
+}
+
+impl std::fmt::Display for EquationSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let equations = self.equations.iter().map(ToString::to_string).collect::<Vec<_>>();
+        write!(f,"{{ {} }}",equations.join("; "))
+    }
+}
+
+impl std::fmt::Debug for EquationSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    use crate::manipulation::{VariableAnalysis, EvaluteWithValues, Find};
+
+    #[test]
+    fn display_and_variables() {
+        let system = EquationSystem::try_from("{ 2x + y = 3; x - y = 1 }").unwrap();
+        assert_eq!(system.len(),2);
+        assert_eq!(system.to_string(),"{ 2x + y = 3; x - y = 1 }");
+        assert_eq!(system.get_unique_variables().into_iter().collect::<String>(),"xy");
+        assert!(system.contains_all(&mut ['x','y'].iter()));
+    }
+
+    #[test]
+    fn evaluate_every_equation() {
+        let system = EquationSystem::try_from("2x + y = 3\nx - y = z").unwrap();
+        let values = HashMap::from([('x',1.0),('y',1.0)]);
+
+        let system = system.evaluate_with_multiple_values(&values).find();
+        assert_eq!(system.to_string(),"{ 3 = 3; 0 = z }");
+    }
+}
@@ -81,15 +81,14 @@ impl Expression {
     fn collect_all_add_sub_term_till_mul_div(self,vec :&mut Vec<Term>,variables_to_count : &Variables) -> Option<Self> {
         match self {
             Self::Term(ref term) if term.contains_any_variable(&mut variables_to_count.keys()) => Some(self),
-            Self::Function(_) => todo!(),
+            Self::Function(_) => Some(self),
             Self::Term(term) => {
                 // + + 3 then + - 3 then expressino --3 is + 3 so nothing
                 vec.push(term); 
                 None // to tell tree has been 'removed' completely
             },
-            Self::Binary { ref operation, .. } 
-                if operation == &ArithmeticOperation::Mal || operation == &ArithmeticOperation::Durch => Some(self),
-            Self::Binary { operation, left,right } if operation == ArithmeticOperation::Plus => {
+            Self::Binary { operation : ArithmeticOperation::Mal | ArithmeticOperation::Durch | ArithmeticOperation::Pow, .. } => Some(self),
+            Self::Binary { operation : operation @ ArithmeticOperation::Plus, left,right } => {
                 let lhs = Self::collect_all_add_sub_term_till_mul_div(*left, vec, variables_to_count);
                 let rhs = Self::collect_all_add_sub_term_till_mul_div(*right, vec, variables_to_count);
                 match (lhs,rhs) {
@@ -102,8 +101,7 @@ impl Expression {
                 }
             },
 
-            Self::Binary { operation, left,right } 
-                if operation == ArithmeticOperation::Minus => {
+            Self::Binary { operation : operation @ ArithmeticOperation::Minus, left,right } => {
 
                 let lhs = Self::collect_all_add_sub_term_till_mul_div(*left, vec, variables_to_count);
                 let rhs = Self::collect_all_add_sub_term_till_mul_div(-*right, vec, variables_to_count);
@@ -116,8 +114,6 @@ impl Expression {
                     (Some(value),None) | (None,Some(value)) => Some(value)
                 }
             },
-
-            Self::Binary { .. } => unreachable!()
         }
     }
 }
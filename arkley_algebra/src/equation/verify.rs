@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use crate::{
+    Equation, Expression,
+    manipulation::{
+        Evaluate, Find, VariableSubstitution,
+        SingleVariableReplacements, MultipleVariableReplacements,
+        number_to_f64, EPSILON
+    },
+};
+
+/// Gets the value of the expression if it evaluates to a finite number
+fn value_of(expression : &Expression) -> Option<f64> {
+    match expression.clone().evaluate().find() {
+        Expression::Term(term) if term.variables.is_empty() => Some(number_to_f64(&term.coefficient)).filter(|value| value.is_finite()),
+        _ => None
+    }
+}
+
+impl Equation {
+    /// Checks if the equation holds once `values` are substituted into both sides
+    ///
+    /// Returns `Some(true)` or `Some(false)` if both sides evaluate to numbers and `None` (undetermined) if they do not ,
+    /// like when a variable has no value. Sides are treated as equal if they differ by less than `1e-9` relative to their size ,
+    /// use [Equation::holds_within] for another tolerance.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use arkley_algebra::Equation;
+    ///
+    /// let equation = Equation::try_from("2x + y = 7").unwrap();
+    /// assert_eq!(equation.holds(&HashMap::from([('x',2.0),('y',3.0)])),Some(true));
+    /// assert_eq!(equation.holds(&HashMap::from([('x',1.0),('y',3.0)])),Some(false));
+    /// assert_eq!(equation.holds(&HashMap::from([('x',2.0)])),None);
+    /// ```
+    pub fn holds<T>(&self,values : &HashMap<char,T>) -> Option<bool>
+        where Self : VariableSubstitution<T> ,
+        for<'a> MultipleVariableReplacements<'a,Self,T> : Find<Output = Self>
+    {
+        self.holds_within(values,EPSILON)
+    }
+
+    /// Checks if the equation holds once `values` are substituted like [Equation::holds] , treating both sides as equal if they differ
+    /// by at most `tolerance` relative to their size
+    pub fn holds_within<T>(&self,values : &HashMap<char,T>,tolerance : f64) -> Option<bool>
+        where Self : VariableSubstitution<T> ,
+        for<'a> MultipleVariableReplacements<'a,Self,T> : Find<Output = Self>
+    {
+        self.clone().replace_variables(values).find().truth(tolerance)
+    }
+
+    /// Checks if `variable = value` is a solution of the equation by substituting it into both sides
+    ///
+    /// Returns `None` if the result still depends on other variables. This can be used to discard extraneous roots ,
+    /// like `x = 9` for `x^0.5 = -3`.
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::Equation;
+    ///
+    /// let equation = Equation::try_from("x^2 - x >= 6").unwrap();
+    /// assert_eq!(equation.verify_solution('x',3.0),Some(true));
+    /// assert_eq!(equation.verify_solution('x',0.0),Some(false));
+    /// assert_eq!(Equation::try_from("x + y = 2").unwrap().verify_solution('x',1.0),None);
+    /// ```
+    pub fn verify_solution<T>(&self,variable : char,value : T) -> Option<bool>
+        where Self : VariableSubstitution<T> ,
+        SingleVariableReplacements<Self,T> : Find<Output = Self>
+    {
+        self.clone().replace_single_variable(&variable,value).find().truth(EPSILON)
+    }
+
+    /// Compares both sides if they are numbers , falling back to their difference so identities like `x + 1 > x` are still decided
+    fn truth(&self,tolerance : f64) -> Option<bool> {
+        let (left,right) = match (value_of(&self.left),value_of(&self.right)) {
+            (Some(left),Some(right)) => (left,right),
+            _ => (value_of(&Expression::new_minus(self.left.clone(),self.right.clone()))?,0.0)
+        };
+
+        let scale = left.abs().max(right.abs()).max(1.0);
+
+        Some(match (left - right).abs() <= tolerance * scale {
+            true => self.relation.compare(&0.0,&0.0),
+            false => self.relation.compare(&left,&right)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn holds() {
+        let equation = Equation::try_from("x^2 + y = 10").unwrap();
+        assert_eq!(equation.holds(&HashMap::from([('x',3.0),('y',1.0)])),Some(true));
+        assert_eq!(equation.holds(&HashMap::from([('x',1.0),('y',1.0)])),Some(false));
+        assert_eq!(equation.holds(&HashMap::from([('x',3.0)])),None);
+
+        assert_eq!(Equation::try_from("x + 1 > x").unwrap().holds::<f64>(&HashMap::new()),Some(true));
+        assert_eq!(Equation::try_from("1/x = 2").unwrap().verify_solution('x',0.0),None);
+    }
+
+    #[test]
+    fn tolerance() {
+        let equation = Equation::try_from("3x = 1").unwrap();
+        let values = HashMap::from([('x',0.3333)]);
+
+        assert_eq!(equation.holds(&values),Some(false));
+        assert_eq!(equation.holds_within(&values,1e-3),Some(true));
+        assert_eq!(Equation::try_from("3x != 1").unwrap().holds_within(&values,1e-3),Some(false));
+    }
+
+    #[test]
+    fn verify_solution() {
+        let equation = Equation::try_from("x^2 - 5x + 6 = 0").unwrap();
+        assert_eq!(equation.verify_solution('x',2.0),Some(true));
+        assert_eq!(equation.verify_solution('x',3),Some(true));
+        assert_eq!(equation.verify_solution('x',4.0),Some(false));
+
+        assert_eq!(Equation::try_from("2x < 4").unwrap().verify_solution('x',2.0),Some(false));
+        assert_eq!(Equation::try_from("2x <= 4").unwrap().verify_solution('x',2.0),Some(true));
+    }
+}
@@ -0,0 +1,105 @@
+use thiserror::Error;
+
+use num_notation::Number;
+
+use crate::{Expression, Numeric, radicals::NotRationalError};
+
+#[cfg(feature="parse")]
+use crate::{ParseError, ContextError};
+
+#[cfg(feature="equation")]
+use crate::RearrangeError;
+
+/// Represents errors that can occur when doing arithmetic with terms and expressions.
+///
+/// It is returned by the `checked_*` methods of [crate::Term] and [Expression] and while evaluating.
+#[derive(Debug,Error,Clone)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum AlgebraError<N : Numeric = Number> {
+    /// Indicates that an expression was divided by `0`
+    ///
+    /// Provides the expression which was being divided
+    #[error("{} can not be divided by 0",.0)]
+    DivisionByZero(Expression<N>),
+
+    /// Indicates that a power is undefined like `0^0`
+    ///
+    /// Provides the base and the exponent
+    #[error("{}^{} is undefined",.0,.1)]
+    UndefinedPower(Box<Expression<N>>,Box<Expression<N>>),
+
+    /// Indicates that the result contains a coefficient or exponent which is not a finite number (like `inf` or `NaN`)
+    ///
+    /// Provides the resulting expression
+    #[error("{} contains a number which is not finite",.0)]
+    NonFinite(Expression<N>),
+
+    /// Indicates that no value was given for a variable while evaluating
+    ///
+    /// Provides the variable
+    #[error("no value was given for {}",.0)]
+    MissingValue(char),
+
+    /// Indicates that a function can not be evaluated in the current mode (like an unknown function with intervals)
+    ///
+    /// Provides the name of the function
+    #[error("{} can not be evaluated",.0)]
+    UnsupportedFunction(String),
+}
+
+/// Represents every error which can be returned by this crate , so all of them can be handled in one place
+///
+/// The errors of the different modules (like [AlgebraError] or the errors of parsing and rearranging) convert into it using `?`
+///
+/// # Example
+/// ```
+/// use arkley_algebra::{ArkleyError, Expression};
+///
+/// fn parse(input : &str) -> Result<Expression,ArkleyError> {
+///     Ok(Expression::try_from(input)?)
+/// }
+///
+/// assert!(matches!(parse("5 + (2 * 3"),Err(ArkleyError::Parse(_))));
+/// ```
+#[derive(Debug,Error)]
+#[cfg_attr(test, derive(PartialEq))]
+pub enum ArkleyError {
+    /// Indicates that an arithmetic operation failed , see [AlgebraError]
+    #[error(transparent)]
+    Algebra(#[from] AlgebraError),
+
+    /// Indicates that the coefficient `a` of a quadratic is zero , making concavity undefined
+    #[error("the coefficient of the squared term is 0 , so it is not a quadratic")]
+    UndefinedConcavity,
+
+    /// Indicates that there are multiple variables with exponents of 2 in the input , hence unable to 'decide' the coefficient
+    #[error("more than one variable is squared , so the coefficients of the quadratic are ambiguous")]
+    MultipleVariablesToThePowerOf2,
+
+    /// Indicates that the input does not represent a valid quadratic
+    #[error("the input is not a quadratic")]
+    InvalidQuadratic,
+
+    /// Indicates that a value can not be written as an exact rational number
+    #[error(transparent)]
+    NotRational(#[from] NotRationalError),
+
+    /// Indicates that an exact calculation (like with [crate::radicals::Surd]) overflowed as the numbers involved are too large
+    #[error("the numbers are too large to be calculated exactly")]
+    Overflow,
+
+    /// Indicates that the input could not be parsed , see [ParseError]
+    #[cfg(feature="parse")]
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+
+    /// Indicates that a statement could not be run in a [crate::Context] , see [ContextError]
+    #[cfg(feature="parse")]
+    #[error(transparent)]
+    Context(#[from] ContextError),
+
+    /// Indicates that an equation could not be rearranged or solved , see [RearrangeError]
+    #[cfg(feature="equation")]
+    #[error(transparent)]
+    Rearrange(#[from] RearrangeError),
+}
@@ -20,9 +20,11 @@
 
 mod core;
 mod arithmetics;
+mod error;
 
 pub use core::*;
 pub use arithmetics::*;
+pub use error::*;
 
 
 /// Module containing manipulations for algebra
@@ -31,6 +33,15 @@ pub mod manipulation;
 /// Module containing `quadratics` for both algebra and numerics
 pub mod quadratics;
 
+/// Module containing `cubics` for both algebra and numerics
+pub mod cubics;
+
+/// Module containing `quartics` for both algebra and numerics
+pub mod quartics;
+
+/// Module containing `radicals` (surds) for exact square roots
+pub mod radicals;
+
 #[cfg(feature="parse")]
 mod parser;
 
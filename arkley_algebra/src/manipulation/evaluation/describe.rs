@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use num_notation::Pow;
 
 use arkley_describe::{
     Describe,
@@ -8,7 +9,7 @@ use arkley_describe::{
 
 use crate::{Term, Expression, Equation, ArithmeticOperation};
 
-use super::{Evaluate, EvaluateNoValues};
+use super::{Evaluate, EvaluateNoValues, super::Find};
 
 impl Describe for EvaluateNoValues<Term> {
     fn describe(self,_:&StaticLoader,_: &LanguageIdentifier) -> Option<Steps> {
@@ -35,6 +36,7 @@ impl ArithmeticOperation {
             Self::Minus => left - right,
             Self::Mal => left * right,
             Self::Durch => left / right,
+            Self::Pow => left.pow(right),
         };
 
         args.insert("ans",ans.to_string().into());
@@ -52,7 +54,7 @@ impl Expression {
             Expression::Term(_) => Some(self),
             Expression::Binary { operation, left, right } => 
                 operation.operate_on_describe(resource,lang,*left,*right,steps),
-            Expression::Function(func) => todo!()
+            Expression::Function(func) => Some(func.evaluate().find())
         }   
     }
 }
@@ -1,8 +1,11 @@
+use num_notation::Pow;
+
 use crate::{
-    Term, Expression, Function, ArithmeticOperation, 
+    Term, Expression, Function, ArithmeticOperation, AlgebraError, Numeric,
     manipulation::{
         VariableSubstitution, 
         Find, 
+        TryFind, 
         SingleVariableReplacements, 
         MultipleVariableReplacements
     }
@@ -13,29 +16,37 @@ use super::{
     EvaluateWithSingleValue, EvaluateWithMultipleValues, EvaluteWithValues
 };
 
-impl Evaluate for Term {}
-impl Evaluate for Expression {}
-impl Evaluate for Function {}
+impl<N : Numeric> Evaluate for Term<N> {}
+impl<N : Numeric> Evaluate for Expression<N> {}
+impl<N : Numeric> Evaluate for Function<N> {}
 
 #[cfg(feature="equation")]
 impl Evaluate for crate::Equation {}
+#[cfg(feature="equation")]
+impl Evaluate for crate::ChainedRelation {}
+#[cfg(feature="equation")]
+impl Evaluate for crate::EquationSystem {}
 
-impl<T> EvaluteWithValues<T> for Term where Self : VariableSubstitution<T> {}
-impl<T> EvaluteWithValues<T> for Expression where Self : VariableSubstitution<T> {}
-impl<T> EvaluteWithValues<T> for Function where Self : VariableSubstitution<T> {}
+impl<N : Numeric,T> EvaluteWithValues<T> for Term<N> where Self : VariableSubstitution<T> {}
+impl<N : Numeric,T> EvaluteWithValues<T> for Expression<N> where Self : VariableSubstitution<T> {}
+impl<N : Numeric,T> EvaluteWithValues<T> for Function<N> where Self : VariableSubstitution<T> {}
 
 #[cfg(feature="equation")]
 impl<T> EvaluteWithValues<T> for crate::Equation where Self : VariableSubstitution<T> {}
+#[cfg(feature="equation")]
+impl<T> EvaluteWithValues<T> for crate::ChainedRelation where Self : VariableSubstitution<T> {}
+#[cfg(feature="equation")]
+impl<T> EvaluteWithValues<T> for crate::EquationSystem where Self : VariableSubstitution<T> {}
 
-impl Find for EvaluateNoValues<Term> {
-    type Output = Term;
+impl<N : Numeric> Find for EvaluateNoValues<Term<N>> {
+    type Output = Term<N>;
     fn find(self) -> Self::Output {
         self.0
     }
 }
 
-impl Find for EvaluateNoValues<Function> {
-    type Output = Expression;
+impl<N : Numeric> Find for EvaluateNoValues<Function<N>> {
+    type Output = Expression<N>;
 
     fn find(self) -> Self::Output {
         (self.0.closure)(self.0)
@@ -43,18 +54,29 @@ impl Find for EvaluateNoValues<Function> {
 }
 
 impl ArithmeticOperation {
-    fn operate_on(&self,left : Expression,right : Expression) -> Expression {
+    fn operate_on<N : Numeric>(&self,left : Expression<N>,right : Expression<N>) -> Expression<N> {
         match self {
             Self::Plus => left + right,
             Self::Minus => left - right,
             Self::Mal => left * right,
             Self::Durch => left / right,
+            Self::Pow => left.pow(right),
+        }
+    }
+
+    fn try_operate_on<N : Numeric>(&self,left : Expression<N>,right : Expression<N>) -> Result<Expression<N>,AlgebraError<N>> {
+        match self {
+            Self::Plus => left.checked_add(right),
+            Self::Minus => left.checked_sub(right),
+            Self::Mal => left.checked_mul(right),
+            Self::Durch => left.checked_div(right),
+            Self::Pow => left.checked_pow(right),
         }
     }
 }
 
-impl Find for EvaluateNoValues<Expression> {
-    type Output = Expression;
+impl<N : Numeric> Find for EvaluateNoValues<Expression<N>> {
+    type Output = Expression<N>;
     fn find(self) -> Self::Output {
         match self.0 {
             Expression::Term(_) => self.0,
@@ -65,6 +87,38 @@ impl Find for EvaluateNoValues<Expression> {
     }
 }
 
+impl<N : Numeric> TryFind for EvaluateNoValues<Term<N>> {
+    type Output = Term<N>;
+    type Error = AlgebraError<N>;
+    fn try_find(self) -> Result<Self::Output,Self::Error> {
+        match Expression::from(self.0).ensure_finite()? {
+            Expression::Term(term) => Ok(term),
+            expression => Err(AlgebraError::NonFinite(expression))
+        }
+    }
+}
+
+impl<N : Numeric> TryFind for EvaluateNoValues<Function<N>> {
+    type Output = Expression<N>;
+    type Error = AlgebraError<N>;
+    fn try_find(self) -> Result<Self::Output,Self::Error> {
+        self.find().ensure_finite()
+    }
+}
+
+impl<N : Numeric> TryFind for EvaluateNoValues<Expression<N>> {
+    type Output = Expression<N>;
+    type Error = AlgebraError<N>;
+    fn try_find(self) -> Result<Self::Output,Self::Error> {
+        match self.0 {
+            Expression::Term(_) => self.0.ensure_finite(),
+            Expression::Binary { operation, left, right } => 
+                operation.try_operate_on(left.evaluate().try_find()?, right.evaluate().try_find()?),
+            Expression::Function(func) => func.evaluate().try_find()
+        }   
+    }
+}
+
 #[cfg(feature="equation")]
 impl Find for EvaluateNoValues<crate::Equation> {
     type Output = crate::Equation;
@@ -76,6 +130,60 @@ impl Find for EvaluateNoValues<crate::Equation> {
     }
 }
 
+#[cfg(feature="equation")]
+impl TryFind for EvaluateNoValues<crate::Equation> {
+    type Output = crate::Equation;
+    type Error = AlgebraError;
+    fn try_find(self) -> Result<Self::Output,Self::Error> {
+        let mut eq = self.0;
+        eq.left = eq.left.evaluate().try_find()?;
+        eq.right = eq.right.evaluate().try_find()?;
+        Ok(eq)
+    }
+}
+
+#[cfg(feature="equation")]
+impl Find for EvaluateNoValues<crate::EquationSystem> {
+    type Output = crate::EquationSystem;
+    fn find(self) -> Self::Output {
+        self.0.into_iter().map(|equation| equation.evaluate().find()).collect()
+    }
+}
+
+#[cfg(feature="equation")]
+impl TryFind for EvaluateNoValues<crate::EquationSystem> {
+    type Output = crate::EquationSystem;
+    type Error = AlgebraError;
+    fn try_find(self) -> Result<Self::Output,Self::Error> {
+        self.0.into_iter().map(|equation| equation.evaluate().try_find()).collect()
+    }
+}
+
+#[cfg(feature="equation")]
+impl Find for EvaluateNoValues<crate::ChainedRelation> {
+    type Output = crate::ChainedRelation;
+    fn find(self) -> Self::Output {
+        let mut chain = self.0;
+        chain.first = chain.first.evaluate().find();
+        chain.rest = chain.rest.into_iter().map(|(relation,part)| (relation,part.evaluate().find())).collect();
+        chain
+    }
+}
+
+#[cfg(feature="equation")]
+impl TryFind for EvaluateNoValues<crate::ChainedRelation> {
+    type Output = crate::ChainedRelation;
+    type Error = AlgebraError;
+    fn try_find(self) -> Result<Self::Output,Self::Error> {
+        let mut chain = self.0;
+        chain.first = chain.first.evaluate().try_find()?;
+        chain.rest = chain.rest.into_iter()
+            .map(|(relation,part)| Ok((relation,part.evaluate().try_find()?)))
+            .collect::<Result<_,AlgebraError>>()?;
+        Ok(chain)
+    }
+}
+
 impl<T,V> Find for EvaluateWithSingleValue<T,V> 
     where SingleVariableReplacements<T,V> : Find , 
     <SingleVariableReplacements<T, V> as Find>::Output : Evaluate,
@@ -97,3 +205,69 @@ impl<'a,T,V> Find for EvaluateWithMultipleValues<'a,T,V>
         self.0.find().evaluate().find()
     }
 }
+
+impl<T,V> TryFind for EvaluateWithSingleValue<T,V> 
+    where SingleVariableReplacements<T,V> : Find , 
+    <SingleVariableReplacements<T, V> as Find>::Output : Evaluate,
+    EvaluateNoValues<<SingleVariableReplacements<T, V> as Find>::Output> : TryFind
+{
+    type Output = <EvaluateNoValues<<SingleVariableReplacements<T, V> as Find>::Output> as TryFind>::Output;
+    type Error = <EvaluateNoValues<<SingleVariableReplacements<T, V> as Find>::Output> as TryFind>::Error;
+    fn try_find(self) -> Result<Self::Output,Self::Error> {
+        EvaluateNoValues(self.0.find()).try_find()
+    }
+}
+
+impl<'a,T,V> TryFind for EvaluateWithMultipleValues<'a,T,V> 
+    where MultipleVariableReplacements<'a,T,V> : Find , 
+    <MultipleVariableReplacements<'a,T, V> as Find>::Output : Evaluate,
+    EvaluateNoValues<<MultipleVariableReplacements<'a,T, V> as Find>::Output> : TryFind
+{
+    type Output = <EvaluateNoValues<<MultipleVariableReplacements<'a,T, V> as Find>::Output> as TryFind>::Output;
+    type Error = <EvaluateNoValues<<MultipleVariableReplacements<'a,T, V> as Find>::Output> as TryFind>::Error;
+    fn try_find(self) -> Result<Self::Output,Self::Error> {
+        EvaluateNoValues(self.0.find()).try_find()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_evaluate_division_by_zero() {
+        let expr = Expression::new_durch(Term::from(1.0).into(),Expression::new_minus(Term::from(2.0).into(),Term::from(2.0).into()));
+        assert!(matches!(expr.evaluate().try_find(),Err(AlgebraError::DivisionByZero(_))));
+
+        let expr = Expression::new_durch(Term::from(1.0).into(),Term::from('x').into());
+        let result = expr.evaluate_with_single_value(&'x',0.0).try_find();
+        assert!(matches!(result,Err(AlgebraError::DivisionByZero(_))));
+
+        let expr = Expression::new_durch(Term::from(1.0).into(),Term::from('x').into());
+        assert_eq!(expr.evaluate_with_single_value(&'x',2.0).try_find().unwrap().to_string(),"0.5");
+    }
+
+    #[test]
+    fn try_evaluate_undefined_power() {
+        let expr = Expression::new_pow(Term::from('x').into(),Term::from(0.0).into());
+        let result = expr.evaluate_with_single_value(&'x',0.0).try_find();
+        assert!(matches!(result,Err(AlgebraError::UndefinedPower(..))));
+    }
+
+    #[test]
+    #[cfg(feature="parse")]
+    fn undefined_values_do_not_overflow() {
+        let find = |input : &str| Expression::try_from(input).unwrap().evaluate().find().to_string();
+        let try_find = |input : &str| Expression::try_from(input).unwrap().evaluate().try_find().map(|expr| expr.to_string());
+
+        assert_eq!(find("y/(0/0)"),"y/(0/0)");
+        assert!(matches!(try_find("y/(0/0)"),Err(AlgebraError::DivisionByZero(_))));
+        assert_eq!(find("(x + 1)/(0/0)"),"(1 + x)/(0/0)");
+
+        assert_eq!(find("(-y)^0.5"),"(-1y)^0.5");
+        assert_eq!(try_find("(-y)^0.5").unwrap(),"(-1y)^0.5");
+        assert_eq!(find("(y/-2)^0.5"),"(-1y)^0.5/1.4142135623730951");
+        assert!(try_find("(y/-2)^0.5").is_ok());
+        assert_eq!(find("(-8)^(1/3)"),"-2");
+    }
+}
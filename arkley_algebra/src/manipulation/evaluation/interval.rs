@@ -0,0 +1,445 @@
+use std::collections::HashMap;
+use std::f64::consts::PI;
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+use crate::{Term, Expression, Function, ArithmeticOperation, AlgebraError};
+use crate::manipulation::{
+    number_to_f64, Find, VariableSubstitution,
+    SingleVariableReplacements, MultipleVariableReplacements
+};
+
+use super::{EvaluateWithSingleValue, EvaluateWithMultipleValues};
+
+/// A closed range of real numbers `[lower, upper]` used to evaluate expressions with uncertain values.
+///
+/// When it is passed to [super::EvaluteWithValues::evaluate_with_multiple_values] the bounds are propagated through
+/// the expression so the result is a range containing every possible value , for example
+/// `x^2 - 1` with `x = [-1, 2]` results in `[-1, 3]`.
+///
+/// **Note** : The bounds may be infinite when dividing by a range which touches `0`
+#[derive(Clone,Copy,PartialEq)]
+pub struct Interval {
+    lower : f64,
+    upper : f64,
+}
+
+impl Interval {
+    /// Creates a new interval , the bounds are swapped if `lower > upper`
+    pub fn new(lower : f64,upper : f64) -> Self {
+        Self { lower : lower.min(upper) , upper : lower.max(upper) }
+    }
+
+    /// Creates an interval containing only `value`
+    pub const fn point(value : f64) -> Self {
+        Self { lower : value , upper : value }
+    }
+
+    /// Creates an interval for a measured value like `5 ± 0.1`
+    pub fn with_tolerance(value : f64,tolerance : f64) -> Self {
+        Self::new(value - tolerance.abs(),value + tolerance.abs())
+    }
+
+    /// The interval containing every real number
+    pub const fn entire() -> Self {
+        Self { lower : f64::NEG_INFINITY , upper : f64::INFINITY }
+    }
+
+    /// Gets the lower bound
+    pub const fn lower(&self) -> f64 {
+        self.lower
+    }
+
+    /// Gets the upper bound
+    pub const fn upper(&self) -> f64 {
+        self.upper
+    }
+
+    /// Gets the distance between both bounds
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+
+    /// Gets the value in the middle of both bounds
+    pub fn midpoint(&self) -> f64 {
+        (self.lower + self.upper) / 2.0
+    }
+
+    /// Checks if `value` lies within the interval
+    pub fn contains(&self,value : f64) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+
+    /// Checks if the interval contains only a single value
+    pub fn is_point(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Creates the smallest interval containing all of the values
+    fn hull<I : IntoIterator<Item = f64>>(values : I) -> Self {
+        values.into_iter().fold(Self { lower : f64::INFINITY , upper : f64::NEG_INFINITY },|acc,value| Self {
+            lower : acc.lower.min(value),
+            upper : acc.upper.max(value)
+        })
+    }
+
+    /// Divides the intervals , returning `None` if `other` is `[0, 0]`
+    ///
+    /// If `other` contains `0` the result is unbounded , like `[1, 2] / [0, 1] = [1, inf]`
+    pub fn checked_div(self,other : Self) -> Option<Self> {
+        let (lower,upper) = (other.lower,other.upper);
+
+        let reciprocal = if lower == 0.0 && upper == 0.0 {
+            return None;
+        } else if lower > 0.0 || upper < 0.0 {
+            Self::new(1.0 / upper,1.0 / lower)
+        } else if lower == 0.0 {
+            Self::new(1.0 / upper,f64::INFINITY)
+        } else if upper == 0.0 {
+            Self::new(f64::NEG_INFINITY,1.0 / lower)
+        } else {
+            // The result would be the union of two unbounded intervals so every number is possible
+            return Some(match self == Self::point(0.0) {
+                true => self,
+                false => Self::entire()
+            });
+        };
+
+        Some(self * reciprocal)
+    }
+
+    /// Raises the interval to a constant power
+    ///
+    /// Returns `None` if the result is not defined for the whole interval , like `[-1, 4]^0.5`
+    pub fn checked_pow(self,exponent : f64) -> Option<Self> {
+        if exponent == 0.0 {
+            return match self.contains(0.0) {
+                true => None,
+                false => Some(Self::point(1.0))
+            };
+        }
+
+        let power = match exponent.fract() != 0.0 {
+            // Fractional powers like x^0.5 are only defined for x >= 0
+            true if self.lower < 0.0 => return None,
+            true => Self::monotonic(self,exponent.abs()),
+            // Even powers have their minimum at 0 , like x^2 with x = [-1, 2]
+            false if (exponent.abs() as i64) % 2 == 0 && self.contains(0.0) => Self::new(0.0,self.lower.abs().max(self.upper.abs()).powf(exponent.abs())),
+            false => Self::monotonic(self,exponent.abs())
+        };
+
+        match exponent < 0.0 {
+            true => Self::point(1.0).checked_div(power),
+            false => Some(power)
+        }
+    }
+
+    fn monotonic(self,exponent : f64) -> Self {
+        Self::new(self.lower.powf(exponent),self.upper.powf(exponent))
+    }
+
+    /// Raises the interval to the power of another interval
+    ///
+    /// Returns `None` if the base is not positive (unless the exponent is a single value)
+    pub fn checked_pow_interval(self,exponent : Self) -> Option<Self> {
+        if exponent.is_point() {
+            return self.checked_pow(exponent.lower);
+        }
+
+        if self.lower <= 0.0 {
+            return None;
+        }
+
+        // For positive bases x^y is monotonic in both x and y so the extremes are at the corners
+        Some(Self::hull([
+            self.lower.powf(exponent.lower),
+            self.lower.powf(exponent.upper),
+            self.upper.powf(exponent.lower),
+            self.upper.powf(exponent.upper),
+        ]))
+    }
+
+    /// Calculates the range of `cos(x)` over the interval
+    pub fn cos(self) -> Self {
+        if self.width() >= 2.0 * PI {
+            return Self::new(-1.0,1.0);
+        }
+
+        let mut result = Self::hull([self.lower.cos(),self.upper.cos()]);
+
+        // cos has its maximum at 2kπ and its minimum at (2k + 1)π
+        if (self.lower / (2.0 * PI)).ceil() * 2.0 * PI <= self.upper {
+            result.upper = 1.0;
+        }
+
+        if ((self.lower - PI) / (2.0 * PI)).ceil() * 2.0 * PI + PI <= self.upper {
+            result.lower = -1.0;
+        }
+
+        result
+    }
+
+    /// Calculates the range of `sin(x)` over the interval
+    pub fn sin(self) -> Self {
+        (self - Self::point(PI / 2.0)).cos()
+    }
+
+    /// Calculates the range of `|x|` over the interval
+    pub fn abs(self) -> Self {
+        match self.contains(0.0) {
+            true => Self::new(0.0,self.lower.abs().max(self.upper.abs())),
+            false => Self::new(self.lower.abs(),self.upper.abs())
+        }
+    }
+
+    /// Applies a function which is increasing on its whole domain , returning `None` if `lower_limit` is not within the interval
+    fn increasing<F : Fn(f64) -> f64>(self,lower_limit : f64,f : F) -> Option<Self> {
+        match self.lower < lower_limit {
+            true => None,
+            false => Some(Self::new(f(self.lower),f(self.upper)))
+        }
+    }
+
+    /// Evaluates a known function like `sin` or `sqrt` over the interval
+    fn apply_function(self,name : &str) -> Option<Self> {
+        match name {
+            "sin" => Some(self.sin()),
+            "cos" => Some(self.cos()),
+            "abs" => Some(self.abs()),
+            "sqrt" => self.increasing(0.0,f64::sqrt),
+            "exp" => self.increasing(f64::NEG_INFINITY,f64::exp),
+            "ln" => self.increasing(0.0,f64::ln),
+            _ => None
+        }
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+    fn add(self,other : Self) -> Self {
+        Self::new(self.lower + other.lower,self.upper + other.upper)
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+    fn sub(self,other : Self) -> Self {
+        Self::new(self.lower - other.upper,self.upper - other.lower)
+    }
+}
+
+impl Neg for Interval {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.upper,-self.lower)
+    }
+}
+
+impl Mul for Interval {
+    type Output = Self;
+    fn mul(self,other : Self) -> Self {
+        // 0 * inf is taken as 0 as the unbounded side comes from dividing by values close to 0
+        let product = |a : f64,b : f64| match a == 0.0 || b == 0.0 {
+            true => 0.0,
+            false => a * b
+        };
+
+        Self::hull([
+            product(self.lower,other.lower),
+            product(self.lower,other.upper),
+            product(self.upper,other.lower),
+            product(self.upper,other.upper),
+        ])
+    }
+}
+
+impl Div for Interval {
+    type Output = Self;
+
+    /// Divides the intervals , see [Interval::checked_div]
+    ///
+    /// Just like dividing `0.0` by `0.0` this does not panic , dividing by `[0, 0]` results in `[NaN, NaN]`
+    fn div(self,other : Self) -> Self {
+        self.checked_div(other).unwrap_or(Self::point(f64::NAN))
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(value : f64) -> Self {
+        Self::point(value)
+    }
+}
+
+impl std::fmt::Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"[{}, {}]",self.lower,self.upper)
+    }
+}
+
+impl std::fmt::Debug for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+impl Term {
+    fn interval_with(&self,values : &HashMap<char,Interval>) -> Result<Interval,AlgebraError> {
+        let mut result = Interval::point(number_to_f64(&self.coefficient));
+
+        for (variable,exponent) in self.variables.iter() {
+            let value = values.get(variable).ok_or(AlgebraError::MissingValue(*variable))?;
+            let power = value.checked_pow(number_to_f64(exponent))
+                .ok_or_else(|| AlgebraError::UndefinedPower(Box::new((*variable).into()),Box::new(exponent.clone().into())))?;
+
+            result = result * power;
+        }
+
+        Ok(result)
+    }
+}
+
+impl Function {
+    fn interval_with(&self,values : &HashMap<char,Interval>) -> Result<Interval,AlgebraError> {
+        let unsupported = || AlgebraError::UnsupportedFunction(self.name.clone());
+
+        let mut arguments = HashMap::new();
+        for (variable,argument) in self.arguments.iter() {
+            let value = match argument {
+                Some(expression) => expression.interval_with(values)?,
+                None => *values.get(variable).ok_or(AlgebraError::MissingValue(*variable))?
+            };
+
+            arguments.insert(*variable,value);
+        }
+
+        match &self.expression {
+            Some(expression) => expression.interval_with(&arguments),
+            None => match arguments.values().next() {
+                Some(argument) if arguments.len() == 1 => argument.apply_function(&self.name).ok_or_else(unsupported),
+                _ => Err(unsupported())
+            }
+        }
+    }
+}
+
+impl Expression {
+    fn interval_with(&self,values : &HashMap<char,Interval>) -> Result<Interval,AlgebraError> {
+        match self {
+            Expression::Term(term) => term.interval_with(values),
+            Expression::Function(func) => func.interval_with(values),
+            Expression::Binary { operation, left, right } => {
+                let lvalue = left.interval_with(values)?;
+                let rvalue = right.interval_with(values)?;
+
+                match operation {
+                    ArithmeticOperation::Plus => Ok(lvalue + rvalue),
+                    ArithmeticOperation::Minus => Ok(lvalue - rvalue),
+                    ArithmeticOperation::Mal => Ok(lvalue * rvalue),
+                    ArithmeticOperation::Durch => lvalue.checked_div(rvalue)
+                        .ok_or_else(|| AlgebraError::DivisionByZero(*left.clone())),
+                    ArithmeticOperation::Pow => lvalue.checked_pow_interval(rvalue)
+                        .ok_or_else(|| AlgebraError::UndefinedPower(left.clone(),right.clone())),
+                }
+            }
+        }
+    }
+}
+
+impl VariableSubstitution<Interval> for Expression {}
+
+// As intervals can not be stored within an expression , replacing the variables already evaluates the expression
+impl Find for SingleVariableReplacements<Expression,Interval> {
+    type Output = Result<Interval,AlgebraError>;
+    fn find(self) -> Self::Output {
+        self.source.interval_with(&HashMap::from([(self.variable,self.value)]))
+    }
+}
+
+impl Find for MultipleVariableReplacements<'_,Expression,Interval> {
+    type Output = Result<Interval,AlgebraError>;
+    fn find(self) -> Self::Output {
+        self.source.interval_with(self.values)
+    }
+}
+
+impl Find for EvaluateWithSingleValue<Expression,Interval> {
+    type Output = Result<Interval,AlgebraError>;
+    fn find(self) -> Self::Output {
+        self.0.find()
+    }
+}
+
+impl Find for EvaluateWithMultipleValues<'_,Expression,Interval> {
+    type Output = Result<Interval,AlgebraError>;
+    fn find(self) -> Self::Output {
+        self.0.find()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use num_notation::Number;
+    use crate::manipulation::EvaluteWithValues;
+
+    fn x_squared_minus_one() -> Expression {
+        let x_squared = Term::create_single_variable_term(Number::Decimal(1.0),'x',Number::Decimal(2.0));
+        Expression::new_minus(x_squared.into(),Term::from(1.0).into())
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Interval::new(1.0,2.0);
+        let b = Interval::new(-3.0,4.0);
+
+        assert_eq!(a + b,Interval::new(-2.0,6.0));
+        assert_eq!(a - b,Interval::new(-3.0,5.0));
+        assert_eq!(a * b,Interval::new(-6.0,8.0));
+        assert_eq!(a / Interval::new(2.0,4.0),Interval::new(0.25,1.0));
+    }
+
+    #[test]
+    fn division_by_intervals_containing_zero() {
+        let a = Interval::new(1.0,2.0);
+
+        assert_eq!(a.checked_div(Interval::new(0.0,1.0)),Some(Interval::new(1.0,f64::INFINITY)));
+        assert_eq!(a.checked_div(Interval::new(-1.0,0.0)),Some(Interval::new(f64::NEG_INFINITY,-1.0)));
+        assert_eq!(a.checked_div(Interval::new(-1.0,1.0)),Some(Interval::entire()));
+        assert_eq!(a.checked_div(Interval::point(0.0)),None);
+        assert_eq!((a / Interval::point(0.0)).to_string(),"[NaN, NaN]");
+    }
+
+    #[test]
+    fn powers_and_functions() {
+        assert_eq!(Interval::new(-1.0,2.0).checked_pow(2.0),Some(Interval::new(0.0,4.0)));
+        assert_eq!(Interval::new(-2.0,1.0).checked_pow(3.0),Some(Interval::new(-8.0,1.0)));
+        assert_eq!(Interval::new(-1.0,4.0).checked_pow(0.5),None);
+        assert_eq!(Interval::new(1.0,4.0).checked_pow(0.5),Some(Interval::new(1.0,2.0)));
+        assert_eq!(Interval::new(0.0,4.0).checked_pow(0.5),Some(Interval::new(0.0,2.0)));
+        assert_eq!(Interval::point(0.0).checked_pow(0.5),Some(Interval::point(0.0)));
+        assert_eq!(Interval::new(0.0,4.0).checked_pow(-0.5),Some(Interval::new(0.5,f64::INFINITY)));
+
+        assert_eq!(Interval::new(0.0,PI).sin().upper(),1.0);
+        assert_eq!(Interval::new(-0.5,0.5).cos().upper(),1.0);
+        assert_eq!(Interval::new(3.0,3.5).cos().lower(),-1.0);
+    }
+
+    #[test]
+    fn evaluate_expression() {
+        let values = HashMap::from([('x',Interval::new(-1.0,2.0))]);
+        let result = x_squared_minus_one().evaluate_with_multiple_values(&values).find();
+        assert_eq!(result.unwrap(),Interval::new(-1.0,3.0));
+
+        // 1/(x^2 - 1) with x = ±1 is divided by [0, 0]
+        let expr = Expression::new_durch(Term::from(1.0).into(),x_squared_minus_one());
+        let values = HashMap::from([('x',Interval::point(1.0))]);
+        assert!(matches!(expr.evaluate_with_multiple_values(&values).find(),Err(AlgebraError::DivisionByZero(_))));
+
+        let values = HashMap::from([('y',Interval::point(1.0))]);
+        assert_eq!(x_squared_minus_one().evaluate_with_multiple_values(&values).find(),Err(AlgebraError::MissingValue('x')));
+        // x^0.5 with x = [0, 4] includes the root at 0
+        let root : Expression = Term::create_single_variable_term(Number::Decimal(1.0),'x',Number::Decimal(0.5)).into();
+        let values = HashMap::from([('x',Interval::new(0.0,4.0))]);
+        assert_eq!(root.evaluate_with_multiple_values(&values).find(),Ok(Interval::new(0.0,2.0)));
+    }
+}
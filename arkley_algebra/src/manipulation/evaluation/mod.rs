@@ -1,8 +1,10 @@
 mod inner;
 mod gen;
+mod interval;
 
 pub use inner::*;
 pub use gen::*;
+pub use interval::*;
 
 #[cfg(feature="describe")]
 mod describe;
@@ -4,13 +4,16 @@ mod variable_analysis;
 mod variable_substitution;
 
 mod polynomial;
+mod partial_fraction;
 
 pub use evaluation::*;
 
 pub use variable_analysis::*;
 pub use variable_substitution::*;
 
-pub use polynomial::*;
+pub use partial_fraction::*;
+
+pub(crate) use polynomial::{Polynomial, EPSILON, number_to_f64, tidy};
 
 
 /// A trait for types that provide a `find` method to obtain a value of type `T`.
@@ -29,3 +32,21 @@ pub trait Find {
     #[must_use]
     fn find(self) -> Self::Output;
 }
+
+/// A fallible version of [Find] for helper structs where obtaining the value can fail.
+///
+/// For example evaluating `1/0` using [Evaluate::evaluate] returns an error instead of an invalid expression.
+pub trait TryFind {
+    /// Output Type
+    type Output;
+
+    /// Error Type
+    type Error;
+
+    /// Try to find and return a value of type `T`.
+    ///
+    /// # Returns
+    ///
+    /// A value of type `T` representing the result of the operation or an error if it failed.
+    fn try_find(self) -> Result<Self::Output,Self::Error>;
+}
@@ -0,0 +1,527 @@
+use num_notation::fraction::{Ratio, Integer};
+
+use crate::{Expression, ArithmeticOperation};
+use crate::cubics::{rational_root, MAX_SEARCHED_COEFFICIENT};
+
+use super::{Find, Polynomial, EPSILON, tidy};
+
+/// The largest denominator a solved value is recognised as a fraction with , like `1/3` for `0.3333333333333333`
+const MAX_DENOMINATOR : i64 = 1000;
+
+/// A utility struct for decomposing a rational expression into partial fractions.
+///
+/// The `PartialFractions` struct splits `p(x)/q(x)` into a sum of simpler fractions like
+/// `A/(x + 1) + B/(x + 2)`. The denominator may contain linear , repeated linear and
+/// irreducible quadratic factors.
+///
+/// It is created by [Expression::partial_fractions].
+#[derive(Debug, Clone)]
+pub struct PartialFractions {
+    source : Expression,
+    variable : char,
+}
+
+impl Expression {
+    /// Decomposes the rational expression into partial fractions with respect to `variable`.
+    ///
+    /// Use [Find::find] on the result to obtain the decomposed expression.
+    pub fn partial_fractions(self,variable : &char) -> PartialFractions {
+        PartialFractions { source : self , variable : *variable }
+    }
+}
+
+/// A factor of the denominator with a positive leading coefficient , see [primitive]
+#[derive(Debug, Clone)]
+enum Factor {
+    /// Represents `ax + b`
+    Linear(Polynomial),
+    /// Represents `ax^2 + bx + c` which has no rational roots
+    Quadratic(Polynomial),
+}
+
+impl Factor {
+    fn polynomial(&self) -> Polynomial {
+        match self {
+            Factor::Linear(polynomial) | Factor::Quadratic(polynomial) => polynomial.clone(),
+        }
+    }
+
+    fn approx_eq(&self,other : &Self) -> bool {
+        match (self,other) {
+            (Factor::Linear(p1),Factor::Linear(p2)) | (Factor::Quadratic(p1),Factor::Quadratic(p2)) => p1.coefficients.len() == p2.coefficients.len()
+                && p1.coefficients.iter().zip(&p2.coefficients).all(|(c1,c2)| (c1 - c2).abs() < EPSILON),
+            _ => false
+        }
+    }
+
+    fn to_expression(&self,variable : char,multiplicity : usize) -> Expression {
+        let factor = self.polynomial().into_expression(variable);
+        match multiplicity {
+            1 => factor,
+            _ => Expression::new_pow(factor,(multiplicity as f64).into())
+        }
+    }
+
+    /// The root of a linear factor , quadratic factors are listed after them
+    fn root(&self) -> f64 {
+        match self {
+            Factor::Linear(polynomial) => -polynomial.coefficient(0) / polynomial.coefficient(1),
+            Factor::Quadratic(..) => f64::NEG_INFINITY,
+        }
+    }
+
+    /// Number of unknowns a fraction over this factor needs , `A` for linear and `Bx + C` for quadratic
+    const fn unknowns(&self) -> usize {
+        match self {
+            Factor::Linear(_) => 1,
+            Factor::Quadratic(..) => 2,
+        }
+    }
+}
+
+/// The result of decomposing , kept around so `describe` can show the intermediate steps
+struct Decomposition {
+    /// Polynomial part when the numerator's degree is not less than the denominator's degree
+    quotient : Polynomial,
+    #[cfg(feature="describe")]
+    remainder : Polynomial,
+    /// Constant pulled out of the denominator
+    #[cfg(feature="describe")]
+    scalar : f64,
+    factors : Vec<(Factor,usize)>,
+    /// Solved numerators in the order of `factors` and their powers
+    numerators : Vec<Polynomial>,
+}
+
+impl PartialFractions {
+    fn decompose(&self) -> Option<Decomposition> {
+        let (numerator,denominator) = match &self.source {
+            Expression::Binary { operation, left, right } if operation == &ArithmeticOperation::Durch => (left,right),
+            _ => return None
+        };
+
+        let numerator = Polynomial::try_from_expression(numerator,&self.variable)?;
+
+        let mut scalar = 1.0;
+        let mut factors : Vec<(Factor,usize)> = Vec::new();
+
+        for polynomial in collect_product(denominator,&self.variable)? {
+            let mut found = Vec::new();
+            scalar *= factorise(polynomial,&mut found)?;
+
+            // Linear factors are listed from the largest root to the smallest , like `(2x + 1)(x + 1)`
+            found.sort_by(|f1,f2| f2.root().total_cmp(&f1.root()));
+
+            for factor in found {
+                match factors.iter_mut().find(|(f,_)| f.approx_eq(&factor)) {
+                    Some((_,multiplicity)) => *multiplicity += 1,
+                    None => factors.push((factor,1)),
+                }
+            }
+        }
+
+        if scalar.abs() < EPSILON {
+            return None;
+        }
+
+        let product = factors.iter().fold(Polynomial::constant(1.0),|acc,(factor,multiplicity)| acc * factor.polynomial().pow(*multiplicity));
+
+        let (quotient,remainder) = numerator.div_rem(&product.clone().scale(scalar));
+        let target = remainder.clone().scale(1.0 / scalar);
+
+        // Each unknown contributes `product / factor^k` (times `x` for the `B` in `Bx + C`) to the numerator
+        let mut columns = Vec::new();
+        for (factor,multiplicity) in factors.iter() {
+            for power in 1..=*multiplicity {
+                let (basis,_) = product.div_rem(&factor.polynomial().pow(power));
+                if let Factor::Quadratic(..) = factor {
+                    columns.push(basis.clone() * Polynomial::monomial(1.0,1));
+                }
+                columns.push(basis);
+            }
+        }
+
+        let size = product.degree();
+        let matrix = (0..size).map(|row| columns.iter().map(|column| column.coefficient(row)).collect()).collect();
+        let rhs = (0..size).map(|row| target.coefficient(row)).collect();
+
+        let mut solution = solve_linear_system(matrix,rhs)?.into_iter();
+
+        let mut numerators = Vec::new();
+        for (factor,multiplicity) in factors.iter() {
+            for _ in 1..=*multiplicity {
+                let numerator = match factor.unknowns() {
+                    1 => Polynomial::constant(solution.next()?),
+                    _ => {
+                        let b = solution.next()?;
+                        let c = solution.next()?;
+                        Polynomial::new(vec![c,b])
+                    }
+                };
+                numerators.push(numerator);
+            }
+        }
+
+        Some(Decomposition {
+            quotient,
+            #[cfg(feature="describe")]
+            remainder,
+            #[cfg(feature="describe")]
+            scalar,
+            factors,
+            numerators
+        })
+    }
+}
+
+impl Decomposition {
+    fn into_expression(self,variable : char) -> Expression {
+        let mut expression = match self.quotient.is_zero() {
+            true => None,
+            false => Some(self.quotient.into_expression(variable))
+        };
+
+        let mut numerators = self.numerators.into_iter();
+
+        for (factor,multiplicity) in self.factors.iter() {
+            for power in 1..=*multiplicity {
+                let numerator = numerators.next().unwrap_or_else(|| Polynomial::new(vec![]));
+
+                if numerator.is_zero() {
+                    continue;
+                }
+
+                // Written as `- 2/(x + 1)` instead of `+ -2/(x + 1)`
+                let negative = expression.is_some() && numerator.leading_coefficient() < 0.0;
+                let numerator = match negative {
+                    true => numerator.scale(-1.0),
+                    false => numerator
+                };
+
+                // Written as `1/(3(x - 1))` instead of `0.3333333333/(x - 1)`
+                let (numerator,scale) = common_denominator(numerator);
+                let denominator = match scale == 1 {
+                    true => factor.to_expression(variable,power),
+                    false => Expression::new_mal((scale as f64).into(),factor.to_expression(variable,power))
+                };
+
+                let fraction = Expression::new_durch(numerator.into_expression(variable),denominator);
+
+                expression = Some(match expression {
+                    None => fraction,
+                    Some(expr) if negative => Expression::new_minus(expr,fraction),
+                    Some(expr) => Expression::new_plus(expr,fraction),
+                });
+            }
+        }
+
+        expression.unwrap_or_else(|| 0.into())
+    }
+}
+
+impl Find for PartialFractions {
+    type Output = Option<Expression>;
+
+    /// Returns `None` if the expression is not a fraction of polynomials in `variable`
+    /// or if the denominator could not be factorised into linear and quadratic factors
+    fn find(self) -> Self::Output {
+        let variable = self.variable;
+        self.decompose().map(|decomposition| decomposition.into_expression(variable))
+    }
+}
+
+/// Collects the factors of a product like `(x + 1)(x + 2)` into polynomials
+fn collect_product(expression : &Expression,variable : &char) -> Option<Vec<Polynomial>> {
+    match expression {
+        Expression::Binary { operation, left, right } if operation == &ArithmeticOperation::Mal => {
+            let mut factors = collect_product(left,variable)?;
+            factors.extend(collect_product(right,variable)?);
+            Some(factors)
+        },
+        _ => Polynomial::try_from_expression(expression,variable).map(|polynomial| vec![polynomial])
+    }
+}
+
+/// Splits `polynomial` into linear factors and quadratic factors without rational roots , returning the constant left over
+fn factorise(polynomial : Polynomial,factors : &mut Vec<Factor>) -> Option<f64> {
+    // Written with whole coefficients , like `2x + 1` for `x + 0.5` , so the rational root theorem applies
+    let (polynomial,scale) = common_denominator(polynomial);
+    let scale = scale as f64;
+
+    match polynomial.degree() {
+        0 => Some(polynomial.coefficient(0) / scale),
+        1 => {
+            let (factor,content) = primitive(polynomial);
+            factors.push(Factor::Linear(factor));
+            Some(content / scale)
+        },
+        degree => match find_rational_root(&polynomial) {
+            Some(root) => {
+                // The root p/q means qx - p is a factor , its coefficients are coprime as the root is in lowest terms
+                let linear = Polynomial::new(vec![-(*root.numer() as f64),*root.denom() as f64]);
+                let (quotient,_) = polynomial.div_rem(&linear);
+                factors.push(Factor::Linear(linear));
+                factorise(Polynomial::new(quotient.coefficients.into_iter().map(tidy).collect()),factors).map(|constant| constant / scale)
+            },
+            // Quadratics with irrational roots like `x^2 - 2` are kept whole instead of being split into decimals
+            None if degree == 2 => {
+                let (factor,content) = primitive(polynomial);
+                factors.push(Factor::Quadratic(factor));
+                Some(content / scale)
+            },
+            None => None
+        }
+    }
+}
+
+/// Divides the polynomial by its content so its coefficients are coprime whole numbers with a positive leading coefficient ,
+/// or makes it monic if a coefficient is not a whole number , returning it with the content
+fn primitive(polynomial : Polynomial) -> (Polynomial,f64) {
+    let integers = polynomial.coefficients.iter()
+        .map(|c| match (c - c.round()).abs() < EPSILON && c.abs() < MAX_SEARCHED_COEFFICIENT as f64 {
+            true => Some(c.round() as i64),
+            false => None
+        })
+        .collect::<Option<Vec<_>>>();
+
+    let content = match integers {
+        Some(integers) => integers.iter().fold(0i64,|acc,c| acc.gcd(c)) as f64 * polynomial.leading_coefficient().signum(),
+        None => polynomial.leading_coefficient()
+    };
+
+    (polynomial.scale(1.0 / content),content)
+}
+
+/// Uses the rational root theorem to find a root of a polynomial with integer coefficients , see [rational_root]
+///
+/// Polynomials with coefficients larger than [MAX_SEARCHED_COEFFICIENT] are not searched
+fn find_rational_root(polynomial : &Polynomial) -> Option<Ratio<i64>> {
+    if polynomial.coefficient(0).abs() < EPSILON {
+        return Some(Ratio::from_integer(0));
+    }
+
+    if polynomial.coefficients.iter().any(|c| c.fract().abs() > EPSILON || c.abs() > MAX_SEARCHED_COEFFICIENT as f64) {
+        return None;
+    }
+
+    let coefficients = polynomial.coefficients.iter().rev()
+        .map(|c| Ratio::from_integer(c.round() as i64))
+        .collect::<Vec<_>>();
+
+    rational_root(&coefficients)
+}
+
+/// Recognises the value as a fraction with a denominator up to [MAX_DENOMINATOR] , like `1/3` for `0.3333333333333333`
+fn to_fraction(value : f64) -> Option<Ratio<i64>> {
+    (1..=MAX_DENOMINATOR).find_map(|denominator| {
+        let numerator = (value * denominator as f64).round();
+
+        match (value - numerator / denominator as f64).abs() < EPSILON && numerator.abs() < MAX_SEARCHED_COEFFICIENT as f64 {
+            true => Some(Ratio::new(numerator as i64,denominator)),
+            false => None
+        }
+    })
+}
+
+/// Multiplies the polynomial by the common denominator of its coefficients so they are whole numbers ,
+/// returning it with the common denominator (or `1` if a coefficient is not a fraction)
+fn common_denominator(polynomial : Polynomial) -> (Polynomial,i64) {
+    let fractions = polynomial.coefficients.iter().map(|c| to_fraction(*c)).collect::<Option<Vec<_>>>();
+
+    match fractions.map(|fractions| fractions.iter().fold(1i64,|acc,fraction| acc.lcm(fraction.denom()))) {
+        Some(scale) if scale != 1 => (polynomial.scale(scale as f64),scale),
+        _ => (polynomial,1)
+    }
+}
+
+/// Writes the solved value exactly if it is a fraction , like `1/3`
+#[cfg(feature="describe")]
+fn format_value(value : f64) -> String {
+    match to_fraction(value) {
+        Some(fraction) => fraction.to_string(),
+        None => tidy(value).to_string()
+    }
+}
+
+/// Solves `matrix * x = rhs` using gaussian elimination with partial pivoting
+fn solve_linear_system(mut matrix : Vec<Vec<f64>>,mut rhs : Vec<f64>) -> Option<Vec<f64>> {
+    let size = rhs.len();
+
+    for column in 0..size {
+        let pivot = (column..size).max_by(|a,b| matrix[*a][column].abs().total_cmp(&matrix[*b][column].abs()))?;
+
+        if matrix[pivot][column].abs() < EPSILON {
+            return None;
+        }
+
+        matrix.swap(column,pivot);
+        rhs.swap(column,pivot);
+
+        for row in (column + 1)..size {
+            let factor = matrix[row][column] / matrix[column][column];
+            let pivot_row = matrix[column].clone();
+            for (value,pivot_value) in matrix[row].iter_mut().zip(pivot_row).skip(column) {
+                *value -= factor * pivot_value;
+            }
+            rhs[row] -= factor * rhs[column];
+        }
+    }
+
+    let mut solution = vec![0.0;size];
+    for row in (0..size).rev() {
+        let sum : f64 = ((row + 1)..size).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = tidy((rhs[row] - sum) / matrix[row][row]);
+    }
+
+    Some(solution)
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    Describe,
+    Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+impl Describe for PartialFractions {
+    fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        use std::collections::HashMap;
+
+        let variable = self.variable;
+        let decomposition = self.decompose()?;
+
+        let mut steps = Vec::new();
+
+        let factored = decomposition.factors.iter()
+            .map(|(factor,multiplicity)| format!("({})",factor.to_expression(variable,1)).repeat(*multiplicity))
+            .collect::<String>();
+
+        let factored = match tidy(decomposition.scalar) == 1.0 {
+            true => factored,
+            false => format!("{}{factored}",tidy(decomposition.scalar))
+        };
+
+        let args = HashMap::from([("denominator",factored.into())]);
+        steps.push(resources.lookup_single_language(lang,"partial-fractions.factor",Some(&args))?);
+
+        if !decomposition.quotient.is_zero() {
+            let args = HashMap::from([
+                ("quotient",decomposition.quotient.clone().into_expression(variable).to_string().into()),
+                ("remainder",decomposition.remainder.clone().into_expression(variable).to_string().into()),
+            ]);
+            steps.push(resources.lookup_single_language(lang,"partial-fractions.divide",Some(&args))?);
+        }
+
+        let mut unknowns = ('A'..='Z').cycle();
+        let (mut setup,mut values) = (Vec::new(),Vec::new());
+        let mut numerators = decomposition.numerators.iter();
+
+        for (factor,multiplicity) in decomposition.factors.iter() {
+            for power in 1..=*multiplicity {
+                let denominator = factor.to_expression(variable,power);
+                let numerator = numerators.next()?;
+
+                match factor {
+                    Factor::Linear(_) => {
+                        let a = unknowns.next()?;
+                        setup.push(format!("{a}/({denominator})"));
+                        values.push(format!("{a} = {}",format_value(numerator.coefficient(0))));
+                    },
+                    Factor::Quadratic(..) => {
+                        let (b,c) = (unknowns.next()?,unknowns.next()?);
+                        setup.push(format!("({b}{variable} + {c})/({denominator})"));
+                        values.push(format!("{b} = {}",format_value(numerator.coefficient(1))));
+                        values.push(format!("{c} = {}",format_value(numerator.coefficient(0))));
+                    }
+                }
+            }
+        }
+
+        let args = HashMap::from([("setup",setup.join(" + ").into())]);
+        steps.push(resources.lookup_single_language(lang,"partial-fractions.setup",Some(&args))?);
+
+        let args = HashMap::from([("values",values.join(", ").into())]);
+        steps.push(resources.lookup_single_language(lang,"partial-fractions.solve",Some(&args))?);
+
+        let args = HashMap::from([("result",decomposition.into_expression(variable).to_string().into())]);
+        steps.push(resources.lookup_single_language(lang,"partial-fractions.result",Some(&args))?);
+
+        Some(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decompose(input : &str) -> String {
+        Expression::try_from(input).unwrap()
+            .partial_fractions(&'x')
+            .find()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn distinct_linear_factors() {
+        assert_eq!(&decompose("(3x + 5)/((x + 1)(x + 2))"),"2/(x + 1) + 1/(x + 2)");
+    }
+
+    #[test]
+    fn negative_numerator() {
+        assert_eq!(&decompose("1/((x - 1)(x + 1))"),"1/(2(x - 1)) - 1/(2(x + 1))");
+    }
+
+    #[test]
+    fn exact_fractions() {
+        assert_eq!(&decompose("1/(x^3 - 1)"),"1/(3(x - 1)) - (x + 2)/(3(x^2 + x + 1))");
+    }
+
+    #[test]
+    fn repeated_linear_factor() {
+        // (x + 2)/(x + 1)^2 = 1/(x + 1) + 1/(x + 1)^2
+        assert_eq!(&decompose("(x + 2)/((x + 1)(x + 1))"),"1/(x + 1) + 1/(x + 1)^2");
+    }
+
+    #[test]
+    fn irreducible_quadratic_factor() {
+        // 1/(x(x^2 + 1)) = 1/x - x/(x^2 + 1)
+        assert_eq!(&decompose("1/(x(x^2 + 1))"),"1/x - x/(x^2 + 1)");
+    }
+
+    #[test]
+    fn improper_fraction() {
+        // (x^2 + 1)/((x - 1)(x + 1)) = 1 + 1/(x - 1) - 1/(x + 1)
+        assert_eq!(&decompose("(x^2 + 1)/((x - 1)(x + 1))"),"1 + 1/(x - 1) - 1/(x + 1)");
+    }
+
+    #[test]
+    fn leading_coefficients_are_kept() {
+        assert_eq!(&decompose("(2x + 3)/(2x^2 + 3x + 1)"),"4/(2x + 1) - 1/(x + 1)");
+        assert_eq!(&decompose("1/((2x + 2)(x - 1))"),"-1/(4(x + 1)) + 1/(4(x - 1))");
+    }
+
+    #[test]
+    fn irrational_roots_are_not_split() {
+        assert_eq!(&decompose("1/(x^2 - 2)"),"1/(x^2 - 2)");
+        assert_eq!(&decompose("(x + 1)/((x - 1)(x^2 - 2))"),"-2/(x - 1) + (2x + 3)/(x^2 - 2)");
+    }
+
+    #[test]
+    fn not_a_fraction_of_polynomials() {
+        let expression = Expression::try_from("(x + y)/(x + 1)").unwrap();
+        assert!(expression.partial_fractions(&'x').find().is_none());
+
+        // Coefficients this large are not searched for rational roots
+        let expression = Expression::try_from("1/(x^3 + 2000000000000)").unwrap();
+        assert!(expression.partial_fractions(&'x').find().is_none());
+
+        // and powers this large are not expanded
+        let expression = Expression::try_from("1/(x^1000000000000)").unwrap();
+        assert!(expression.partial_fractions(&'x').find().is_none());
+
+        let expression = Expression::try_from("1/((x + 1)^100000)").unwrap();
+        assert!(expression.partial_fractions(&'x').find().is_none());
+    }
+}
@@ -1,5 +1,7 @@
-use crate::Expression;
-use crate::manipulation::VariableAnalysis;
+use num_notation::{Number, fraction::GenericFraction};
+
+use crate::{Expression, Term, ArithmeticOperation, MAX_EXPANDED_EXPONENT};
+use crate::manipulation::{VariableAnalysis, Evaluate, Find};
 
 impl Expression {
     /// Check if the polynomial equation is quadratic (degree 2).
@@ -31,6 +33,74 @@ impl Expression {
 }
 
 impl Expression {
+    /// Collects the expression into `c0 + c1 * variable + c2 * variable^2 + ...` and returns `[c0,c1,c2,...]`
+    /// where no coefficient contains `variable` , so other variables are kept as parameters.
+    ///
+    /// Like terms are combined so `3kx^2 + 2kx^2 - 1` gives `[-1,0,5k]`. Trailing zero coefficients are dropped.
+    /// Returns `None` if `variable` appears in a non polynomial way , like `1 / x` , `x^y` or `sin(x)`
+    pub(crate) fn coefficients_in(&self,variable : &char) -> Option<Vec<Expression>> {
+        let mut coefficients = self.collect_coefficients(variable)?
+            .into_iter()
+            .map(|coefficient| coefficient.evaluate().find())
+            .collect::<Vec<_>>();
+
+        while coefficients.last().is_some_and(Expression::is_zero) {
+            coefficients.pop();
+        }
+
+        Some(coefficients)
+    }
+
+    /// Checks if `variable` appears in the expression , including inside the arguments of functions unlike [VariableAnalysis::contains_variable]
+    fn depends_on(&self,variable : &char) -> bool {
+        match self {
+            Self::Term(term) => term.variables.contains_key(variable),
+            Self::Binary { left, right, .. } => left.depends_on(variable) || right.depends_on(variable),
+            #[cfg(feature="function")]
+            Self::Function(function) => function.arguments.values().flatten().any(|argument| argument.depends_on(variable)),
+        }
+    }
+
+    fn collect_coefficients(&self,variable : &char) -> Option<Vec<Expression>> {
+        if !self.depends_on(variable) {
+            return Some(vec![self.clone()]);
+        }
+
+        match self {
+            Self::Term(term) => {
+                let mut term = term.clone();
+                let degree = natural_number(&term.variables.remove(variable)?)?;
+
+                let mut coefficients = vec![Expression::from(0);degree];
+                coefficients.push(term.into());
+                Some(coefficients)
+            },
+            Self::Binary { operation, left, right } => {
+                let left_coefficients = left.collect_coefficients(variable)?;
+
+                match operation {
+                    ArithmeticOperation::Plus => Some(zip_coefficients(left_coefficients,right.collect_coefficients(variable)?,|l,r| l + r)),
+                    ArithmeticOperation::Minus => Some(zip_coefficients(left_coefficients,right.collect_coefficients(variable)?,|l,r| l - r)),
+                    ArithmeticOperation::Mal => Some(multiply_coefficients(&left_coefficients,&right.collect_coefficients(variable)?)),
+                    ArithmeticOperation::Durch => match right.depends_on(variable) {
+                        true => None,
+                        false => Some(left_coefficients.into_iter().map(|c| c / *right.clone()).collect())
+                    },
+                    ArithmeticOperation::Pow => {
+                        let exponent = match right.as_ref() {
+                            Self::Term(term) if term.variables.is_empty() => natural_number(&term.coefficient)?,
+                            _ => return None
+                        };
+
+                        Some((0..exponent).fold(vec![Expression::from(1)],|acc,_| multiply_coefficients(&acc,&left_coefficients)))
+                    }
+                }
+            },
+            #[cfg(feature="function")]
+            Self::Function(_) => None,
+        }
+    }
+
     fn get_max_exponent_for(&self,variable : &char) -> u32 {
         match self {
             Self::Term(term) => term.variables.get(variable).map_or(0, |_| 1),
@@ -38,4 +108,353 @@ impl Expression {
             _ => 0
         }
     }
-}
\ No newline at end of file
+}
+
+/// Gets the exponent as a degree , which is at most [MAX_EXPANDED_EXPONENT] so that powers like `(x + 1)^1000` are not expanded
+fn natural_number(number : &Number) -> Option<usize> {
+    let value = number_to_f64(number);
+    match value >= 0.0 && value.fract() == 0.0 && value <= f64::from(MAX_EXPANDED_EXPONENT) {
+        true => Some(value as usize),
+        false => None
+    }
+}
+
+fn zip_coefficients(left : Vec<Expression>,right : Vec<Expression>,operation : impl Fn(Expression,Expression) -> Expression) -> Vec<Expression> {
+    let length = left.len().max(right.len());
+    let mut left = left.into_iter();
+    let mut right = right.into_iter();
+
+    (0..length)
+        .map(|_| operation(left.next().unwrap_or_else(|| 0.into()),right.next().unwrap_or_else(|| 0.into())))
+        .collect()
+}
+
+fn multiply_coefficients(left : &[Expression],right : &[Expression]) -> Vec<Expression> {
+    let mut coefficients = vec![Expression::from(0);(left.len() + right.len()).saturating_sub(1)];
+
+    for (i,l) in left.iter().enumerate() {
+        for (j,r) in right.iter().enumerate() {
+            let product = l.clone() * r.clone();
+            coefficients[i + j] = std::mem::replace(&mut coefficients[i + j],0.into()) + product;
+        }
+    }
+
+    coefficients
+}
+
+/// Coefficients smaller than this are treated as `0` when working with [Polynomial]
+pub(crate) const EPSILON : f64 = 1e-9;
+
+/// Converts a `Number` into `f64` without truncating fractions (`f64::from` uses `to_integer` for them)
+pub(crate) fn number_to_f64(number : &Number) -> f64 {
+    match number {
+        Number::Fraction(GenericFraction::Rational(sign,ratio)) => {
+            let value = *ratio.numer() as f64 / *ratio.denom() as f64;
+            match sign.is_negative() {
+                true => -value,
+                false => value
+            }
+        },
+        _ => f64::from(number.clone())
+    }
+}
+
+/// Removes floating point noise like `0.30000000000000004` so results display nicely
+pub(crate) fn tidy(value : f64) -> f64 {
+    let rounded = value.round();
+    match (value - rounded).abs() < EPSILON {
+        true => rounded + 0.0, // + 0.0 turns -0 into 0
+        false => (value * 1e10).round() / 1e10
+    }
+}
+
+/// A dense polynomial in a single variable used internally for algorithms that need
+/// coefficient access (like partial fraction decomposition).
+///
+/// Coefficients are stored from the constant term upwards so `coefficients[i]` belongs to `x^i`.
+#[derive(Debug,Clone,PartialEq)]
+pub(crate) struct Polynomial {
+    pub(crate) coefficients : Vec<f64>
+}
+
+impl Polynomial {
+    pub(crate) fn new(coefficients : Vec<f64>) -> Self {
+        let mut polynomial = Self { coefficients };
+        polynomial.trim();
+        polynomial
+    }
+
+    pub(crate) fn constant(value : f64) -> Self {
+        Self::new(vec![value])
+    }
+
+    pub(crate) fn monomial(coefficient : f64,degree : usize) -> Self {
+        let mut coefficients = vec![0.0;degree + 1];
+        coefficients[degree] = coefficient;
+        Self::new(coefficients)
+    }
+
+    /// Drops (near) zero leading coefficients
+    fn trim(&mut self) {
+        while self.coefficients.last().is_some_and(|c| c.abs() < EPSILON) {
+            self.coefficients.pop();
+        }
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Degree of the polynomial , the zero polynomial is treated as degree 0
+    pub(crate) fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    pub(crate) fn leading_coefficient(&self) -> f64 {
+        self.coefficients.last().copied().unwrap_or(0.0)
+    }
+
+    pub(crate) fn coefficient(&self,degree : usize) -> f64 {
+        self.coefficients.get(degree).copied().unwrap_or(0.0)
+    }
+
+    pub(crate) fn scale(self,factor : f64) -> Self {
+        Self::new(self.coefficients.into_iter().map(|c| c * factor).collect())
+    }
+
+    #[cfg(feature="equation")]
+    pub(crate) fn evaluate(&self,x : f64) -> f64 {
+        self.coefficients.iter().rev().fold(0.0,|acc,c| acc * x + c)
+    }
+
+    pub(crate) fn pow(&self,exponent : usize) -> Self {
+        (0..exponent).fold(Self::constant(1.0),|acc,_| acc * self.clone())
+    }
+
+    #[cfg(feature="equation")]
+    pub(crate) fn derivative(&self) -> Self {
+        Self::new(self.coefficients.iter().enumerate().skip(1).map(|(degree,c)| c * degree as f64).collect())
+    }
+
+    /// Finds the distinct real roots in ascending order
+    ///
+    /// The roots of the derivative split the real line into pieces where the polynomial is monotonic ,
+    /// so each piece contains at most one root which is found using bisection.
+    /// Returns an empty list for constant polynomials (including the zero polynomial).
+    #[cfg(feature="equation")]
+    pub(crate) fn real_roots(&self) -> Vec<f64> {
+        match self.degree() {
+            0 => return vec![],
+            1 => return vec![tidy(-self.coefficient(0) / self.coefficient(1))],
+            _ => {}
+        }
+
+        // Every root lies within the Cauchy bound
+        let lead = self.leading_coefficient();
+        let bound = 1.0 + self.coefficients.iter().map(|c| (c / lead).abs()).fold(0.0,f64::max);
+
+        let mut points = vec![-bound];
+        points.extend(self.derivative().real_roots().into_iter().filter(|x| x.abs() < bound));
+        points.push(bound);
+
+        let mut roots : Vec<f64> = Vec::new();
+        let mut push = |root : f64| if roots.last().is_none_or(|last| (root - last).abs() > EPSILON) {
+            roots.push(root);
+        };
+
+        for window in points.windows(2) {
+            let (mut lower,mut upper) = (window[0],window[1]);
+
+            if self.evaluate(lower).abs() < EPSILON {
+                push(tidy(lower));
+                continue;
+            }
+
+            if self.evaluate(lower).signum() == self.evaluate(upper).signum() {
+                continue;
+            }
+
+            for _ in 0..200 {
+                let middle = (lower + upper) / 2.0;
+                match self.evaluate(middle).signum() == self.evaluate(lower).signum() {
+                    true => lower = middle,
+                    false => upper = middle
+                }
+            }
+
+            push(tidy((lower + upper) / 2.0));
+        }
+
+        roots
+    }
+
+    /// Polynomial long division returning `(quotient,remainder)`
+    ///
+    /// **Note** : `divisor` must not be the zero polynomial
+    pub(crate) fn div_rem(&self,divisor : &Self) -> (Self,Self) {
+        let mut remainder = self.coefficients.clone();
+        let divisor_degree = divisor.degree();
+        let lead = divisor.leading_coefficient();
+
+        if remainder.len() <= divisor_degree {
+            return (Self::new(vec![]),self.clone());
+        }
+
+        let mut quotient = vec![0.0;remainder.len() - divisor_degree];
+
+        for index in (0..quotient.len()).rev() {
+            let factor = remainder[index + divisor_degree] / lead;
+            quotient[index] = factor;
+
+            for (offset,coefficient) in divisor.coefficients.iter().enumerate() {
+                remainder[index + offset] -= factor * coefficient;
+            }
+        }
+
+        remainder.truncate(divisor_degree);
+
+        (Self::new(quotient),Self::new(remainder))
+    }
+
+    /// Converts `expression` into a polynomial in `variable`.
+    ///
+    /// Returns `None` if the expression contains other variables , functions , non natural exponents
+    /// (or ones above [MAX_EXPANDED_EXPONENT]) or divisions by something other than a non-zero constant.
+    pub(crate) fn try_from_expression(expression : &Expression,variable : &char) -> Option<Self> {
+        match expression {
+            Expression::Term(term) => {
+                let coefficient = number_to_f64(&term.coefficient);
+                match term.variables.len() {
+                    0 => Some(Self::constant(coefficient)),
+                    1 => natural_number(term.variables.get(variable)?).map(|degree| Self::monomial(coefficient,degree)),
+                    _ => None
+                }
+            },
+            Expression::Binary { operation, left, right } => {
+                let left = Self::try_from_expression(left,variable)?;
+                let right = Self::try_from_expression(right,variable)?;
+
+                match operation {
+                    ArithmeticOperation::Plus => Some(left + right),
+                    ArithmeticOperation::Minus => Some(left - right),
+                    ArithmeticOperation::Mal => Some(left * right),
+                    ArithmeticOperation::Durch => match right.degree() == 0 && !right.is_zero() {
+                        true => Some(left.scale(1.0 / right.coefficient(0))),
+                        false => None
+                    },
+                    ArithmeticOperation::Pow => match right.degree() == 0 {
+                        true => natural_number(&Number::Decimal(right.coefficient(0))).map(|exponent| left.pow(exponent)),
+                        false => None
+                    }
+                }
+            },
+            #[cfg(feature="function")]
+            Expression::Function(_) => None,
+        }
+    }
+
+    /// Converts the polynomial back into an expression in `variable` , highest power first
+    pub(crate) fn into_expression(self,variable : char) -> Expression {
+        let mut expression : Option<Expression> = None;
+
+        for (degree,coefficient) in self.coefficients.into_iter().enumerate().rev() {
+            let coefficient = tidy(coefficient);
+
+            if coefficient == 0.0 {
+                continue;
+            }
+
+            let term = |coefficient : f64| match degree {
+                0 => Term::new(Number::Decimal(coefficient)),
+                _ => Term::create_single_variable_term(Number::Decimal(coefficient),variable,Number::Decimal(degree as f64))
+            };
+
+            expression = Some(match expression {
+                None => term(coefficient).into(),
+                Some(expr) => match coefficient.is_sign_positive() {
+                    true => Expression::new_plus(expr,term(coefficient).into()),
+                    false => Expression::new_minus(expr,term(-coefficient).into()),
+                }
+            });
+        }
+
+        expression.unwrap_or_else(|| 0.into())
+    }
+}
+
+impl std::ops::Add for Polynomial {
+    type Output = Self;
+    fn add(self,other : Self) -> Self {
+        let length = self.coefficients.len().max(other.coefficients.len());
+        Self::new((0..length).map(|i| self.coefficient(i) + other.coefficient(i)).collect())
+    }
+}
+
+impl std::ops::Sub for Polynomial {
+    type Output = Self;
+    fn sub(self,other : Self) -> Self {
+        self + other.scale(-1.0)
+    }
+}
+
+impl std::ops::Mul for Polynomial {
+    type Output = Self;
+    fn mul(self,other : Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::new(vec![]);
+        }
+
+        let mut coefficients = vec![0.0;self.coefficients.len() + other.coefficients.len() - 1];
+
+        for (i,a) in self.coefficients.iter().enumerate() {
+            for (j,b) in other.coefficients.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+
+        Self::new(coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn div_rem() {
+        // x^3 - 1 = (x - 1)(x^2 + x + 1)
+        let dividend = Polynomial::new(vec![-1.0,0.0,0.0,1.0]);
+        let divisor = Polynomial::new(vec![-1.0,1.0]);
+
+        let (quotient,remainder) = dividend.div_rem(&divisor);
+
+        assert_eq!(quotient,Polynomial::new(vec![1.0,1.0,1.0]));
+        assert!(remainder.is_zero());
+    }
+
+    #[test]
+    fn from_and_into_expression() {
+        let expression = Expression::try_from("(x + 1)(x - 1)").unwrap();
+        let polynomial = Polynomial::try_from_expression(&expression,&'x').unwrap();
+
+        assert_eq!(polynomial,Polynomial::new(vec![-1.0,0.0,1.0]));
+        assert_eq!(&polynomial.into_expression('x').to_string(),"x^2 - 1");
+    }
+
+    #[test]
+    #[cfg(feature="equation")]
+    fn real_roots() {
+        // (x + 2)(x - 1)(x - 3)
+        assert_eq!(Polynomial::new(vec![6.0,-5.0,-2.0,1.0]).real_roots(),[-2.0,1.0,3.0]);
+        // (x - 1)^2 (x^2 + 1)
+        assert_eq!(Polynomial::new(vec![1.0,-2.0,2.0,-2.0,1.0]).real_roots(),[1.0]);
+        assert!(Polynomial::new(vec![1.0,0.0,1.0]).real_roots().is_empty());
+        assert_eq!(Polynomial::new(vec![-5.0,0.0,1.0]).real_roots(),[-2.2360679775,2.2360679775]);
+    }
+
+    #[test]
+    fn other_variables_are_rejected() {
+        let expression = Expression::try_from("2x + y").unwrap();
+        assert!(Polynomial::try_from_expression(&expression,&'x').is_none());
+    }
+}
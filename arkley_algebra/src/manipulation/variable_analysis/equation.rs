@@ -1,4 +1,4 @@
-use crate::Equation;
+use crate::{Equation, ChainedRelation, EquationSystem};
 
 use super::VariableAnalysis;
 
@@ -20,4 +20,42 @@ impl VariableAnalysis for Equation {
     fn contains_variable(&self, variable: &char) -> bool {
         self.left.contains_variable(variable) || self.right.contains_variable(variable)
     }
+}
+
+impl VariableAnalysis for EquationSystem {
+    fn get_unique_variables(&self) -> std::collections::BTreeSet<&char> {
+        self.equations.iter().flat_map(|equation| equation.get_unique_variables()).collect()
+    }
+
+    fn contains_any_variable<'a,I>(&self,variables : &mut I) -> bool where I : Iterator<Item = &'a char> {
+        let variables : Vec<_> = variables.collect();
+        self.equations.iter().any(|equation| equation.contains_any_variable(&mut variables.iter().copied()))
+    }
+
+    fn contains_all<'a,I>(&self,variables : &mut I) -> bool where I : Iterator<Item = &'a char> {
+        variables.all(|variable| self.contains_variable(variable))
+    }
+
+    fn contains_variable(&self, variable: &char) -> bool {
+        self.equations.iter().any(|equation| equation.contains_variable(variable))
+    }
+}
+
+impl VariableAnalysis for ChainedRelation {
+    fn get_unique_variables(&self) -> std::collections::BTreeSet<&char> {
+        self.parts().flat_map(|part| part.get_unique_variables()).collect()
+    }
+
+    fn contains_any_variable<'a,I>(&self,variables : &mut I) -> bool where I : Iterator<Item = &'a char> {
+        let variables : Vec<_> = variables.collect();
+        self.parts().any(|part| part.contains_any_variable(&mut variables.iter().copied()))
+    }
+
+    fn contains_all<'a,I>(&self,variables : &mut I) -> bool where I : Iterator<Item = &'a char> {
+        variables.all(|variable| self.contains_variable(variable))
+    }
+
+    fn contains_variable(&self, variable: &char) -> bool {
+        self.parts().any(|part| part.contains_variable(variable))
+    }
 }
\ No newline at end of file
@@ -4,12 +4,12 @@ use crate::Expression;
 
 use super::VariableAnalysis;
 
-impl VariableAnalysis for Expression {
+impl<N> VariableAnalysis for Expression<N> {
     fn get_unique_variables(&self) -> BTreeSet<&char> {
         let mut unique_variables = BTreeSet::new();
 
         // Helper function to recursively traverse the Expression.
-        fn extract_variables<'a>(expr: &'a Expression, unique_vars: &mut BTreeSet<&'a char>) {
+        fn extract_variables<'a,N>(expr: &'a Expression<N>, unique_vars: &mut BTreeSet<&'a char>) {
             match expr {
                 Expression::Term(term) => {
                     unique_vars.extend(term.variables.keys());
@@ -4,7 +4,7 @@ use crate::Term;
 
 use super::VariableAnalysis;
 
-impl VariableAnalysis for Term{
+impl<N> VariableAnalysis for Term<N> {
     fn get_unique_variables(&self) -> BTreeSet<&char> {
         self.variables.keys().collect()   
     }
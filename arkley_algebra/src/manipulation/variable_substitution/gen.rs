@@ -1,6 +1,6 @@
-use num_notation::Number;
+use num_notation::Pow;
 
-use crate::{Term,Expression,manipulation::Find, Function};
+use crate::{Term,Expression,manipulation::Find, Function, Numeric, power_of};
 
 use super::{VariableSubstitution, SingleVariableReplacements, MultipleVariableReplacements};
 // Term 
@@ -16,6 +16,12 @@ macro_rules! impl_trait {
 
             #[cfg(feature="equation")]
             impl VariableSubstitution<$t> for crate::Equation {}
+
+            #[cfg(feature="equation")]
+            impl VariableSubstitution<$t> for crate::ChainedRelation {}
+
+            #[cfg(feature="equation")]
+            impl VariableSubstitution<$t> for crate::EquationSystem {}
         )*
     };
 
@@ -48,82 +54,132 @@ macro_rules! impl_trait {
             }
         )*
     };
+
+    (generic => $($value : ident),*) => {
+        $(
+            impl<N : Numeric> VariableSubstitution<$value<N>> for Term<N> {}
+            
+            impl<N : Numeric> VariableSubstitution<$value<N>> for Expression<N> {}
+            
+            impl<N : Numeric> VariableSubstitution<$value<N>> for Function<N> {}
+        )*
+    };
 }
 impl_trait!(term => u8,u16,u32,u64,i8,i16,i32,i64,f32,f64);
-impl_trait!(u8,u16,u32,u64,i8,i16,i32,i64,f32,f64,Number,Term,Function,Expression);
+impl_trait!(u8,u16,u32,u64,i8,i16,i32,i64,f32,f64);
+impl_trait!(generic => Term,Function,Expression);
+
+impl<N : Numeric> VariableSubstitution<N> for Term<N> {}
+impl<N : Numeric> VariableSubstitution<N> for Expression<N> {}
+impl<N : Numeric> VariableSubstitution<N> for Function<N> {}
 
-impl Find for SingleVariableReplacements<Term,Number> {
-    type Output = Term;
+#[cfg(feature="equation")]
+impl VariableSubstitution<num_notation::Number> for crate::Equation {}
+#[cfg(feature="equation")]
+impl VariableSubstitution<Term> for crate::Equation {}
+#[cfg(feature="equation")]
+impl VariableSubstitution<Function> for crate::Equation {}
+#[cfg(feature="equation")]
+impl VariableSubstitution<Expression> for crate::Equation {}
+
+#[cfg(feature="equation")]
+impl VariableSubstitution<num_notation::Number> for crate::EquationSystem {}
+#[cfg(feature="equation")]
+impl VariableSubstitution<Term> for crate::EquationSystem {}
+#[cfg(feature="equation")]
+impl VariableSubstitution<Function> for crate::EquationSystem {}
+#[cfg(feature="equation")]
+impl VariableSubstitution<Expression> for crate::EquationSystem {}
+
+#[cfg(feature="equation")]
+impl VariableSubstitution<num_notation::Number> for crate::ChainedRelation {}
+#[cfg(feature="equation")]
+impl VariableSubstitution<Term> for crate::ChainedRelation {}
+#[cfg(feature="equation")]
+impl VariableSubstitution<Function> for crate::ChainedRelation {}
+#[cfg(feature="equation")]
+impl VariableSubstitution<Expression> for crate::ChainedRelation {}
+
+// Whole exponents are calculated using the numeric type itself so `x^2` with `x = 1/3` stays exact
+// If the numeric type can not represent a power (like `2^(-1)` for integers) it is kept as a power instead
+impl<N : Numeric> Find for SingleVariableReplacements<Term<N>,N> {
+    type Output = Expression<N>;
     fn find(self) -> Self::Output {                    
         let mut term = self.source;
-        if let Some(exponent) = term.variables.remove(&self.variable) {
-            term.coefficient *= f64::from(self.value).powf(f64::from(exponent));
+        match term.variables.remove(&self.variable) {
+            Some(exponent) => Expression::from(term) * power_of(self.value,&exponent),
+            None => term.into()
         }
-        
-        term
     }
 }
 
-impl Find for MultipleVariableReplacements<'_,Term,Number> {
-    type Output = Term;
+impl<N : Numeric> Find for MultipleVariableReplacements<'_,Term<N>,N> {
+    type Output = Expression<N>;
     fn find(self) -> Self::Output {
         let mut term = self.source;
+        let mut powers = Vec::new();
+
         for (k,v) in self.values.iter() {
             if let Some(exponent) = term.variables.remove(k) {
-                term.coefficient *= f64::from((*v).clone()).powf(f64::from(exponent));
+                powers.push(power_of(v.clone(),&exponent));
             }
         }
 
-        term
+        powers.into_iter().fold(term.into(),|result,power| result * power)
     }
 }
 
-impl Find for SingleVariableReplacements<Term,Term> {
-    type Output = Expression;
+impl<N : Numeric> Find for SingleVariableReplacements<Term<N>,Term<N>> {
+    type Output = Expression<N>;
     fn find(self) -> Self::Output {
         match self.value.variables.is_empty() {
-            true => self.source.replace_single_variable(&self.variable, self.value.coefficient).find().into(),
-            false => todo!("Implement this once `Pow` trait for expressions and terms is finished"),
+            true => self.source.replace_single_variable(&self.variable, self.value.coefficient).find(),
+            false => replace_with_expressions(self.source,[(&self.variable,self.value.into())]),
         }
     }
 }
 
-impl Find for SingleVariableReplacements<Term,Expression> {
-    type Output = Expression;
+impl<N : Numeric> Find for SingleVariableReplacements<Term<N>,Expression<N>> {
+    type Output = Expression<N>;
     fn find(self) -> Self::Output {
         match self.value {
-            Expression::Term(term) => self.source.replace_single_variable(&self.variable, term).find().into(),
-            _ => todo!("Implement this once `Pow` trait for expressions and terms is finished")
+            Expression::Term(term) => self.source.replace_single_variable(&self.variable, term).find(),
+            value => replace_with_expressions(self.source,[(&self.variable,value)])
         }
     }
 }
 
-impl Find for MultipleVariableReplacements<'_,Term,Term> {
-    type Output = Expression;
+impl<N : Numeric> Find for MultipleVariableReplacements<'_,Term<N>,Term<N>> {
+    type Output = Expression<N>;
     fn find(self) -> Self::Output {
-        if self.values.is_empty() {
-            return self.source.into()
-        }
-
-        todo!("Implement this once `Pow` trait for expressions and terms is finished")
+        replace_with_expressions(self.source,self.values.iter().map(|(variable,value)| (variable,value.clone().into())))
     }
 }
 
-impl Find for MultipleVariableReplacements<'_,Term,Expression> {
-    type Output = Expression;
+impl<N : Numeric> Find for MultipleVariableReplacements<'_,Term<N>,Expression<N>> {
+    type Output = Expression<N>;
     fn find(self) -> Self::Output {
-        if self.values.is_empty() {
-            return self.source.into()
-        }
+        replace_with_expressions(self.source,self.values.iter().map(|(variable,value)| (variable,value.clone())))
+    }
+}
 
-        todo!("Implement this once `Pow` trait for expressions and terms is finished")
+/// Replaces the variables of the term with expressions , like `2x^2` with `x = y + 1` into `2(y + 1)^2`
+fn replace_with_expressions<'a,N : Numeric>(mut term : Term<N>,values : impl IntoIterator<Item = (&'a char,Expression<N>)>) -> Expression<N> {
+    let mut powers = Vec::new();
+
+    for (variable,value) in values {
+        if let Some(exponent) = term.variables.remove(variable) {
+            powers.push(value.pow(exponent));
+        }
     }
+
+    powers.into_iter().fold(term.into(),|result,power| result * power)
 }
 
 // -----------------
 // Function
-impl<T : Into<Expression>> Find for SingleVariableReplacements<Function,T> {
-    type Output = Function;
+impl<N,T : Into<Expression<N>>> Find for SingleVariableReplacements<Function<N>,T> {
+    type Output = Function<N>;
     fn find(self) -> Self::Output {
         let mut func = self.source;
         let variable = self.variable;
@@ -141,8 +197,8 @@ impl<T : Into<Expression>> Find for SingleVariableReplacements<Function,T> {
     }
 }
 
-impl<'a,T : Clone + Into<Expression>> Find for MultipleVariableReplacements<'a,Function,T> {
-    type Output = Function;
+impl<'a,N,T : Clone + Into<Expression<N>>> Find for MultipleVariableReplacements<'a,Function<N>,T> {
+    type Output = Function<N>;
     fn find(self) -> Self::Output {
         let mut func = self.source;
         let values = self.values;
@@ -165,18 +221,18 @@ impl<'a,T : Clone + Into<Expression>> Find for MultipleVariableReplacements<'a,F
 // -----------------
 // Expression
 
-impl<T : Clone> Find for SingleVariableReplacements<Expression,T> 
+impl<N,T : Clone> Find for SingleVariableReplacements<Expression<N>,T> 
     where 
-    Term: VariableSubstitution<T>,
-    Expression : VariableSubstitution<T>
-         + From<<SingleVariableReplacements<Term,T> as Find>::Output>
-         + From<<SingleVariableReplacements<Function, T> as Find>::Output>,
-    Function : VariableSubstitution<T> ,
-    SingleVariableReplacements<Term, T> : Find,
-    SingleVariableReplacements<Function, T> : Find
+    Term<N>: VariableSubstitution<T>,
+    Expression<N> : VariableSubstitution<T>
+         + From<<SingleVariableReplacements<Term<N>,T> as Find>::Output>
+         + From<<SingleVariableReplacements<Function<N>, T> as Find>::Output>,
+    Function<N> : VariableSubstitution<T> ,
+    SingleVariableReplacements<Term<N>, T> : Find,
+    SingleVariableReplacements<Function<N>, T> : Find
     {
 
-    type Output = Expression;
+    type Output = Expression<N>;
     fn find(self) -> Self::Output {
         let variable = &self.variable;
         let value = self.value;
@@ -194,18 +250,18 @@ impl<T : Clone> Find for SingleVariableReplacements<Expression,T>
     }
 }
 
-impl<'a,T : Clone + 'a> Find for MultipleVariableReplacements<'a,Expression,T> 
+impl<'a,N,T : Clone + 'a> Find for MultipleVariableReplacements<'a,Expression<N>,T> 
     where 
-    Term: VariableSubstitution<T>,
-    Expression : VariableSubstitution<T> 
-        + From<<MultipleVariableReplacements<'a,Term, T> as Find>::Output>
-        + From<<MultipleVariableReplacements<'a,Function, T> as Find>::Output>,
-    Function : VariableSubstitution<T> ,
-
-    MultipleVariableReplacements<'a,Term, T> : Find,
-    MultipleVariableReplacements<'a,Function, T> : Find
+    Term<N>: VariableSubstitution<T>,
+    Expression<N> : VariableSubstitution<T> 
+        + From<<MultipleVariableReplacements<'a,Term<N>, T> as Find>::Output>
+        + From<<MultipleVariableReplacements<'a,Function<N>, T> as Find>::Output>,
+    Function<N> : VariableSubstitution<T> ,
+
+    MultipleVariableReplacements<'a,Term<N>, T> : Find,
+    MultipleVariableReplacements<'a,Function<N>, T> : Find
     {
-    type Output = Expression;
+    type Output = Expression<N>;
     fn find(self) -> Self::Output {
         let values = &self.values;
 
@@ -266,6 +322,83 @@ impl<'a,T : Clone> Find for MultipleVariableReplacements<'a,crate::Equation,T>
     }
 }
 
+// Every equation of the system is replaced on its own
+#[cfg(feature="equation")]
+impl<T : Clone> Find for SingleVariableReplacements<crate::EquationSystem,T> 
+    where 
+    crate::Equation : VariableSubstitution<T>,
+    SingleVariableReplacements<crate::Equation,T> : Find<Output = crate::Equation> {
+    type Output = crate::EquationSystem;
+    fn find(self) -> Self::Output {
+        let value = self.value;
+        let variable = &self.variable;
+
+        self.source.into_iter()
+            .map(|equation| equation.replace_single_variable(variable, value.clone()).find())
+            .collect()
+    }
+}
+
+#[cfg(feature="equation")]
+impl<'a,T> Find for MultipleVariableReplacements<'a,crate::EquationSystem,T> 
+    where 
+    crate::Equation : VariableSubstitution<T>,
+    MultipleVariableReplacements<'a,crate::Equation,T> : Find<Output = crate::Equation> {
+    type Output = crate::EquationSystem;
+    fn find(self) -> Self::Output { 
+        let values = self.values;
+
+        self.source.into_iter()
+            .map(|equation| equation.replace_variables(values).find())
+            .collect()
+    }
+}
+
+#[cfg(feature="equation")]
+impl<T : Clone> Find for SingleVariableReplacements<crate::ChainedRelation,T> 
+    where 
+    Expression: VariableSubstitution<T> 
+        + From<<SingleVariableReplacements<Expression,T> as Find>::Output> 
+        + From<<SingleVariableReplacements<Term,T> as Find>::Output>,
+    Term: VariableSubstitution<T>,
+    SingleVariableReplacements<Expression,T> : Find ,
+    SingleVariableReplacements<Term, T> : Find  {
+    type Output = crate::ChainedRelation;
+    fn find(self) -> Self::Output {
+        let value = self.value;
+        let variable = &self.variable;
+        let mut chain = self.source;
+
+        chain.first = chain.first.replace_single_variable(variable, value.clone()).find().into();
+        chain.rest = chain.rest.into_iter()
+            .map(|(relation,part)| (relation,part.replace_single_variable(variable, value.clone()).find().into()))
+            .collect();
+        chain
+    }
+}
+
+#[cfg(feature="equation")]
+impl<'a,T : Clone> Find for MultipleVariableReplacements<'a,crate::ChainedRelation,T> 
+    where 
+    Expression: VariableSubstitution<T> 
+        + From<<MultipleVariableReplacements<'a,Expression,T> as Find>::Output> 
+        + From<<MultipleVariableReplacements<'a,Term,T> as Find>::Output>,
+    Term: VariableSubstitution<T>,
+    MultipleVariableReplacements<'a,Expression,T> : Find ,
+    MultipleVariableReplacements<'a,Term, T> : Find  {
+    type Output = crate::ChainedRelation;
+    fn find(self) -> Self::Output { 
+        let values = self.values;
+        let mut chain = self.source;
+
+        chain.first = chain.first.replace_variables(values).find().into();
+        chain.rest = chain.rest.into_iter()
+            .map(|(relation,part)| (relation,part.replace_variables(values).find().into()))
+            .collect();
+        chain
+    }
+}
+
 #[cfg(test)]
 mod expr {
     use super::*;
@@ -7,9 +7,9 @@ use std::collections::HashMap;
 /// It is created by [super::VariableSubstitution::replace_single_variable]
 #[derive(Debug, Clone)]
 pub struct SingleVariableReplacements<T, V> {
-    pub(super) source: T,
-    pub(super) variable : char,
-    pub(super) value : V,
+    pub(crate) source: T,
+    pub(crate) variable : char,
+    pub(crate) value : V,
 }
 
 impl<T, V> SingleVariableReplacements<T, V> {
@@ -23,8 +23,8 @@ impl<T, V> SingleVariableReplacements<T, V> {
 /// It is created by [super::VariableSubstitution::replace_variables]
 #[derive(Debug, Clone)]
 pub struct MultipleVariableReplacements<'a,T, V> {
-    pub(super) source: T,
-    pub(super) values : &'a HashMap<char,V>,
+    pub(crate) source: T,
+    pub(crate) values : &'a HashMap<char,V>,
 }
 
 impl<'a, T, V> MultipleVariableReplacements<'a, T, V> {
@@ -1,105 +1,271 @@
 use std::collections::HashMap;
 
-use nom::{combinator::value, bytes::complete::tag,IResult};
-
-use crate::{Expression, Function};
-
-use super::tokens::Token;
+use crate::{Expression, Function, Term, ContextError};
+use crate::manipulation::{VariableSubstitution, Find};
 
 /// A context that stores its mappings in hash maps.
 ///
 /// *Value and function mappings are stored independently, meaning that there can be a function and a value with the same identifier.*
-/// 
+///
 /// It allows using variables , user-defined functions within an expression used during the parsing phase.
 /// When assigning to variables, the assignment is stored in a context. When the variable is read later on,
 /// it is read from the context. Contexts can be preserved between multiple calls by creating them yourself.
-/// 
-/// TODO : Allow `context` to be in expression so no 'converting' maybe idk and also make the parser for values and function feilds
-#[derive(Clone, Debug,Default)]
-pub struct Context<'a> {
+///
+/// Statements like `x = 4y` or `f(x) = x^2` can be stored using [Context::apply] or [Context::run_script].
+///
+/// The mappings are stored in nested scopes , see [Context::push_scope]. Mappings of inner scopes shadow the ones of outer scopes
+/// and the getters like [Context::values] only return the mappings of the innermost scope.
+///
+/// TODO : Allow `context` to be in expression so no 'converting' maybe idk
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    /// The global scope which is never removed
+    global : Scope,
+    /// The scopes added by [Context::push_scope] , from the outermost to the innermost
+    scopes : Vec<Scope>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Scope {
     /// Used for storing input like
-    /// ```
+    /// ```text
     /// a = 0
     /// b = 543x
     /// x = 4y + 5u
     /// ```
     values : HashMap<char,Expression>,
 
-    tags : ContextHashMap<'a,Expression>,
+    tags : HashMap<String,Expression>,
     #[cfg(feature="function")]
     // used cuz functions will have different 'parsing' logic
-    functions : ContextHashMap<'a,Function>
+    functions : HashMap<String,Function>
 }
 
-type ContextHashMap<'a,T> =  HashMap<&'a str,T>;
+impl Context {
+    /// Iterates over the global scope followed by the added scopes , from the outermost to the innermost
+    fn all_scopes(&self) -> impl DoubleEndedIterator<Item = &Scope> {
+        std::iter::once(&self.global).chain(&self.scopes)
+    }
+
+    fn scope(&self) -> &Scope {
+        self.scopes.last().unwrap_or(&self.global)
+    }
+
+    fn scope_mut(&mut self) -> &mut Scope {
+        match self.scopes.last_mut() {
+            Some(scope) => scope,
+            None => &mut self.global
+        }
+    }
 
-impl<'a> Context<'a,>{
-    /// Gets reference to the values eg x = 10 
-    pub const fn values(&self) -> &HashMap<char,Expression> {
-        &self.values
+    /// Gets reference to the values of the innermost scope eg x = 10
+    pub fn values(&self) -> &HashMap<char,Expression> {
+        &self.scope().values
     }
-    /// Gets mutable reference to the values eg x = 10 
+
+    /// Gets mutable reference to the values of the innermost scope eg x = 10
     pub fn values_mut(&mut self) -> &mut HashMap<char,Expression> {
-        &mut self.values
+        &mut self.scope_mut().values
     }
 
-    /// Gets reference to the tags context 
-    pub const fn tags(&self) -> &ContextHashMap<'a,Expression> {
-        &self.tags
+    /// Gets reference to the tags of the innermost scope
+    pub fn tags(&self) -> &HashMap<String,Expression> {
+        &self.scope().tags
     }
- 
-    /// Gets a mutable reference to the tags context
-    pub fn tags_mut(&mut self) -> &mut ContextHashMap<'a,Expression> {
-        &mut self.tags
+
+    /// Gets a mutable reference to the tags of the innermost scope
+    pub fn tags_mut(&mut self) -> &mut HashMap<String,Expression> {
+        &mut self.scope_mut().tags
     }
 
     #[cfg(feature="function")]
-    /// Gets reference to the function context 
-    pub const fn functions(&self) -> &ContextHashMap<'a,Function> {
-        &self.functions
+    /// Gets reference to the functions of the innermost scope
+    pub fn functions(&self) -> &HashMap<String,Function> {
+        &self.scope().functions
     }
 
-    /// Gets a mutable reference to the function context
+    /// Gets a mutable reference to the functions of the innermost scope
     #[cfg(feature="function")]
-    pub fn functions_mut(&mut self) -> &mut ContextHashMap<'a,Function> {
-        &mut self.functions
+    pub fn functions_mut(&mut self) -> &mut HashMap<String,Function> {
+        &mut self.scope_mut().functions
+    }
+
+    /// Gets the value of the variable from the innermost scope that contains it
+    pub fn value(&self,variable : &char) -> Option<&Expression> {
+        self.all_scopes().rev().find_map(|scope| scope.values.get(variable))
+    }
+
+    /// Gets the tag from the innermost scope that contains it
+    pub fn tag(&self,name : &str) -> Option<&Expression> {
+        self.all_scopes().rev().find_map(|scope| scope.tags.get(name))
+    }
+
+    /// Gets the function from the innermost scope that contains it
+    #[cfg(feature="function")]
+    pub fn function(&self,name : &str) -> Option<&Function> {
+        self.all_scopes().rev().find_map(|scope| scope.functions.get(name))
+    }
+
+    /// Adds a new innermost scope , whose mappings shadow the ones of the outer scopes until it is removed by [Context::pop_scope]
+    pub fn push_scope(&mut self) {
+        self.scopes.push(Scope::default());
+    }
+
+    /// Removes the innermost scope , returning `false` if only the global scope is left (which is never removed)
+    pub fn pop_scope(&mut self) -> bool {
+        self.scopes.pop().is_some()
+    }
+
+    /// Replaces the variables of the expression with their values , which are resolved transitively
+    /// so `x = 4y` and `y = 2` resolve `x + 1` into `8 + 1`.
+    ///
+    /// Calls of functions with a definition are replaced by the definition , with the parameters shadowing any value with the same name.
+    ///
+    /// Returns [ContextError::Cycle] if a value depends on itself like `x = y + 1` and `y = x`
+    pub fn resolve(&self,expression : Expression) -> Result<Expression,ContextError> {
+        Resolver { context : self , arguments : Vec::new() , visiting : Vec::new() }.expression(expression)
+    }
+
+    /// Gets the resolved values of all variables visible in the innermost scope , see [Context::resolve]
+    pub fn resolved_values(&self) -> Result<HashMap<char,Expression>,ContextError> {
+        let mut resolver = Resolver { context : self , arguments : Vec::new() , visiting : Vec::new() };
+
+        self.all_scopes()
+            .flat_map(|scope| scope.values.keys())
+            .map(|variable| Ok((*variable,resolver.variable(*variable)?.unwrap_or_else(|| (*variable).into()))))
+            .collect()
     }
 }
 
-impl Context<'_> {
-    pub(super) fn parse_tags<'a : 'b,'b>(&'b self) -> impl FnMut(&'a str) -> IResult<&'a str,Vec<Token>> + 'b {
-        move |input|{
-            let (input,expression) = alternative(&self.tags())(input)?;    
+struct Resolver<'a> {
+    context : &'a Context,
+    /// The arguments of the functions being expanded , which are already resolved
+    arguments : Vec<HashMap<char,Expression>>,
+    /// The variables currently being resolved , used for detecting cycles
+    visiting : Vec<char>,
+}
 
-            let vec = vec![expression.into()];
-            Ok((input,vec))
+impl Resolver<'_> {
+    fn expression(&mut self,expression : Expression) -> Result<Expression,ContextError> {
+        match expression {
+            Expression::Term(term) => self.term(term),
+            Expression::Binary { operation, left, right } => Ok(Expression::new_binary(operation,self.expression(*left)?,self.expression(*right)?)),
+            Expression::Function(function) => self.function(function),
         }
     }
 
-    /*pub fn parse_values<'a>(mut self) -> impl FnMut(&'a str) -> IResult<&'a str,Context<'_>>  {
-        move |input| {
-            let (input,key_str) = take_until1("=")(input)?;
-            let (input,_) = take(1usize)(input); // cuz take until = doesnt consume the =
-            let (input,expression) = parse_expression(&self)?;
+    fn term(&mut self,term : Term) -> Result<Expression,ContextError> {
+        let mut values = HashMap::new();
+
+        for variable in term.variables.keys() {
+            if let Some(value) = self.variable(*variable)? {
+                values.insert(*variable,value);
+            }
+        }
 
+        Ok(match values.is_empty() {
+            true => term.into(),
+            false => term.replace_variables(&values).find()
+        })
+    }
 
-            todo!()
+    fn variable(&mut self,variable : char) -> Result<Option<Expression>,ContextError> {
+        if let Some(argument) = self.arguments.last().and_then(|arguments| arguments.get(&variable)) {
+            return Ok(Some(argument.clone()));
         }
-    }*/
-}
 
-fn alternative<'a : 'b,'b,T : Clone>(alternatives: &'b HashMap<&'b str,T>) -> impl FnMut(&'a str) -> nom::IResult<&'a str,T> + 'b {
+        let Some(value) = self.context.value(&variable) else {
+            return Ok(None);
+        };
+
+        if self.visiting.contains(&variable) {
+            let mut cycle = std::mem::take(&mut self.visiting);
+            cycle.push(variable);
+            return Err(ContextError::Cycle(cycle));
+        }
+
+        // Values are resolved where they were defined so the arguments of the function being expanded do not apply to them
+        let arguments = std::mem::take(&mut self.arguments);
+        self.visiting.push(variable);
+
+        let value = self.expression(value.clone())?;
+
+        self.visiting.pop();
+        self.arguments = arguments;
+
+        Ok(Some(value))
+    }
+
+    fn function(&mut self,mut function : Function) -> Result<Expression,ContextError> {
+        let mut arguments = HashMap::new();
 
-    move |input| {
-        let mut last_err = Err(nom::Err::Error(nom::error::Error { input, code: nom::error::ErrorKind::NonEmpty }));
+        for (parameter,argument) in function.arguments.iter_mut() {
+            if let Some(value) = argument.take() {
+                let value = self.expression(value)?;
+                arguments.insert(*parameter,value.clone());
+                *argument = Some(value);
+            }
+            else {
+                // Parameters without an argument stay as they are instead of using a value with the same name
+                arguments.insert(*parameter,(*parameter).into());
+            }
+        }
 
-        for (key,t) in alternatives {
-            match value(t,tag(*key))(input) {
-                Ok((input,other)) => return Ok((input,(*other).clone())),
-                error @ Err(_) => last_err = error,
+        match function.expression.take() {
+            None => Ok(function.into()),
+            Some(body) => {
+                self.arguments.push(arguments);
+                let body = self.expression(*body);
+                self.arguments.pop();
+                body
             }
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::manipulation::{Evaluate, EvaluteWithValues};
+
+    #[test]
+    fn transitive_resolution() {
+        let mut context = Context::default();
+        context.run_script("x = 4y\ny = 2").unwrap();
+
+        let result = context.resolve(Expression::try_from("x + 1").unwrap()).unwrap();
+        assert_eq!(result.evaluate().find().to_string(),"9");
+
+        let values = context.resolved_values().unwrap();
+        assert_eq!(Expression::from('x').evaluate_with_multiple_values(&values).find().to_string(),"8");
+    }
+
+    #[test]
+    fn cycles_are_errors() {
+        let mut context = Context::default();
+        let error = context.run_script("x = y + 1\ny = x").unwrap_err();
+
+        assert!(matches!(error,ContextError::Cycle(ref cycle) if cycle == &['y','x','y']));
+        // The assignment causing the cycle is not stored
+        assert!(!context.values().contains_key(&'y'));
+    }
 
-        last_err.map(|(i,v)| (i,v.clone()))
+    #[test]
+    fn scopes_and_parameters_shadow_values() {
+        let mut context = Context::default();
+        context.run_script("x = 5\na = 2\nf(x) = x^2 + a").unwrap();
+
+        let result = context.resolve(Expression::try_from(("f(3) + x",&context)).unwrap()).unwrap();
+        assert_eq!(result.evaluate().find().to_string(),"16");
+
+        context.push_scope();
+        context.values_mut().insert('a',10.into());
+        assert_eq!(context.resolve('a'.into()).unwrap().to_string(),"10");
+        assert_eq!(context.resolve('x'.into()).unwrap().to_string(),"5");
+
+        assert!(context.pop_scope());
+        assert!(!context.pop_scope());
+        assert_eq!(context.resolve('a'.into()).unwrap().to_string(),"2");
     }
-}
\ No newline at end of file
+}
@@ -1,13 +1,8 @@
-use nom::{
-    sequence::delimited, 
-    character::complete::multispace0, 
-    bytes::complete::tag, 
-    branch::alt, 
-    combinator::{value, all_consuming}, 
-    IResult
-};
+use std::str::FromStr;
 
-use crate::{Equation, RelationalOperator, parse_expression, Context};
+use crate::{Equation, ChainedRelation, EquationSystem, RelationalOperator, Context, ParseError, NomError, ParseResult, Expression};
+
+use super::{expression::ExpressionParser, lexer::{Token, TokenKind}};
 
 
 /// Parse an equation from the input string.
@@ -25,44 +20,224 @@ use crate::{Equation, RelationalOperator, parse_expression, Context};
 ///
 /// Returns an `Option<Equation>`, where `Some` contains a valid `Equation` object if
 /// both expressions are successfully parsed, and `None` if parsing fails.
-pub fn parse_equation<'a : 'b,'b>(context : &'b Context<'b>) -> impl FnMut(&'a str) -> IResult<&'a str,Equation> + 'b {
+pub fn parse_equation<'a : 'b,'b>(context : &'b Context) -> impl FnMut(&'a str) -> ParseResult<'a,Equation> + 'b {
     move |input| {
-        let (input,lexpr) = parse_expression(context)(input)?;
+        let mut parser = ExpressionParser::new(input,context);
 
-        let (input,relational_operator) = delimited(
-            multispace0,
-            parse_relation_operator,
-            multispace0
-        )(input)?;
+        match parser.equation() {
+            Ok(equation) => Ok((parser.lexer.remaining(),equation)),
+            Err(error) => Err(parser.into_nom_error(input,error))
+        }
+    }
+}
 
-        let (input,rexpr) = parse_expression(context)(input)?;
+/// Parse a chain of relations like `2 < 3x + 1 <= 10` from the input string , see [parse_equation]
+pub fn parse_chained_relation<'a : 'b,'b>(context : &'b Context) -> impl FnMut(&'a str) -> ParseResult<'a,ChainedRelation> + 'b {
+    move |input| {
+        let mut parser = ExpressionParser::new(input,context);
 
-        let equation = Equation::new(lexpr,relational_operator,rexpr);
+        match parser.expression().and_then(|first| parser.chained_relation_with_first(first)) {
+            Ok(chain) => Ok((parser.lexer.remaining(),chain)),
+            Err(error) => Err(parser.into_nom_error(input,error))
+        }
+    }
+}
 
-        Ok((input,equation))
+/// Parse a system of equations like `{ 2x + y = 3; x - y = 1 }` from the input string.
+///
+/// The equations are separated by `;` or new lines and the surrounding braces are optional ,
+/// but without them the whole input is parsed as the system.
+pub fn parse_equation_system<'a : 'b,'b>(context : &'b Context) -> impl FnMut(&'a str) -> ParseResult<'a,EquationSystem> + 'b {
+    move |input| {
+        equation_system(input,context).map_err(|error| nom::Err::Error(NomError::new(input,error)))
     }
 }
 
-fn parse_relation_operator(input: &str) -> IResult<&str,RelationalOperator> {
-    alt((
-        value(RelationalOperator::Equal,tag("=")),
-        value(RelationalOperator::GreaterThan,tag(">")),
-        value(RelationalOperator::LessThan,tag("<")),
-    ))(input)
+/// Parses the system , returning the input following the closing brace (if any)
+fn equation_system<'a>(input : &'a str,context : &Context) -> Result<(&'a str,EquationSystem),ParseError> {
+    let start = input.len() - input.trim_start().len();
+
+    let (body,body_start,remaining) = match input[start..].strip_prefix('{') {
+        None => (input,0,&input[input.len()..]),
+        Some(rest) => match rest.find('}') {
+            Some(end) => (&rest[..end],start + 1,&rest[end + 1..]),
+            None => return Err(
+                ParseError::new(input.len()..input.len(),"`}`","end of input").with_hint("add the missing closing `}`")
+            )
+        }
+    };
+
+    let mut system = EquationSystem::default();
+    let mut offset = body_start;
+
+    for line in body.split(['\n',';']) {
+        if !line.trim().is_empty() {
+            let equation = Equation::try_from((line,context)).map_err(|error| error.shifted(offset))?;
+            system.push(equation);
+        }
+
+        offset += line.len() + 1;
+    }
+
+    match system.is_empty() {
+        true => Err(
+            ParseError::new(body_start..body_start,"an equation","an empty system").with_hint("separate the equations with `;` or new lines")
+        ),
+        false => Ok((remaining,system))
+    }
 }
 
-impl<'a,'b> TryFrom<(&'a str,&'b Context<'b>)> for Equation {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
-    fn try_from((input,context): (&'a str,&'b Context<'b>)) -> Result<Self, Self::Error> {
-        all_consuming(parse_equation(&context))(input)
-        .map(|(_,v)| v)
+impl ExpressionParser<'_,'_> {
+    pub(super) fn equation(&mut self) -> Result<Equation,ParseError> {
+        let left = self.expression()?;
+        self.equation_with_left(left)
+    }
+
+    /// Parses the relation and right side of an equation whose left side has already been parsed
+    pub(super) fn equation_with_left(&mut self,left : Expression) -> Result<Equation,ParseError> {
+        let relation = self.relation()?;
+        let right = self.expression()?;
+
+        Ok(Equation::new(left,relation,right))
+    }
+
+    /// Parses at least one relation and expression following the first expression of a chain like `2 < 3x + 1 <= 10`
+    pub(super) fn chained_relation_with_first(&mut self,first : Expression) -> Result<ChainedRelation,ParseError> {
+        let mut rest = vec![(self.relation()?,self.expression()?)];
+
+        while let Some(Token { kind : TokenKind::Relation(_), .. }) = self.lexer.peek()? {
+            rest.push((self.relation()?,self.expression()?));
+        }
+
+        Ok(ChainedRelation::new(first,rest))
+    }
+
+    fn relation(&mut self) -> Result<RelationalOperator,ParseError> {
+        const EXPECTED : &str = "a relation like `=`";
+
+        match self.lexer.next_token()? {
+            Some(Token { kind : TokenKind::Relation(relation), span }) => RelationalOperator::from_str(relation)
+                .map_err(|_| ParseError::new(span,EXPECTED,format!("`{relation}`"))),
+            Some(token) => Err(Self::unexpected(token,EXPECTED)),
+            None => Err(self.end_of_input(EXPECTED).with_hint("an equation needs a relation like `=` between both sides"))
+        }
+    }
+}
+
+impl<'a,'b> TryFrom<(&'a str,&'b Context)> for Equation {
+    type Error = ParseError;
+    fn try_from((input,context): (&'a str,&'b Context)) -> Result<Self, Self::Error> {
+        let mut parser = ExpressionParser::new(input,context);
+        let equation = parser.equation()?;
+        parser.expect_end()?;
+
+        Ok(equation)
+    }
+}
+
+impl<'a,'b> TryFrom<(&'a str,&'b Context)> for ChainedRelation {
+    type Error = ParseError;
+    fn try_from((input,context): (&'a str,&'b Context)) -> Result<Self, Self::Error> {
+        let mut parser = ExpressionParser::new(input,context);
+        let first = parser.expression()?;
+        let chain = parser.chained_relation_with_first(first)?;
+        parser.expect_end()?;
+
+        Ok(chain)
+    }
+}
+
+impl<'a> TryFrom<&'a str> for ChainedRelation {
+    type Error = ParseError;
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let context = Context::default();
+        Self::try_from((input,&context))
+    }
+}
+
+impl<'a,'b> TryFrom<(&'a str,&'b Context)> for EquationSystem {
+    type Error = ParseError;
+    fn try_from((input,context): (&'a str,&'b Context)) -> Result<Self, Self::Error> {
+        let (remaining,system) = equation_system(input,context)?;
+
+        match remaining.trim_start().chars().next() {
+            None => Ok(system),
+            Some(c) => {
+                let start = input.len() - remaining.trim_start().len();
+                Err(ParseError::new(start..start + c.len_utf8(),"the end of input",format!("`{c}`")))
+            }
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a str> for EquationSystem {
+    type Error = ParseError;
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let context = Context::default();
+        Self::try_from((input,&context))
     }
 }
 
 impl<'a> TryFrom<&'a str> for Equation {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+    type Error = ParseError;
     fn try_from(input: &'a str) -> Result<Self, Self::Error> {
         let context = Context::default();
         Self::try_from((input,&context))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_without_whitespace() {
+        let equation = Equation::try_from("2x+3=5").unwrap();
+        assert_eq!(equation.to_string(),"2x + 3 = 5");
+    }
+
+    #[test]
+    fn all_relations() {
+        for (input,relation) in [("x >= 1","x >= 1"),("x ≥ 1","x >= 1"),("x<=1","x <= 1"),("x ≤ 1","x <= 1"),("x != 1","x != 1"),("x ≠ 1","x != 1")] {
+            assert_eq!(Equation::try_from(input).unwrap().to_string(),relation);
+        }
+    }
+
+    #[test]
+    fn chained_relations() {
+        let chain = ChainedRelation::try_from("2 < 3x + 1 <= 10").unwrap();
+        assert_eq!(chain.relations().cloned().collect::<Vec<_>>(),[RelationalOperator::LessThan,RelationalOperator::LessThanOrEqual]);
+
+        let context = Context::default();
+        let (remaining,chain) = parse_chained_relation(&context)("-1 < x < 1 , y").unwrap();
+        assert_eq!(remaining,", y");
+        assert_eq!(chain.to_string(),"-1 < x < 1");
+
+        // Equations only have a single relation
+        assert!(Equation::try_from("-1 < x < 1").is_err());
+        assert!(ChainedRelation::try_from("x + 1").is_err());
+    }
+
+    #[test]
+    fn equation_systems() {
+        let context = Context::default();
+        let (remaining,system) = parse_equation_system(&context)("{ 2x + y = 3; x - y = 1 } rest").unwrap();
+        assert_eq!(remaining," rest");
+        assert_eq!(system.len(),2);
+
+        assert_eq!(EquationSystem::try_from("2x + y = 3\n\nx - y = 1").unwrap().len(),2);
+
+        let error = EquationSystem::try_from("{ 2x + y = 3; x - y }").unwrap_err();
+        assert_eq!(error.span(),20..20);
+
+        assert_eq!(EquationSystem::try_from("{ x = 1").unwrap_err().expected(),"`}`");
+        assert!(EquationSystem::try_from("{ }").is_err());
+    }
+
+    #[test]
+    fn missing_relation() {
+        let error = Equation::try_from("2x + 3").unwrap_err();
+        assert_eq!(error.span(),6..6);
+        assert_eq!(error.expected(),"a relation like `=`");
+    }
+}
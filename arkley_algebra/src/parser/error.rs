@@ -0,0 +1,263 @@
+use std::ops::Range;
+
+use thiserror::Error;
+
+/// Represents an error that occurred while parsing the input.
+///
+/// It stores the byte span of the offending input , what the parser expected , what it found instead and optionally
+/// a hint on how to fix it. Use [ParseError::render] to draw a caret under the offending part of the input.
+///
+/// # Example
+/// ```
+/// use arkley_algebra::{Expression, ParseError};
+///
+/// let input = "5 + (2 * 3";
+/// let error : ParseError = Expression::try_from(input).unwrap_err();
+///
+/// println!("{}",error.render(input));
+/// ```
+#[derive(Debug,Error,Clone,PartialEq,Eq)]
+#[error("expected {expected} , found {found}")]
+pub struct ParseError {
+    span : Range<usize>,
+    expected : String,
+    found : String,
+    hint : Option<String>,
+}
+
+impl ParseError {
+    /// Creates a new error for the given byte span
+    pub fn new(span : Range<usize>,expected : impl Into<String>,found : impl Into<String>) -> Self {
+        Self { span , expected : expected.into() , found : found.into() , hint : None }
+    }
+
+    /// Adds a hint on how the error can be fixed
+    pub fn with_hint(mut self,hint : impl Into<String>) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Gets the byte span of the offending input
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Gets what the parser expected at the span
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Gets what was found instead , like `` `)` `` or `end of input`
+    pub fn found(&self) -> &str {
+        &self.found
+    }
+
+    /// Gets the hint on how the error can be fixed (if any)
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+
+    /// Moves the span by `offset` bytes , used when the input was part of a larger input like a line of a script
+    pub(crate) fn shifted(mut self,offset : usize) -> Self {
+        self.span = self.span.start + offset..self.span.end + offset;
+        self
+    }
+
+    /// Draws a caret under the offending part of the input , like
+    ///
+    /// ```text
+    /// 2 + * 3
+    ///     ^ expected a term , function or `(` , found `*`
+    /// ```
+    ///
+    /// `input` should be the same input that was parsed
+    pub fn render(&self,input : &str) -> String {
+        let start = self.span.start.min(input.len());
+        let end = self.span.end.clamp(start,input.len());
+
+        // Only the line containing the error is drawn
+        let line_start = input[..start].rfind('\n').map_or(0,|index| index + 1);
+        let line_end = input[start..].find('\n').map_or(input.len(),|index| start + index);
+        let line = &input[line_start..line_end];
+
+        let padding = input[line_start..start].chars().count();
+        let carets = input[start..end.min(line_end)].chars().count().max(1);
+
+        let mut rendered = format!("{line}\n{}{} {self}"," ".repeat(padding),"^".repeat(carets));
+
+        if let Some(hint) = &self.hint {
+            rendered.push_str(&format!("\n{}= hint : {hint}"," ".repeat(padding)));
+        }
+
+        rendered
+    }
+
+    /// Converts an error returned by the `nom` parsers like [crate::parse_expression] into a [ParseError]
+    ///
+    /// `input` should be the full input which was parsed and `expected` describes what was being parsed , like `an expression`
+    pub fn from_nom(input : &str,error : nom::Err<nom::error::Error<&str>>,expected : &str) -> Self {
+        let (remaining,kind) = match error {
+            nom::Err::Incomplete(_) => ("",nom::error::ErrorKind::Complete),
+            nom::Err::Error(error) | nom::Err::Failure(error) => (error.input,error.code)
+        };
+
+        let start = input.len().saturating_sub(remaining.len());
+        let found = remaining.chars().next();
+        let span = start..start + found.map_or(0,char::len_utf8);
+
+        let expected = match kind {
+            // Returned by `all_consuming` when there is input left over
+            nom::error::ErrorKind::Eof => "an operator or the end of input",
+            _ => expected
+        };
+
+        let error = Self::new(span,expected,found.map_or("end of input".to_string(),|c| format!("`{c}`")));
+
+        match Self::guess_hint(input,found) {
+            Some(hint) => error.with_hint(hint),
+            None => error
+        }
+    }
+
+    fn guess_hint(input : &str,found : Option<char>) -> Option<&'static str> {
+        let open = input.matches('(').count();
+        let close = input.matches(')').count();
+
+        match found {
+            _ if open > close => Some("add the missing closing `)`"),
+            _ if close > open => Some("remove the extra `)` or add an opening `(`"),
+            Some('=' | '<' | '>') => Some("relations like `=` can only be used in equations"),
+            None => Some("the input ended too early , maybe a term is missing"),
+            _ => None
+        }
+    }
+}
+
+/// The error returned by the `nom` parsers like [crate::parse_expression] , which keeps the [ParseError] describing what went wrong
+///
+/// The span of the [ParseError] is relative to [NomError::input] , the input given to the parser which failed.
+/// Use [NomError::into_parse_error] to get it relative to the whole input.
+///
+/// # Example
+/// ```
+/// use arkley_algebra::{parse_expression, Context};
+///
+/// let Err(nom::Err::Error(error)) = parse_expression(&Context::default())("2 + * 3") else { panic!() };
+/// assert_eq!(error.error().found(),"`*`");
+/// assert_eq!(error.into_parse_error("2 + * 3").span(),4..5);
+/// ```
+#[derive(Debug,Clone,PartialEq,Eq)]
+pub struct NomError<'a> {
+    input : &'a str,
+    error : ParseError,
+}
+
+impl<'a> NomError<'a> {
+    /// Creates a new error for the input given to the parser which failed
+    pub const fn new(input : &'a str,error : ParseError) -> Self {
+        Self { input , error }
+    }
+
+    /// Gets the input given to the parser which failed
+    pub const fn input(&self) -> &'a str {
+        self.input
+    }
+
+    /// Gets the error , whose span is relative to [NomError::input]
+    pub const fn error(&self) -> &ParseError {
+        &self.error
+    }
+
+    /// Converts it into a [ParseError] whose span is relative to `input` , which should be the whole input that was parsed
+    pub fn into_parse_error(self,input : &str) -> ParseError {
+        self.error.shifted(input.len().saturating_sub(self.input.len()))
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for NomError<'a> {
+    // Used by the combinators of `nom` like `tag` , which only know the kind of parser which failed
+    fn from_error_kind(input : &'a str,kind : nom::error::ErrorKind) -> Self {
+        let found = input.chars().next();
+        let error = ParseError::new(
+            0..found.map_or(0,char::len_utf8),
+            kind.description(),
+            found.map_or("end of input".to_string(),|c| format!("`{c}`"))
+        );
+
+        Self::new(input,error)
+    }
+
+    fn append(_ : &'a str,_ : nom::error::ErrorKind,other : Self) -> Self {
+        other
+    }
+}
+
+/// The result of the `nom` parsers like [crate::parse_expression] , see [NomError]
+pub type ParseResult<'a,T> = nom::IResult<&'a str,T,NomError<'a>>;
+
+/// Represents an error that occurred while running statements in or resolving values from a [crate::Context]
+#[derive(Debug,Error,Clone,PartialEq,Eq)]
+pub enum ContextError {
+    /// A value depends on itself , like `x = y + 1` and `y = x` , with the variables of the cycle in order
+    #[error("cyclic definition {}",cycle_to_string(.0))]
+    Cycle(Vec<char>),
+    /// A statement could not be parsed
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
+
+fn cycle_to_string(cycle : &[char]) -> String {
+    cycle.iter().map(char::to_string).collect::<Vec<_>>().join(" -> ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Expression, Term};
+
+    #[test]
+    fn render_caret() {
+        let error = ParseError::new(4..5,"a term","`*`");
+        assert_eq!(error.render("2 + * 3"),"2 + * 3\n    ^ expected a term , found `*`");
+
+        let error = error.with_hint("remove the `*`");
+        assert_eq!(error.render("2 + * 3"),"2 + * 3\n    ^ expected a term , found `*`\n    = hint : remove the `*`");
+    }
+
+    #[test]
+    fn render_multiline_and_end_of_input() {
+        let error = ParseError::new(10..10,"a term","end of input");
+        assert_eq!(error.render("x = 2\ny + "),"y + \n    ^ expected a term , found end of input");
+    }
+
+    #[test]
+    fn from_try_from() {
+        let error = Expression::try_from("5 + (2 * 3").unwrap_err();
+        assert_eq!(error.span(),10..10);
+        assert_eq!(error.hint(),Some("add the missing closing `)`"));
+
+        let error = Term::try_from("2x^").unwrap_err();
+        assert_eq!(error.found(),"`^`");
+    }
+
+    #[test]
+    fn nom_errors_keep_the_parse_error() {
+        use nom::bytes::complete::tag;
+
+        let input = "x = (2 + 3";
+        let (rest,_) = tag::<_,_,NomError>("x = ")(input).unwrap();
+
+        let Err(nom::Err::Error(error)) = crate::parse_expression(&Default::default())(rest) else { panic!() };
+        assert_eq!(error.error().hint(),Some("add the missing closing `)`"));
+        assert_eq!(error.into_parse_error(input).span(),10..10);
+
+        let Err(nom::Err::Error(error)) = tag::<_,_,NomError>("evaluate")("solve") else { panic!() };
+        assert_eq!(error.error().found(),"`s`");
+    }
+
+    #[test]
+    fn cycle_message() {
+        assert_eq!(ContextError::Cycle(vec!['x','y','x']).to_string(),"cyclic definition x -> y -> x");
+    }
+}
@@ -1,48 +1,455 @@
-use nom::{IResult, combinator::all_consuming};
+use std::ops::Range;
 
-use crate::{Expression, Context};
+use num_notation::{Number, One, Zero};
 
-use super::tokens::Token;
+use crate::{Expression, Context, ParseError, NomError, ParseResult, ArithmeticOperation, Term};
+
+use super::lexer::{Lexer, Token, TokenKind};
 
 /// Parses a mathematical expression from the input string.
 ///
 /// This function takes an input string and parses it into a mathematical expression. It handles
 /// expressions with various levels of complexity, including terms, binary operations, and nested
-/// expressions.
+/// expressions. Whitespace between tokens is ignored so `2x+3` and `2x + 3` are the same.
+///
+/// Parsing stops at the first token that can not continue the expression (like `=`) , which is returned as the
+/// remaining input. Use [Expression::try_from] to parse the whole input with a detailed [ParseError].
 ///
 /// # Arguments
 ///
 /// * `input`: A string containing the mathematical expression to be parsed.
-pub fn parse_expression<'a : 'b,'b>(context : &'b Context<'b>) -> impl FnMut(&'a str) -> IResult<&'a str,Expression> + 'b {
-    // TODO : This parses 2x = 5 into 5 as an equation and succeds for some reason
+pub fn parse_expression<'a : 'b,'b>(context : &'b Context) -> impl FnMut(&'a str) -> ParseResult<'a,Expression> + 'b {
     move |input| {
-        let (input,vec) = Token::parse(context)(input)?;
-        let expression = Token::into_expression_tree(Token::to_rpn(vec));
-    
-        Ok((input,expression))
+        let mut parser = ExpressionParser::new(input,context);
+
+        match parser.expression() {
+            Ok(expression) => Ok((parser.lexer.remaining(),expression)),
+            Err(error) => Err(parser.into_nom_error(input,error))
+        }
     }
 }
 
-impl<'a,'b> TryFrom<(&'a str,&'b Context<'b>)> for Expression {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
-    fn try_from((input,context): (&'a str,&'b Context<'b>)) -> Result<Self, Self::Error> {
-        let (_,vec) = all_consuming(Token::parse(context))(input)?;
-        let expression = Token::into_expression_tree(Token::to_rpn(vec));
-    
+impl<'a,'b> TryFrom<(&'a str,&'b Context)> for Expression {
+    type Error = ParseError;
+    fn try_from((input,context): (&'a str,&'b Context)) -> Result<Self, Self::Error> {
+        let mut parser = ExpressionParser::new(input,context);
+        let expression = parser.expression()?;
+        parser.expect_end()?;
+
         Ok(expression)
     }
 }
 
 impl<'a> TryFrom<&'a str> for Expression {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+    type Error = ParseError;
     fn try_from(input: &'a str) -> Result<Self, Self::Error> {
         let context = Context::default();
+        Self::try_from((input,&context))
+    }
+}
+
+impl ArithmeticOperation {
+    const fn precedence(&self) -> i32 {
+        match self {
+            ArithmeticOperation::Plus | ArithmeticOperation::Minus => 1,
+            ArithmeticOperation::Mal | ArithmeticOperation::Durch => 2,
+            ArithmeticOperation::Pow => 4,
+        }
+    }
+}
+
+/// Implicit multiplication binds tighter than `*` and `/` so `1/2(x)` is `1/(2(x))` , just like `1/2x`
+const IMPLICIT_MULTIPLICATION_PRECEDENCE : i32 = 3;
+
+/// The deepest nesting (of brackets , roots , powers ...) which is parsed , as every level uses more of the stack
+pub(super) const MAX_DEPTH : usize = 128;
+
+/// Parses expressions in a single pass using precedence climbing , reading the tokens from the [Lexer] one at a time
+pub(super) struct ExpressionParser<'a,'b> {
+    pub(super) lexer : Lexer<'a>,
+    pub(super) context : &'b Context,
+    depth : usize,
+}
+
+impl<'a,'b> ExpressionParser<'a,'b> {
+    pub(super) const fn new(input : &'a str,context : &'b Context) -> Self {
+        Self { lexer : Lexer::new(input) , context , depth : 0 }
+    }
+
+    /// Creates a parser for LaTeX input , see [super::parse_latex_expression]
+    pub(super) const fn latex(input : &'a str,context : &'b Context) -> Self {
+        Self { lexer : Lexer::latex(input) , context , depth : 0 }
+    }
+
+    /// Converts the error into a `nom` error , keeping the [ParseError] for the caller
+    pub(super) fn into_nom_error(self,input : &'a str,error : ParseError) -> nom::Err<NomError<'a>> {
+        nom::Err::Error(NomError::new(input,error))
+    }
+
+    /// Returns an error if there is any input left
+    pub(super) fn expect_end(&mut self) -> Result<(),ParseError> {
+        match self.lexer.next_token()? {
+            None => Ok(()),
+            Some(token) => Err(Self::unexpected(token,"an operator or the end of input"))
+        }
+    }
+
+    /// Consumes the next token if it is `kind` , otherwise returns an error
+    pub(super) fn expect(&mut self,kind : TokenKind<'_>,expected : &str) -> Result<Range<usize>,ParseError> {
+        match self.lexer.next_token()? {
+            Some(token) if token.kind == kind => Ok(token.span),
+            Some(token) => Err(Self::unexpected(token,expected)),
+            None => Err(self.end_of_input(expected))
+        }
+    }
+
+    pub(super) fn unexpected(token : Token<'_>,expected : &str) -> ParseError {
+        let error = ParseError::new(token.span,expected,token.kind.describe());
+
+        match token.kind {
+            TokenKind::Relation(_) => error.with_hint("relations like `=` can only be used in equations"),
+            TokenKind::CloseParenthesis => error.with_hint("remove the extra `)` or add an opening `(`"),
+            _ => error
+        }
+    }
+
+    pub(super) fn end_of_input(&self,expected : &str) -> ParseError {
+        let end = self.lexer.end();
+        let error = ParseError::new(end..end,expected,"end of input");
+
+        match expected {
+            "`)`" | "`}`" | "`]`" => error.with_hint(format!("add the missing closing {expected}")),
+            _ => error.with_hint("the input ended too early , maybe a term is missing")
+        }
+    }
+
+    pub(super) fn expression(&mut self) -> Result<Expression,ParseError> {
+        self.binary(0)
+    }
+
+    fn binary(&mut self,min_precedence : i32) -> Result<Expression,ParseError> {
+        self.nested(|parser| parser.operations(min_precedence))
+    }
+
+    /// Parses operations whose precedence is at least `min_precedence`
+    fn operations(&mut self,min_precedence : i32) -> Result<Expression,ParseError> {
+        let mut left = self.unary()?;
+
+        loop {
+            let (operation,precedence,implicit) = match self.lexer.peek()? {
+                Some(Token { kind : TokenKind::Operator(operation), .. }) => (operation.clone(),operation.precedence(),false),
+                // Implicit multiplication like `2(x + 1)` , `(x + 1)y` , `2√x` , `2\frac{x}{3}` or `2\sin{x}`
+                Some(Token { kind : TokenKind::Word(_) | TokenKind::OpenParenthesis | TokenKind::Root(_) | TokenKind::OpenBrace | TokenKind::Fraction | TokenKind::Command(_), .. }) =>
+                    (ArithmeticOperation::Mal,IMPLICIT_MULTIPLICATION_PRECEDENCE,true),
+                _ => break
+            };
 
-        let (_,vec) = all_consuming(Token::parse(&context))(input)?;
-        let expression = Token::into_expression_tree(Token::to_rpn(vec));
-    
+            if precedence < min_precedence {
+                break;
+            }
+
+            if !implicit {
+                self.lexer.next_token()?;
+            }
+
+            let right = match operation {
+                // Powers are right associative
+                ArithmeticOperation::Pow => self.binary(precedence)?,
+                _ => self.binary(precedence + 1)?
+            };
+
+            left = Expression::new_binary(operation,left,right);
+        }
+
+        Ok(left)
+    }
+
+    /// Parses any number of leading signs like `--x` , which cancel each other out
+    fn unary(&mut self) -> Result<Expression,ParseError> {
+        let mut negative = false;
+
+        while let Some(Token { kind : TokenKind::Operator(operation @ (ArithmeticOperation::Plus | ArithmeticOperation::Minus)), .. }) = self.lexer.peek()? {
+            negative ^= *operation == ArithmeticOperation::Minus;
+            self.lexer.next_token()?;
+        }
+
+        let expression = self.power()?;
+
+        Ok(match negative {
+            true => negate(expression),
+            false => expression
+        })
+    }
+
+    /// Runs `parse` one level deeper , returning an error instead of overflowing the stack if the input is nested deeper than [MAX_DEPTH]
+    fn nested<T>(&mut self,parse : impl FnOnce(&mut Self) -> Result<T,ParseError>) -> Result<T,ParseError> {
+        if self.depth == MAX_DEPTH {
+            let end = self.lexer.end();
+            let span = self.lexer.peek()?.map_or(end..end,|token| token.span.clone());
+            return Err(
+                ParseError::new(span,format!("at most {MAX_DEPTH} nested levels"),"too deeply nested input")
+                    .with_hint("split the input into smaller expressions")
+            );
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+
+        result
+    }
+
+    pub(super) fn power(&mut self) -> Result<Expression,ParseError> {
+        self.nested(|parser| {
+            let base = parser.primary()?;
+            parser.optional_exponent(base)
+        })
+    }
+
+    /// Raises `base` to the exponent if it is followed by `^` or a superscript like `²`
+    fn optional_exponent(&mut self,base : Expression) -> Result<Expression,ParseError> {
+        match self.lexer.peek()?.cloned() {
+            Some(Token { kind : TokenKind::Superscript(exponent), .. }) => {
+                self.lexer.next_token()?;
+                Ok(Expression::new_pow(base,Term::new(exponent).into()))
+            },
+            Some(Token { kind : TokenKind::Operator(ArithmeticOperation::Pow), .. }) => {
+                self.lexer.next_token()?;
+                let exponent = self.exponent()?;
+                Ok(Expression::new_pow(base,exponent))
+            },
+            _ => Ok(base)
+        }
+    }
+
+    /// Parses an exponent , which binds tighter than implicit multiplication so `x^2y` is `x^2 * y`
+    fn exponent(&mut self) -> Result<Expression,ParseError> {
+        // Exponents of variables like `x^y^z` are parsed without passing through [ExpressionParser::power]
+        self.nested(Self::signed_exponent)
+    }
+
+    fn signed_exponent(&mut self) -> Result<Expression,ParseError> {
+        let negative = match self.lexer.peek()? {
+            Some(Token { kind : TokenKind::Operator(operation @ (ArithmeticOperation::Plus | ArithmeticOperation::Minus)), .. }) => {
+                let negative = *operation == ArithmeticOperation::Minus;
+                self.lexer.next_token()?;
+                negative
+            },
+            _ => false
+        };
+
+        let exponent = match self.lexer.peek()?.cloned() {
+            Some(Token { kind : TokenKind::Number(number), .. }) => {
+                self.lexer.next_token()?;
+                self.optional_exponent(Term::new(number).into())?
+            },
+            _ => self.power()?
+        };
+
+        Ok(match negative {
+            true => negate(exponent),
+            false => exponent
+        })
+    }
+
+    fn primary(&mut self) -> Result<Expression,ParseError> {
+        const EXPECTED : &str = "a term , function or `(`";
+
+        let Some(token) = self.lexer.peek()?.cloned() else {
+            return Err(self.end_of_input(EXPECTED));
+        };
+
+        match token.kind {
+            TokenKind::Number(number) => {
+                self.lexer.next_token()?;
+                self.term(number)
+            },
+            TokenKind::Word(word) => self.word(word,token.span),
+            TokenKind::Command(name) => self.command(name,token.span),
+            TokenKind::OpenParenthesis => {
+                self.lexer.next_token()?;
+                let expression = self.expression()?;
+                self.expect(TokenKind::CloseParenthesis,"`)`")?;
+                Ok(expression)
+            },
+            // Groups like `{x + 1}` in `x^{x + 1}` are only used by LaTeX
+            TokenKind::OpenBrace => self.group(),
+            TokenKind::Fraction => {
+                self.lexer.next_token()?;
+                let numerator = self.group()?;
+                let denominator = self.group()?;
+                Ok(Expression::new_durch(numerator,denominator))
+            },
+            // Roots like `√(x + 1)` or `√x²` apply to the power following them
+            TokenKind::Root(degree) => {
+                self.lexer.next_token()?;
+
+                // LaTeX roots can have a degree like `\sqrt[3]{x}`
+                let degree = match self.lexer.peek()? {
+                    Some(Token { kind : TokenKind::OpenBracket, span }) => {
+                        let start = span.start;
+                        self.lexer.next_token()?;
+                        let degree = self.expression()?;
+                        let end = self.expect(TokenKind::CloseBracket,"`]`")?.end;
+
+                        if matches!(&degree,Expression::Term(term) if term.variables.is_empty() && term.coefficient.is_zero()) {
+                            return Err(ParseError::new(start..end,"the degree of a root","a degree of 0")
+                                .with_hint("a root of degree 0 is undefined"));
+                        }
+
+                        degree
+                    },
+                    _ => Term::new(Number::Decimal(f64::from(degree))).into()
+                };
+
+                let radicand = self.power()?;
+                Ok(root(radicand,degree))
+            },
+            _ => {
+                self.lexer.next_token()?;
+                Err(Self::unexpected(token,EXPECTED))
+            }
+        }
+    }
+
+    /// Parses a LaTeX group like `{x + 1}`
+    pub(super) fn group(&mut self) -> Result<Expression,ParseError> {
+        self.expect(TokenKind::OpenBrace,"`{`")?;
+        let expression = self.expression()?;
+        self.expect(TokenKind::CloseBrace,"`}`")?;
         Ok(expression)
     }
+
+    /// Parses a tag , function or variables
+    fn word(&mut self,word : &'a str,span : Range<usize>) -> Result<Expression,ParseError> {
+        #[cfg(feature="function")]
+        if let Some(function) = self.context.function(word) {
+            self.lexer.next_token()?;
+            return self.call(function.clone()).map(Expression::from);
+        }
+
+        if let Some(expression) = self.context.tag(word) {
+            self.lexer.next_token()?;
+            return Ok(expression.clone());
+        }
+
+        if word == "π" {
+            self.lexer.next_token()?;
+            return self.term(Number::Decimal(std::f64::consts::PI));
+        }
+
+        if word.chars().count() > 1 && self.is_followed_by_parenthesis() {
+            return Err(Self::unknown_function(word,span));
+        }
+
+        match is_variables(word) {
+            true => self.term(Number::one()),
+            false => Err(
+                ParseError::new(span,"a tag , function or variables",format!("`{word}`"))
+                    .with_hint("only single letters from `a` to `z` can be used as variables")
+            )
+        }
+    }
+
+    /// Parses the variables directly following a coefficient into a single term , like `2x^2y`
+    fn term(&mut self,coefficient : Number) -> Result<Expression,ParseError> {
+        let mut term = Term::new(coefficient);
+
+        while let Some(Token { kind : TokenKind::Word(word), .. }) = self.lexer.peek()? {
+            let word = *word;
+
+            if !is_variables(word) || self.context.tag(word).is_some() || self.is_call(word) {
+                break;
+            }
+
+            self.lexer.next_token()?;
+
+            for variable in word.chars() {
+                term.variables.entry(variable).and_modify(|exponent| *exponent += Number::one()).or_insert_with(Number::one);
+            }
+
+            if let Some(Token { kind : TokenKind::Superscript(exponent), .. }) = self.lexer.peek()? {
+                let exponent = exponent.clone();
+                self.lexer.next_token()?;
+
+                let variable = word.chars().last().unwrap_or_default();
+                let current = term.variables.entry(variable).or_insert_with(Number::one);
+                *current += exponent - Number::one();
+            }
+            else if let Some(Token { kind : TokenKind::Operator(ArithmeticOperation::Pow), .. }) = self.lexer.peek()? {
+                self.lexer.next_token()?;
+
+                // The exponent only applies to the last variable , like `y` in `xy^2`
+                let variable = word.chars().last().unwrap_or_default();
+                let exponent = self.exponent()?;
+
+                match exponent {
+                    Expression::Term(exponent) if exponent.variables.is_empty() => {
+                        let current = term.variables.entry(variable).or_insert_with(Number::one);
+                        *current += exponent.coefficient - Number::one();
+                    },
+                    exponent => return Ok(power_of_variable(term,variable,exponent))
+                }
+            }
+        }
+
+        Ok(term.into())
+    }
+
+    /// Checks if the peeked word is the name of a function , or a longer word directly followed by `(` like `max(a, b)`
+    fn is_call(&self,word : &str) -> bool {
+        #[cfg(feature="function")]
+        if self.context.function(word).is_some() {
+            return true;
+        }
+
+        word.len() > 1 && self.is_followed_by_parenthesis()
+    }
+
+    /// Checks if the peeked token is followed by `(`
+    fn is_followed_by_parenthesis(&self) -> bool {
+        let mut lexer = self.lexer.clone();
+        let _ = lexer.next_token();
+        matches!(lexer.peek(),Ok(Some(Token { kind : TokenKind::OpenParenthesis, .. })))
+    }
+}
+
+/// Checks if the word only contains single letter variable names like `xy`
+fn is_variables(word : &str) -> bool {
+    word.chars().all(|c| c.is_ascii_lowercase())
+}
+
+/// Creates the `degree`th root of the radicand like `√x` , which is stored as `x^(1 / 2)`
+pub(super) fn root(radicand : Expression,degree : Expression) -> Expression {
+    Expression::new_pow(radicand,Expression::new_durch(Term::new(Number::one()).into(),degree))
+}
+
+fn negate(expression : Expression) -> Expression {
+    match expression {
+        Expression::Term(mut term) => {
+            term.coefficient = -term.coefficient;
+            term.into()
+        },
+        expression => Expression::new_mal(Term::new(Number::Decimal(-1.0)).into(),expression)
+    }
+}
+
+/// Creates `term * variable^exponent` for exponents that are not constant , like `2x^y`
+fn power_of_variable(mut term : Term,variable : char,exponent : Expression) -> Expression {
+    if let Some(current) = term.variables.get_mut(&variable) {
+        *current -= Number::one();
+
+        if current.is_zero() {
+            term.variables.remove(&variable);
+        }
+    }
+
+    let power = Expression::new_pow(variable.into(),exponent);
+
+    match term.variables.is_empty() && term.coefficient.is_one() {
+        true => power,
+        false => Expression::new_mal(term.into(),power)
+    }
 }
 
 #[cfg(test)]
@@ -52,7 +459,7 @@ mod tests {
     #[test]
     fn parse_simple_addition() {
         let input_str = "3 + 4";
-        let context = Default::default();   
+        let context = Default::default();
         let parsed = parse_expression(&context)(input_str);
         let expected_expression = Expression::new_plus( 3.0.into(),  4.0.into());
 
@@ -63,7 +470,7 @@ mod tests {
     #[test]
     fn parse_complex_expression() {
         let input_str = "1 + (2 * 3)";
-        let context = Default::default();   
+        let context = Default::default();
         let parsed = parse_expression(&context)(input_str);
 
         assert!(parsed.is_ok());
@@ -73,11 +480,11 @@ mod tests {
     #[test]
     fn parse_with_implicit_mul() {
         let input_str = "1 + 2(4)";
-        let context = Default::default();   
+        let context = Default::default();
         let parsed = parse_expression(&context)(input_str);
 
         let expected_expression = Expression::new_plus(
-            1.0.into(), 
+            1.0.into(),
             Expression::new_mal(2.0.into(), 4.0.into())
         );
         assert!(parsed.is_ok());
@@ -87,7 +494,7 @@ mod tests {
     #[test]
     fn parse_expression_with_unary_minus() {
         let input_str = "-5 + 2";
-        let context = Default::default();   
+        let context = Default::default();
 
         let parsed = parse_expression(&context)(input_str);
         let expected_expression =  Expression::new_plus((-5.0).into(),  2.0.into());
@@ -98,20 +505,21 @@ mod tests {
 
     #[test]
     fn parse_invalid_expression() {
-        let input_str = "5 + (2 * 3"; 
-        let context = Default::default();   
+        let input_str = "5 + (2 * 3";
+        let context = Default::default();
 
         let parsed = parse_expression(&context)(input_str);
-       
-        // one would thing it should be none but parser stops checking at 5 + so output is 5 , for full consumuing use try_from
-        let unwrapped = parsed.unwrap().1;
-        assert_eq!(&unwrapped.to_string(),"5")
+        assert!(parsed.is_err());
+
+        let error = Expression::try_from(input_str).unwrap_err();
+        assert_eq!(error.span(),10..10);
+        assert_eq!(error.hint(),Some("add the missing closing `)`"));
     }
 
     #[test]
     fn parse_expression_with_multiple_operators() {
         let input_str = "2 + 3 * 4 - 5 / 1";
-        let context = Default::default();   
+        let context = Default::default();
 
         let parsed = parse_expression(&context)(input_str);
         let expected_expression = Expression::new_minus(
@@ -126,7 +534,7 @@ mod tests {
     #[test]
     fn double_brackets() {
         let input_str = "(2 + 3)(4/4)";
-        let context = Default::default();   
+        let context = Default::default();
 
         let parsed = parse_expression(&context)(input_str);
 
@@ -137,9 +545,9 @@ mod tests {
     #[test]
     fn with_context() {
         let mut context = Context::default();
-        context.tags_mut().insert("five", 5.into());
-        context.tags_mut().insert("two", 2.into());
-        context.tags_mut().insert("sieben", 7.into());
+        context.tags_mut().insert("five".to_string(), 5.into());
+        context.tags_mut().insert("two".to_string(), 2.into());
+        context.tags_mut().insert("sieben".to_string(), 7.into());
 
         let result = parse_expression(&context)("five * two + sieben");
 
@@ -147,4 +555,94 @@ mod tests {
 
         assert_eq!(&result.unwrap().1.to_string(),"5 * 2 + 7")
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn whitespace_insensitive() {
+        let expected = Expression::new_plus(Term::create_single_variable_term(2.0.into(),'x',1.0.into()).into(),3.0.into());
+
+        assert_eq!(Expression::try_from("2x+3").unwrap(),expected);
+        assert_eq!(Expression::try_from(" 2 x +  3 ").unwrap(),expected);
+    }
+
+    #[test]
+    fn signs_powers_and_terms() {
+        assert_eq!(Expression::try_from("2 ++ 3").unwrap(),Expression::new_plus(2.0.into(),3.0.into()));
+        assert_eq!(Expression::try_from("2 +- 3").unwrap(),Expression::new_plus(2.0.into(),(-3.0).into()));
+        assert_eq!(Expression::try_from("--x").unwrap(),Expression::from('x'));
+
+        let term = Term::new_with_variable(3.0.into(),[('x',2.0.into()),('y',1.0.into())].into());
+        assert_eq!(Expression::try_from("3x^2y").unwrap(),term.clone().into());
+        assert_eq!(Expression::try_from("-3x^2y").unwrap(),Term::new_with_variable((-3.0).into(),term.variables).into());
+
+        // 2^3^2 = 2^(3^2)
+        let expected = Expression::new_pow(2.0.into(),Expression::new_pow(3.0.into(),2.0.into()));
+        assert_eq!(Expression::try_from("2^3^2").unwrap(),expected);
+
+        let expected = Expression::new_mal(2.0.into(),Expression::new_pow('x'.into(),'y'.into()));
+        assert_eq!(Expression::try_from("2x^y").unwrap(),expected);
+    }
+
+    #[test]
+    fn unicode_input() {
+        let parse = |input : &str| Expression::try_from(input).unwrap();
+
+        assert_eq!(parse("3×4"),parse("3 * 4"));
+        assert_eq!(parse("6÷2"),parse("6 / 2"));
+        assert_eq!(parse("−5 − x"),parse("-5 - x"));
+        assert_eq!(parse("3x²y"),parse("3x^2y"));
+        assert_eq!(parse("(x + 1)²"),parse("(x + 1)^2"));
+        assert_eq!(parse("x⁻¹"),parse("x^-1"));
+        assert_eq!(parse("2√(x+1)"),parse("2(x + 1)^(1 / 2)"));
+        assert_eq!(parse("∛x"),parse("x^(1 / 3)"));
+        let pi_r = Term::create_single_variable_term(Number::Decimal(std::f64::consts::PI),'r',1.0.into());
+        assert_eq!(parse("2πr"),Expression::new_mal(2.0.into(),pi_r.into()));
+    }
+
+    #[test]
+    fn rejects_trailing_input() {
+        let context = Default::default();
+        let (remaining,expression) = parse_expression(&context)("2x = 5").unwrap();
+        assert_eq!(remaining,"= 5");
+        assert_eq!(expression.to_string(),"2x");
+
+        let error = Expression::try_from("2x = 5").unwrap_err();
+        assert_eq!(error.span(),3..4);
+        assert_eq!(error.found(),"`=`");
+
+        assert!(Expression::try_from("").is_err());
+        assert!(Expression::try_from("2 *").is_err());
+    }
+
+    #[test]
+    fn implicit_multiplication_binds_tighter_than_division() {
+        let parse = |input| Expression::try_from(input).unwrap();
+
+        assert_eq!(parse("1/2x"),parse("1/(2x)"));
+        assert_eq!(parse("1/2(x)"),parse("1/(2(x))"));
+        assert_eq!(parse("6/2(1 + 2)").to_string(),parse("6/(2(1 + 2))").to_string());
+        assert_eq!(parse("2 * 3(x)"),parse("2 * (3(x))"));
+    }
+
+    #[test]
+    fn deeply_nested_input() {
+        let nested = |depth : usize| format!("{}x{}","(".repeat(depth),")".repeat(depth));
+
+        assert_eq!(Expression::try_from(nested(50).as_str()).unwrap().to_string(),"x");
+
+        let error = Expression::try_from(nested(10_000).as_str()).unwrap_err();
+        assert_eq!(error.found(),"too deeply nested input");
+        assert_eq!(error.span(),MAX_DEPTH / 2..MAX_DEPTH / 2 + 1);
+
+        assert!(Expression::try_from(format!("{}x{}","(x^".repeat(10_000),")".repeat(10_000)).as_str()).is_err());
+
+        assert!(Expression::try_from("√".repeat(10_000).as_str()).is_err());
+        assert!(Expression::try_from(vec!["x"; 10_000].join("^").as_str()).is_err());
+    }
+
+    #[test]
+    fn long_input() {
+        let input = vec!["2x"; 5000].join(" + ");
+        let expression = Expression::try_from(input.as_str()).unwrap();
+        assert!(matches!(expression,Expression::Binary { operation : ArithmeticOperation::Plus, .. }));
+    }
+}
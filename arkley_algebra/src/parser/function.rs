@@ -1,99 +1,252 @@
-use nom::{
-    IResult, 
-    character::complete::{char, multispace0}, 
-    sequence::{delimited, terminated}, 
-    combinator::all_consuming, 
-    bytes::complete::{take_till, take}
-};
+use std::ops::Range;
 
-use crate::{Function, FunctionArguments, parse_expression, Context, Expression};
-use super::satisfies_variable_name;
+use crate::{Function, FunctionArguments, Context, ParseError, ParseResult};
+
+use super::{expression::ExpressionParser, lexer::{Token, TokenKind}};
 
 /// Parses a function definition from the given input string.
 ///
 /// This function takes an input string, `input`, and attempts to parse a function definition
-/// in the form of `name(arguments) = expression'.
-pub fn parse_function_definition<'a,T,F>(context : &'a Context<'_>) -> impl FnMut(&'a str) -> IResult<&'a str,Function>  where T : Fn() -> Expression , F : Fn() -> Function {
+/// in the form of `name(arguments) = expression`.
+pub fn parse_function_definition<'a : 'b,'b>(context : &'b Context) -> impl FnMut(&'a str) -> ParseResult<'a,Function> + 'b {
     move |input| {
-        let (input,name) = Function::parse_name(input)?;
-        let (input,arguments) = Function::parse_arguments(input)?;
-        let (input,_) = delimited(multispace0, char('='), multispace0)(input)?;
-    
-        let (input,expression) = parse_expression(context)(input)?;
-    
-        let function = Function::new_default(name.into(),expression,arguments);
-    
-        Ok((input,function))
+        let mut parser = ExpressionParser::new(input,context);
+
+        match parser.definition() {
+            Ok((_,function)) => Ok((parser.lexer.remaining(),function)),
+            Err(error) => Err(parser.into_nom_error(input,error))
+        }
     }
 }
 
-
-/// Parses a custom function definition from the input string, using the provided `context`.
+/// Parses a call of a function defined in the context , like `f(x)`
 ///
-/// This function is designed to parse custom function definitions, which include user-defined functions
+/// This function is designed to parse calls of custom functions, which include user-defined functions
 /// and potentially more complex functions , as an example trigonometric functions (e.g., cos, sin, tan) defined using
-/// custom closures. The `context` parameter is used to provide context for parsing these functions.
-pub fn parse_function<'a : 'b,'b>(context : &'b Context<'b>) -> impl FnMut(&'a str) -> IResult<&'a str,Function> + 'b {
+/// custom closures. The `context` parameter is used to look up the definition of these functions.
+pub fn parse_function<'a : 'b,'b>(context : &'b Context) -> impl FnMut(&'a str) -> ParseResult<'a,Function> + 'b {
     move |input| {
-        let (input,name) = Function::parse_name(input)?;
-        let (input,mut _arguments) = Function::parse_arguments_with_context(context)(input)?;
+        let mut parser = ExpressionParser::new(input,context);
+
+        match parser.function() {
+            Ok(function) => Ok((parser.lexer.remaining(),function)),
+            Err(error) => Err(parser.into_nom_error(input,error))
+        }
+    }
+}
 
-        let function = match context.functions().get(name) {
-            None => todo!(), // for now , return error in future
-            Some(value) => {
-                let mut func = value.clone();
-                
-                func.arguments.append(&mut _arguments);
+impl<'a> ExpressionParser<'a,'_> {
+    /// Parses the name and arguments of a function defined in the context
+    pub(super) fn function(&mut self) -> Result<Function,ParseError> {
+        const EXPECTED : &str = "the name of a function";
+
+        match self.lexer.next_token()? {
+            Some(Token { kind : TokenKind::Word(name), span }) => match self.context.function(name) {
+                Some(function) => self.call(function.clone()),
+                None => Err(Self::unknown_function(name,span))
+            },
+            Some(token) => Err(Self::unexpected(token,EXPECTED)),
+            None => Err(self.end_of_input(EXPECTED))
+        }
+    }
+
+    /// Parses a definition like `f(x, y) = x + y` , returning the name and the function
+    pub(super) fn definition(&mut self) -> Result<(&'a str,Function),ParseError> {
+        let name = match self.lexer.next_token()? {
+            Some(Token { kind : TokenKind::Word(name), .. }) => name,
+            Some(token) => return Err(Self::unexpected(token,"the name of a function")),
+            None => return Err(self.end_of_input("the name of a function"))
+        };
 
-                func
+        self.expect(TokenKind::OpenParenthesis,"`(`")?;
+
+        let mut parameters = Vec::new();
+
+        loop {
+            match self.lexer.next_token()? {
+                Some(Token { kind : TokenKind::Word(parameter), span }) => match parameter.chars().next().filter(|c| parameter.len() == 1 && c.is_ascii_lowercase()) {
+                    Some(letter) if parameters.contains(&letter) => return Err(ParseError::new(span,"a new parameter",format!("duplicate parameter `{parameter}`"))),
+                    Some(letter) => parameters.push(letter),
+                    None => return Err(ParseError::new(span,"a single letter parameter like `x`",format!("`{parameter}`")))
+                },
+                Some(Token { kind : TokenKind::CloseParenthesis, .. }) if parameters.is_empty() => break,
+                Some(token) => return Err(Self::unexpected(token,"a single letter parameter like `x`")),
+                None => return Err(self.end_of_input("`)`"))
+            };
+
+            match self.lexer.next_token()? {
+                Some(Token { kind : TokenKind::Comma, .. }) => continue,
+                Some(Token { kind : TokenKind::CloseParenthesis, .. }) => break,
+                Some(token) => return Err(Self::unexpected(token,"`,` or `)`")),
+                None => return Err(self.end_of_input("`)`"))
+            }
+        }
+
+        self.expect(TokenKind::Relation("="),"`=`")?;
+
+        let expression = self.expression()?;
+
+        let arguments = parameters.iter().map(|parameter| (*parameter,None)).collect::<FunctionArguments>();
+
+        Ok((name,Function::new_default(name.into(),expression,arguments).with_parameters(parameters)))
+    }
+
+    /// Checks if the upcoming tokens look like `name(x, y) =`
+    pub(super) fn is_definition(&self) -> bool {
+        let mut lexer = self.lexer.clone();
+        let mut next = || lexer.next_token().ok().flatten().map(|token| token.kind);
+
+        if !matches!((next(),next()),(Some(TokenKind::Word(_)),Some(TokenKind::OpenParenthesis))) {
+            return false;
+        }
+
+        loop {
+            match next() {
+                Some(TokenKind::Word(parameter)) if parameter.len() == 1 => (),
+                Some(TokenKind::CloseParenthesis) => return next() == Some(TokenKind::Relation("=")),
+                _ => return false
+            }
+
+            match next() {
+                Some(TokenKind::Comma) => continue,
+                Some(TokenKind::CloseParenthesis) => return next() == Some(TokenKind::Relation("=")),
+                _ => return false
+            }
+        }
+    }
+
+    pub(super) fn unknown_function(name : &str,span : Range<usize>) -> ParseError {
+        ParseError::new(span,"the name of a function",format!("unknown function `{name}`"))
+            .with_hint("define the function in the context before calling it , or use `*` for multiplication")
+    }
+
+    /// Parses the arguments of a call like `(2x + 1, g(y))` , binding them in order to the parameters of `function`
+    pub(super) fn call(&mut self,mut function : Function) -> Result<Function,ParseError> {
+        let start = self.expect(TokenKind::OpenParenthesis,"`(`")?.start;
+
+        let mut arguments = Vec::new();
+
+        let end = match self.lexer.peek()? {
+            Some(Token { kind : TokenKind::CloseParenthesis, .. }) => self.expect(TokenKind::CloseParenthesis,"`)`")?.end,
+            _ => loop {
+                arguments.push(self.expression()?);
+
+                match self.lexer.next_token()? {
+                    Some(Token { kind : TokenKind::Comma, .. }) => continue,
+                    Some(Token { kind : TokenKind::CloseParenthesis, span }) => break span.end,
+                    Some(token) => return Err(Self::unexpected(token,"`,` or `)`")),
+                    None => return Err(self.end_of_input("`)`"))
+                }
             }
         };
 
-        
-        Ok((input,function))
+        Self::check_argument_count(&function,arguments.len(),start..end)?;
+
+        for (parameter,value) in function.parameters().into_iter().zip(arguments) {
+            function.arguments.insert(parameter,Some(value));
+        }
+
+        Ok(function)
+    }
+
+    pub(super) fn check_argument_count(function : &Function,count : usize,span : Range<usize>) -> Result<(),ParseError> {
+        let expected = function.arguments.len();
+
+        match count == expected {
+            true => Ok(()),
+            false => {
+                let parameters = function.parameters().iter().map(char::to_string).collect::<Vec<_>>().join(", ");
+                Err(ParseError::new(span,format!("{expected} argument(s)"),format!("{count} argument(s)"))
+                    .with_hint(format!("`{}` is defined as {}({parameters})",function.name,function.name)))
+            }
+        }
     }
 }
 
-impl Function {
-    fn parse_name(input : &str) -> IResult<&str,&str> {
-        let (input,mut s) = terminated(
-            take_till(|c| c == '('), 
-            take(1u8) // to remove extra ( at end
-        )(input)?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{Expression, manipulation::{Evaluate, Find}};
 
-        s = s.trim();
+    fn context() -> Context {
+        let square = Function::new_default("f".into(),Expression::try_from("x^2").unwrap(),FunctionArguments::from([('x',None)]));
+        let max = Function::new_default("max".into(),Expression::try_from("a + b").unwrap(),FunctionArguments::from([('a',None),('b',None)]));
 
-        Ok((input,s))
+        let mut context = Context::default();
+        context.functions_mut().insert("f".to_string(),square);
+        context.functions_mut().insert("max".to_string(),max);
+        context
     }
-    
-    fn parse_arguments(input : &str) -> IResult<&str,FunctionArguments> {
-        let (input,arguments_str) = take_till(|c| c == ')')(input)?;
-    
-        let mut arguments = FunctionArguments::new();
-
-        for s in arguments_str.trim().split(',') {
-            let (_,key) = all_consuming(satisfies_variable_name)(s)?;
-            arguments.insert(key, None);
+
+    fn argument<'a>(expression : &'a Expression,parameter : &char) -> &'a Expression {
+        match expression {
+            Expression::Function(function) => function.arguments[parameter].as_ref().unwrap(),
+            _ => panic!("{expression} is not a function")
         }
+    }
+
+    #[test]
+    fn expression_arguments() {
+        let context = context();
 
-        Ok((input,arguments))
+        let expression = Expression::try_from(("f(2x + 1)",&context)).unwrap();
+        assert_eq!(argument(&expression,&'x').to_string(),"2x + 1");
+
+        let expression = Expression::try_from(("max(a, b+1)",&context)).unwrap();
+        assert_eq!(argument(&expression,&'a').to_string(),"a");
+        assert_eq!(argument(&expression,&'b').to_string(),"b + 1");
+
+        let expression = Expression::try_from(("f(f(3))",&context)).unwrap();
+        assert_eq!(argument(&expression,&'x').to_string(),"f(3)");
+        assert_eq!(Expression::try_from(("f(3)",&context)).unwrap().evaluate().find().to_string(),"9");
     }
 
-    fn parse_arguments_with_context<'a : 'b,'b>(_context : &'b Context<'b>) -> impl FnMut(&'a str) -> IResult<&'a str,FunctionArguments> {
-        move |input| {
-            let (input,arguments_str) = take_till(|c| c == ')')(input)?;
+    #[test]
+    fn invalid_calls() {
+        let context = context();
 
-            let mut arguments = FunctionArguments::new();
+        // Single letters are still treated as implicit multiplication
+        let expression = Expression::try_from(("2 + g(1)",&Context::default())).unwrap();
+        assert_eq!(expression.to_string(),"2 + g(1)");
 
-            for s in arguments_str.trim().split(',') {
-                match all_consuming(satisfies_variable_name)(s) {
-                    Ok((_,key)) => arguments.insert(key, None),
-                    // TODO : Figure out out the fuck to handle this, maybe convert [`FunctionArguments`] into Vec<..> , for now returned error
-                    Err(err) => return Err(err)
-                };
-            }
+        let error = Expression::try_from(("2 + sin(1)",&context)).unwrap_err();
+        assert_eq!(error.span(),4..7);
+        assert_eq!(error.found(),"unknown function `sin`");
 
-            Ok((input,arguments))
-        }
+        let error = Expression::try_from(("max(1)",&context)).unwrap_err();
+        assert_eq!(error.span(),3..6);
+        assert_eq!(error.hint(),Some("`max` is defined as max(a, b)"));
+
+        assert!(parse_function(&context)("g(1)").is_err());
+        assert!(parse_function(&context)("f(1)").is_ok());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn parameters_bind_in_definition_order() {
+        let mut context = Context::default();
+
+        let (_,function) = parse_function_definition(&context)("f(y, x) = y - x").unwrap();
+        assert_eq!(function.parameters(),['y','x']);
+        assert_eq!(function.to_string(),"f(y, x)");
+        context.functions_mut().insert("f".to_string(),function);
+
+        let expression = Expression::try_from(("f(5, 2)",&context)).unwrap();
+        assert_eq!(argument(&expression,&'y').to_string(),"5");
+        assert_eq!(context.resolve(expression).unwrap().evaluate().find().to_string(),"3");
+
+        // the second `x` is rejected
+        assert!(matches!(parse_function_definition(&context)("g(x, x) = x"),Err(nom::Err::Error(error)) if error.error().span() == (5..6)));
+    }
+
+    #[test]
+    fn debug_and_missing_expression() {
+        let context = context();
+
+        let (_,mut function) = parse_function(&context)("max(1, 2x)").unwrap();
+        assert_eq!(format!("{function:?}"),"max(1, 2x)");
+
+        *function.expression_mut() = None;
+        assert_eq!(function.evaluate().find().to_string(),"max(1, 2x)");
+    }
+}
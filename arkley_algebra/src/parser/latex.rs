@@ -0,0 +1,201 @@
+use std::ops::Range;
+
+use crate::{Expression, Context, ParseError, ParseResult};
+
+#[cfg(feature="function")]
+use crate::Function;
+
+use super::expression::ExpressionParser;
+
+#[cfg(feature="function")]
+use super::lexer::{Token, TokenKind};
+
+/// The LaTeX commands which are functions without being defined in the context , `\log` is the logarithm to base 10
+#[cfg(feature="function")]
+const FUNCTIONS : [&str;5] = ["sin","cos","tan","ln","log"];
+
+/// Parses an expression written in LaTeX , like `\frac{1}{2} \cdot x^{2}`
+///
+/// It understands a practical subset of LaTeX : `\frac{a}{b}` , `x^{2}` , `\sqrt{x}` , `\sqrt[n]{x}` , `\cdot` , `\times` , `\div` ,
+/// `\left( \right)` , `\pi` , the functions `\sin` , `\cos` , `\tan` , `\ln` and `\log` and commands which refer to functions or tags of the `context`.
+/// Everything else is parsed the same way as [super::parse_expression] does.
+pub fn parse_latex_expression<'a : 'b,'b>(context : &'b Context) -> impl FnMut(&'a str) -> ParseResult<'a,Expression> + 'b {
+    move |input| {
+        let mut parser = ExpressionParser::latex(input,context);
+
+        match parser.expression() {
+            Ok(expression) => Ok((parser.lexer.remaining(),expression)),
+            Err(error) => Err(parser.into_nom_error(input,error))
+        }
+    }
+}
+
+/// Parses an equation written in LaTeX , like `\frac{x}{2} = 3` , see [parse_latex_expression]
+#[cfg(feature="equation")]
+pub fn parse_latex_equation<'a : 'b,'b>(context : &'b Context) -> impl FnMut(&'a str) -> ParseResult<'a,crate::Equation> + 'b {
+    move |input| {
+        let mut parser = ExpressionParser::latex(input,context);
+
+        match parser.equation() {
+            Ok(equation) => Ok((parser.lexer.remaining(),equation)),
+            Err(error) => Err(parser.into_nom_error(input,error))
+        }
+    }
+}
+
+impl<'a> ExpressionParser<'a,'_> {
+    /// Parses a LaTeX command like `\sin{x}` or `\tau` , which is a function or tag of the context or one of [FUNCTIONS]
+    pub(super) fn command(&mut self,name : &'a str,span : Range<usize>) -> Result<Expression,ParseError> {
+        #[cfg(feature="function")]
+        if let Some(function) = self.context.function(name) {
+            self.lexer.next_token()?;
+            return self.command_call(function.clone(),span).map(Expression::from);
+        }
+
+        if let Some(expression) = self.context.tag(name) {
+            self.lexer.next_token()?;
+            return Ok(expression.clone());
+        }
+
+        #[cfg(feature="function")]
+        if FUNCTIONS.contains(&name) {
+            self.lexer.next_token()?;
+
+            return self.command_call(Function::built_in(name,None),span).map(Expression::from);
+        }
+
+        Err(ParseError::new(span,"a LaTeX command like `\\frac` or a function of the context",format!("unknown command `\\{name}`"))
+            .with_hint("define the function or tag in the context before using it"))
+    }
+
+    /// Parses the argument of a function called by a LaTeX command , which can be written as `\sin{x}` , `\sin(x)` or `\sin x`
+    #[cfg(feature="function")]
+    fn command_call(&mut self,mut function : Function,span : Range<usize>) -> Result<Function,ParseError> {
+        if let Some(Token { kind : TokenKind::OpenParenthesis, .. }) = self.lexer.peek()? {
+            return self.call(function);
+        }
+
+        Self::check_argument_count(&function,1,span)?;
+
+        let argument = match self.lexer.peek()? {
+            Some(Token { kind : TokenKind::OpenBrace, .. }) => self.group()?,
+            _ => self.power()?
+        };
+
+        if let Some(value) = function.arguments.values_mut().next() {
+            *value = Some(argument);
+        }
+
+        Ok(function)
+    }
+}
+
+impl Expression {
+    /// Parses the whole input as an expression written in LaTeX , see [parse_latex_expression]
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{Expression, Context};
+    ///
+    /// let expression = Expression::from_latex(r"\frac{x}{2} \cdot \left(y + 1\right)",&Context::default()).unwrap();
+    /// assert_eq!(expression.to_string(),Expression::try_from("x / 2 * (y + 1)").unwrap().to_string());
+    /// ```
+    pub fn from_latex(input : &str,context : &Context) -> Result<Self,ParseError> {
+        let mut parser = ExpressionParser::latex(input,context);
+        let expression = parser.expression()?;
+        parser.expect_end()?;
+
+        Ok(expression)
+    }
+}
+
+#[cfg(feature="equation")]
+impl crate::Equation {
+    /// Parses the whole input as an equation written in LaTeX , see [parse_latex_expression]
+    pub fn from_latex(input : &str,context : &Context) -> Result<Self,ParseError> {
+        let mut parser = ExpressionParser::latex(input,context);
+        let equation = parser.equation()?;
+        parser.expect_end()?;
+
+        Ok(equation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn latex(input : &str) -> Expression {
+        Expression::from_latex(input,&Context::default()).unwrap()
+    }
+
+    fn plain(input : &str) -> Expression {
+        Expression::try_from(input).unwrap()
+    }
+
+    #[test]
+    fn latex_subset() {
+        assert_eq!(latex(r"\frac{a}{b}"),plain("a / b"));
+        assert_eq!(latex(r"2\frac{x + 1}{3}"),plain("2((x + 1) / 3)"));
+        assert_eq!(latex(r"x^{2} + y^{x + 1}"),plain("x^2 + y^(x + 1)"));
+        assert_eq!(latex(r"3 \cdot 4 \times 5 \div 2"),plain("3 * 4 * 5 / 2"));
+        assert_eq!(latex(r"\left( x + 1 \right) \left[ y \right]"),plain("(x + 1)(y)"));
+        assert_eq!(latex(r"\sqrt{x}"),plain("x^(1 / 2)"));
+        assert_eq!(latex(r"\sqrt[n]{x + 1}"),plain("(x + 1)^(1 / n)"));
+        assert_eq!(latex(r"2\,\pi"),plain("2π"));
+    }
+
+    #[test]
+    fn many_skipped_commands() {
+        assert_eq!(latex(&format!("2{}x",r"\,".repeat(5000))),plain("2x"));
+        assert_eq!(latex(&format!("2{}x",r"\quad\;".repeat(2000))),plain("2x"));
+    }
+
+    #[test]
+    fn functions_and_tags_from_context() {
+        let mut context = Context::default();
+        context.run_script("sin(x) = x").unwrap();
+        context.tags_mut().insert("tau".to_string(),6.into());
+
+        let expression = Expression::from_latex(r"\sin(\tau)",&context).unwrap();
+        assert_eq!(expression,Expression::try_from(("sin(tau)",&context)).unwrap());
+    }
+
+    #[cfg(feature="function")]
+    #[test]
+    fn known_functions() {
+        use crate::manipulation::{Evaluate, Find};
+
+        assert_eq!(latex(r"\sin{x}").to_string(),"sin(x)");
+        assert_eq!(latex(r"2\cos(x + 1)").to_string(),"2cos(x + 1)");
+        assert_eq!(latex(r"\ln x^{2}").to_string(),"ln(x^2)");
+        assert_eq!(latex(r"\log{100}").evaluate().find().to_string(),"2");
+
+        let error = Expression::from_latex(r"\foo{x}",&Context::default()).unwrap_err();
+        assert_eq!(error.span(),0..4);
+    }
+
+    #[test]
+    fn latex_errors() {
+        let error = Expression::from_latex(r"\frac{1}{2",&Context::default()).unwrap_err();
+        assert_eq!(error.expected(),"`}`");
+        assert_eq!(error.hint(),Some("add the missing closing `}`"));
+
+        let error = Expression::from_latex(r"2 \% 3",&Context::default()).unwrap_err();
+        assert_eq!(error.span(),2..4);
+
+        let error = Expression::from_latex(r"\sqrt[0]{x}",&Context::default()).unwrap_err();
+        assert_eq!(error.span(),5..8);
+        assert_eq!(error.found(),"a degree of 0");
+
+        // Braces are only understood in LaTeX
+        assert!(Expression::try_from("x^{2}").is_err());
+    }
+
+    #[cfg(feature="equation")]
+    #[test]
+    fn latex_equation() {
+        let equation = crate::Equation::from_latex(r"\frac{x}{2} = 3",&Context::default()).unwrap();
+        assert_eq!(equation,crate::Equation::try_from("x / 2 = 3").unwrap());
+    }
+}
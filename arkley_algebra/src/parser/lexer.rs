@@ -0,0 +1,319 @@
+use std::ops::Range;
+
+use num_notation::Number;
+
+use crate::{ArithmeticOperation, ParseError};
+
+use super::parse_superscript;
+
+#[derive(Clone,PartialEq,Debug)]
+pub(super) enum TokenKind<'a> {
+    /// A number like `2` or `3.5`
+    Number(Number),
+    /// A run of letters like `x` , `xy` or `sin` , which is either a tag , a function or variables (or `π` on its own)
+    Word(&'a str),
+    Operator(ArithmeticOperation),
+    /// A superscript exponent like `²` in `x²`
+    Superscript(Number),
+    /// A root sign like `√` , `∛` or `∜` with its degree
+    Root(u32),
+    OpenParenthesis,
+    CloseParenthesis,
+    Comma,
+    /// Relations like `=` , `<` , `<=` and `≤`
+    Relation(&'a str),
+    /// `{` , only used by LaTeX
+    OpenBrace,
+    /// `}` , only used by LaTeX
+    CloseBrace,
+    /// `[` , only used by LaTeX for the degree of a root like `\sqrt[3]{x}`
+    OpenBracket,
+    /// `]` , only used by LaTeX
+    CloseBracket,
+    /// A LaTeX fraction like `\frac`
+    Fraction,
+    /// A LaTeX command like `\sin` or `\tau` , which is either a known function , a tag or a function of the context
+    Command(&'a str),
+}
+
+#[derive(Clone,PartialEq,Debug)]
+pub(super) struct Token<'a> {
+    pub(super) kind : TokenKind<'a>,
+    pub(super) span : Range<usize>,
+}
+
+impl TokenKind<'_> {
+    /// Describes the token for errors , like `` `+` ``
+    pub(super) fn describe(&self) -> String {
+        match self {
+            TokenKind::Number(number) => format!("`{number}`"),
+            TokenKind::Word(word) | TokenKind::Relation(word) => format!("`{word}`"),
+            TokenKind::Operator(operation) => format!("`{operation}`"),
+            TokenKind::Superscript(exponent) => format!("`^{exponent}`"),
+            TokenKind::Root(degree) => format!("`{}`",Self::root_symbol(*degree)),
+            TokenKind::OpenParenthesis => "`(`".to_string(),
+            TokenKind::CloseParenthesis => "`)`".to_string(),
+            TokenKind::Comma => "`,`".to_string(),
+            TokenKind::OpenBrace => "`{`".to_string(),
+            TokenKind::CloseBrace => "`}`".to_string(),
+            TokenKind::OpenBracket => "`[`".to_string(),
+            TokenKind::CloseBracket => "`]`".to_string(),
+            TokenKind::Fraction => "`\\frac`".to_string(),
+            TokenKind::Command(name) => format!("`\\{name}`"),
+        }
+    }
+}
+
+impl TokenKind<'_> {
+    const ROOTS : [(char,u32);3] = [('√',2),('∛',3),('∜',4)];
+
+    fn root_symbol(degree : u32) -> char {
+        Self::ROOTS.iter().find(|(_,d)| *d == degree).map_or('√',|(symbol,_)| *symbol)
+    }
+}
+
+/// Splits the input into tokens on demand , ignoring any whitespace between them
+#[derive(Clone)]
+pub(super) struct Lexer<'a> {
+    input : &'a str,
+    position : usize,
+    peeked : Option<Option<Token<'a>>>,
+    /// Whether LaTeX commands like `\frac` and braces are turned into tokens
+    latex : bool,
+}
+
+impl<'a> Lexer<'a> {
+    pub(super) const fn new(input : &'a str) -> Self {
+        Self { input , position : 0 , peeked : None , latex : false }
+    }
+
+    /// Creates a lexer for LaTeX input like `\frac{1}{2} \cdot x^{2}`
+    pub(super) const fn latex(input : &'a str) -> Self {
+        Self { input , position : 0 , peeked : None , latex : true }
+    }
+
+    /// Gets the input which has not been turned into tokens yet (including the peeked token)
+    pub(super) fn remaining(&self) -> &'a str {
+        match &self.peeked {
+            Some(Some(token)) => &self.input[token.span.start..],
+            _ => &self.input[self.position..]
+        }
+    }
+
+    /// Gets the byte offset of the end of the input
+    pub(super) const fn end(&self) -> usize {
+        self.input.len()
+    }
+
+    pub(super) fn peek(&mut self) -> Result<Option<&Token<'a>>,ParseError> {
+        if self.peeked.is_none() {
+            self.peeked = Some(self.lex()?);
+        }
+
+        Ok(self.peeked.as_ref().and_then(Option::as_ref))
+    }
+
+    pub(super) fn next_token(&mut self) -> Result<Option<Token<'a>>,ParseError> {
+        match self.peeked.take() {
+            Some(token) => Ok(token),
+            None => self.lex()
+        }
+    }
+
+    fn lex(&mut self) -> Result<Option<Token<'a>>,ParseError> {
+        // Loops instead of recursing as any number of skipped LaTeX commands can follow each other
+        loop {
+            let rest = &self.input[self.position..];
+            let trimmed = rest.trim_start();
+            let start = self.position + rest.len() - trimmed.len();
+
+            let Some(c) = trimmed.chars().next() else {
+                self.position = self.input.len();
+                return Ok(None);
+            };
+
+            let (kind,length) = match c {
+                '0'..='9' | '.' => {
+                    let length = trimmed.find(|c : char| !c.is_ascii_digit() && c != '.').unwrap_or(trimmed.len());
+                    let number = trimmed[..length].parse::<f64>()
+                        .map_err(|_| ParseError::new(start..start + length,"a number","an invalid number"))?;
+
+                    (TokenKind::Number(Number::Decimal(number)),length)
+                },
+                // `π` is a word on its own so that `2πr` is `2 * π * r`
+                'π' => (TokenKind::Word(&trimmed[..c.len_utf8()]),c.len_utf8()),
+                c if is_word_character(c) => {
+                    let length = trimmed.find(|c : char| !is_word_character(c)).unwrap_or(trimmed.len());
+                    (TokenKind::Word(&trimmed[..length]),length)
+                },
+                '⁰' | '¹' | '²' | '³' | '⁴' | '⁵' | '⁶' | '⁷' | '⁸' | '⁹' | '⁺' | '⁻' => {
+                    let (remaining,exponent) = parse_superscript(trimmed)
+                        .map_err(|_| ParseError::new(start..start + c.len_utf8(),"a superscript number like `²`",format!("`{c}`")))?;
+
+                    (TokenKind::Superscript(exponent),trimmed.len() - remaining.len())
+                },
+                '^' => (TokenKind::Operator(ArithmeticOperation::Pow),1),
+                '(' => (TokenKind::OpenParenthesis,1),
+                ')' => (TokenKind::CloseParenthesis,1),
+                ',' => (TokenKind::Comma,1),
+                '<' | '>' | '!' if trimmed[1..].starts_with('=') => (TokenKind::Relation(&trimmed[..2]),2),
+                '=' | '<' | '>' | '≤' | '≥' | '≠' => (TokenKind::Relation(&trimmed[..c.len_utf8()]),c.len_utf8()),
+                '\\' if self.latex => match self.command(trimmed,start)? {
+                    Some((kind,length)) => (kind,length),
+                    // Commands like `\,` or `\left` which do not produce a token on their own
+                    None => continue
+                },
+                '{' if self.latex => (TokenKind::OpenBrace,1),
+                '}' if self.latex => (TokenKind::CloseBrace,1),
+                '[' if self.latex => (TokenKind::OpenBracket,1),
+                ']' if self.latex => (TokenKind::CloseBracket,1),
+                c => match (ArithmeticOperation::try_from(c),TokenKind::ROOTS.iter().find(|(symbol,_)| *symbol == c)) {
+                    // Also handles the Unicode operators like `×` and `÷`
+                    (Ok(operation),_) => (TokenKind::Operator(operation),c.len_utf8()),
+                    (_,Some((_,degree))) => (TokenKind::Root(*degree),c.len_utf8()),
+                    _ => return Err(ParseError::new(start..start + c.len_utf8(),"a term , operator or `(`",format!("`{c}`")))
+                }
+            };
+
+            self.position = start + length;
+
+            return Ok(Some(Token { kind , span : start..self.position }));
+        }
+    }
+
+    /// Lexes a LaTeX command like `\cdot` , returning `None` (after skipping it) if it does not produce a token
+    ///
+    /// Other commands made of letters like `\sin` or `\tau` are left for the parser , as they refer to functions or tags
+    fn command(&mut self,trimmed : &'a str,start : usize) -> Result<Option<(TokenKind<'a>,usize)>,ParseError> {
+        let name_length = match trimmed[1..].find(|c : char| !c.is_ascii_alphabetic()).unwrap_or(trimmed.len() - 1) {
+            // Commands like `\,` are a single symbol
+            0 => trimmed[1..].chars().next().map_or(0,char::len_utf8),
+            length => length
+        };
+
+        let length = 1 + name_length;
+
+        let kind = match &trimmed[1..length] {
+            "cdot" | "times" | "ast" => TokenKind::Operator(ArithmeticOperation::Mal),
+            "div" => TokenKind::Operator(ArithmeticOperation::Durch),
+            "frac" | "dfrac" | "tfrac" => TokenKind::Fraction,
+            "sqrt" => TokenKind::Root(2),
+            "pi" => TokenKind::Word("π"),
+            "le" | "leq" => TokenKind::Relation("≤"),
+            "ge" | "geq" => TokenKind::Relation("≥"),
+            "ne" | "neq" => TokenKind::Relation("≠"),
+            "lt" => TokenKind::Relation("<"),
+            "gt" => TokenKind::Relation(">"),
+            "{" => TokenKind::OpenParenthesis,
+            "}" => TokenKind::CloseParenthesis,
+            "left" | "right" => return self.delimiter(trimmed,start,length),
+            "," | ";" | ":" | "!" | " " | "quad" | "qquad" => {
+                self.position = start + length;
+                return Ok(None);
+            },
+            name if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()) => TokenKind::Command(name),
+            name => return Err(
+                ParseError::new(start..start + length,"a LaTeX command like `\\frac`",format!("`\\{name}`"))
+            )
+        };
+
+        Ok(Some((kind,length)))
+    }
+
+    /// Lexes the delimiter after `\left` or `\right` like `(` or `[` , which are both used as parentheses
+    fn delimiter(&mut self,trimmed : &'a str,start : usize,length : usize) -> Result<Option<(TokenKind<'a>,usize)>,ParseError> {
+        let rest = &trimmed[length..];
+        let delimiter_start = length + rest.len() - rest.trim_start().len();
+
+        let kind = match rest.trim_start().chars().next() {
+            Some('(' | '[') => TokenKind::OpenParenthesis,
+            Some(')' | ']') => TokenKind::CloseParenthesis,
+            // `\{` and `\}` are commands on their own
+            Some('\\') => {
+                self.position = start + length;
+                return Ok(None);
+            },
+            found => return Err(ParseError::new(
+                start..start + delimiter_start + found.map_or(0,char::len_utf8),
+                "a delimiter like `(` or `[`",
+                found.map_or("end of input".to_string(),|c| format!("`{c}`"))
+            ))
+        };
+
+        Ok(Some((kind,delimiter_start + 1)))
+    }
+}
+
+fn is_word_character(c : char) -> bool {
+    (c.is_alphabetic() && c != 'π') || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(input : &str) -> Vec<TokenKind<'_>> {
+        let mut lexer = Lexer::new(input);
+        std::iter::from_fn(|| lexer.next_token().unwrap()).map(|token| token.kind).collect()
+    }
+
+    #[test]
+    fn whitespace_is_ignored() {
+        let expected = vec![
+            TokenKind::Number(Number::Decimal(2.0)),
+            TokenKind::Word("x"),
+            TokenKind::Operator(ArithmeticOperation::Plus),
+            TokenKind::Number(Number::Decimal(3.5)),
+        ];
+
+        assert_eq!(kinds("2x+3.5"),expected);
+        assert_eq!(kinds(" 2 x  +\t3.5 "),expected);
+    }
+
+    #[test]
+    fn unicode_symbols() {
+        assert_eq!(kinds("3×4÷2−1"),vec![
+            TokenKind::Number(Number::Decimal(3.0)),
+            TokenKind::Operator(ArithmeticOperation::Mal),
+            TokenKind::Number(Number::Decimal(4.0)),
+            TokenKind::Operator(ArithmeticOperation::Durch),
+            TokenKind::Number(Number::Decimal(2.0)),
+            TokenKind::Operator(ArithmeticOperation::Minus),
+            TokenKind::Number(Number::Decimal(1.0)),
+        ]);
+
+        assert_eq!(kinds("x⁻¹ ∛2πr"),vec![
+            TokenKind::Word("x"),
+            TokenKind::Superscript(Number::Decimal(-1.0)),
+            TokenKind::Root(3),
+            TokenKind::Number(Number::Decimal(2.0)),
+            TokenKind::Word("π"),
+            TokenKind::Word("r"),
+        ]);
+    }
+
+    #[test]
+    fn spans_and_invalid_characters() {
+        let mut lexer = Lexer::new("sin( x)");
+        assert_eq!(lexer.next_token().unwrap().unwrap().span,0..3);
+        assert_eq!(lexer.next_token().unwrap().unwrap().span,3..4);
+        assert_eq!(lexer.next_token().unwrap().unwrap().span,5..6);
+
+        let mut lexer = Lexer::new("2² ≤ 3");
+        lexer.next_token().unwrap();
+        assert_eq!(lexer.next_token().unwrap().unwrap().span,1..3);
+        assert_eq!(lexer.next_token().unwrap().unwrap().span,4..7);
+
+        assert_eq!(kinds("x>=1 != 2"),vec![
+            TokenKind::Word("x"),
+            TokenKind::Relation(">="),
+            TokenKind::Number(Number::Decimal(1.0)),
+            TokenKind::Relation("!="),
+            TokenKind::Number(Number::Decimal(2.0)),
+        ]);
+
+        let mut lexer = Lexer::new("2 # 3");
+        lexer.next_token().unwrap();
+        assert_eq!(lexer.next_token().unwrap_err().span(),2..3);
+    }
+}
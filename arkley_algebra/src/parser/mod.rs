@@ -2,15 +2,21 @@ mod op;
 mod term;
 mod function;
 
-mod tokens;
+mod lexer;
 mod expression;
 mod context;
+mod error;
+mod statement;
+mod latex;
 
 pub use term::*;
 pub use expression::*;
 pub use op::*;
 pub use context::*;
+pub use error::*;
+pub use statement::*;
 pub use function::*;
+pub use latex::*;
 
 
 #[cfg(feature="equation")]
@@ -1,6 +1,6 @@
 use nom::{
     IResult, 
-    character::complete::{char, multispace0}, 
+    character::complete::{char, multispace0, one_of}, 
     sequence::{preceded, pair}, 
     multi::many1_count, 
     combinator::{map, opt}, 
@@ -9,6 +9,13 @@ use nom::{
 
 use crate::ArithmeticOperation;
 
+/// The characters used for subtraction , including the Unicode minus sign `−`
+const MINUS : &str = "-−";
+/// The characters used for multiplication , like `×` and the dot operator `·`
+const MAL : &str = "*×·⋅∗";
+/// The characters used for division , like `÷`
+const DURCH : &str = "/÷∕";
+
 
 /// Parses an operator from the input string.
 ///
@@ -16,6 +23,8 @@ use crate::ArithmeticOperation;
 /// operators such as '*', '/', as well as special cases where there are sequences of
 /// '+' and '-' characters with optional whitespace between them.
 ///
+/// The common Unicode operators like '×' , '·' , '÷' and the minus sign '−' are accepted as well.
+///
 /// # Arguments
 ///
 /// * `input`: A reference to the input string to be parsed.
@@ -30,8 +39,8 @@ pub fn parse_operator(input : &str) -> IResult<&str,ArithmeticOperation> {
         multispace0, 
         alt((
             parse_add_sub,
-            map(char('*'),|_| ArithmeticOperation::Mal),
-            map(char('/'),|_| ArithmeticOperation::Durch),
+            map(one_of(MAL),|_| ArithmeticOperation::Mal),
+            map(one_of(DURCH),|_| ArithmeticOperation::Durch),
         ))
     )(input)
 }
@@ -52,7 +61,7 @@ fn parse_many_add(input : &str) -> IResult<&str,usize> {
 }
 
 fn parse_many_sub(input : &str) -> IResult<&str,usize> {
-    many1_count(char('-'))(input)
+    many1_count(one_of(MINUS))(input)
 }
 
 fn parse_add_with_opt_sub(input : &str) -> IResult<&str,ArithmeticOperation> {
@@ -123,9 +132,9 @@ impl TryFrom<char> for ArithmeticOperation {
         use ArithmeticOperation::*;
         match value {
             '+' => Ok(Plus),
-            '-' => Ok(Minus),
-            '*' => Ok(Mal),
-            '/' => Ok(Durch),
+            c if MINUS.contains(c) => Ok(Minus),
+            c if MAL.contains(c) => Ok(Mal),
+            c if DURCH.contains(c) => Ok(Durch),
             _ => Err(())
         }
     }
@@ -179,6 +188,15 @@ mod parse_tests {
         assert_eq!(parse_operator("-+"), Ok(("",  ArithmeticOperation::try_from('-').unwrap())));
     }
 
+    #[test]
+    fn test_unicode_operator() {
+        assert_eq!(parse_operator("×"), Ok(("", ArithmeticOperation::Mal)));
+        assert_eq!(parse_operator(" ·"), Ok(("", ArithmeticOperation::Mal)));
+        assert_eq!(parse_operator("÷"), Ok(("", ArithmeticOperation::Durch)));
+        assert_eq!(parse_operator("−"), Ok(("", ArithmeticOperation::Minus)));
+        assert_eq!(parse_operator("+−"), Ok(("", ArithmeticOperation::Minus)));
+    }
+
     #[test]
     fn test_invalid_operator() {
         // Add more test cases for invalid input
@@ -0,0 +1,218 @@
+use crate::{Expression, Function, Context, ContextError, ParseError, ParseResult};
+
+use super::{expression::ExpressionParser, lexer::{Token, TokenKind}};
+
+/// Represents a single statement of a script , which is either stored in a [Context] or returned to the caller.
+#[derive(Clone)]
+pub enum Statement {
+    /// An assignment to a variable like `x = 4y + 5`
+    Assignment(char,Expression),
+    /// A function definition like `f(x) = x^2`
+    Definition(Function),
+    /// An equation or inequality like `2x + 1 = 5`
+    #[cfg(feature="equation")]
+    Equation(crate::Equation),
+    /// A chain of relations like `-1 < x < 1`
+    #[cfg(feature="equation")]
+    ChainedRelation(crate::ChainedRelation),
+    /// An expression on its own like `2x + 1`
+    Expression(Expression),
+}
+
+impl std::fmt::Display for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Statement::Assignment(variable,value) => write!(f,"{variable} = {value}"),
+            Statement::Definition(function) => match function.expression() {
+                Some(expression) => write!(f,"{function} = {expression}"),
+                None => write!(f,"{function}")
+            },
+            #[cfg(feature="equation")]
+            Statement::Equation(equation) => write!(f,"{equation}"),
+            #[cfg(feature="equation")]
+            Statement::ChainedRelation(chain) => write!(f,"{chain}"),
+            Statement::Expression(expression) => write!(f,"{expression}"),
+        }
+    }
+}
+
+impl std::fmt::Debug for Statement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+/// Parses a statement from the input string.
+///
+/// A single variable followed by `=` (like `x = 3`) is an assignment , a name followed by single letter
+/// parameters and `=` (like `f(x) = x^2`) is a function definition and anything else is an equation or expression.
+pub fn parse_statement<'a : 'b,'b>(context : &'b Context) -> impl FnMut(&'a str) -> ParseResult<'a,Statement> + 'b {
+    move |input| {
+        let mut parser = ExpressionParser::new(input,context);
+
+        match parser.statement() {
+            Ok(statement) => Ok((parser.lexer.remaining(),statement)),
+            Err(error) => Err(parser.into_nom_error(input,error))
+        }
+    }
+}
+
+impl<'a,'b> TryFrom<(&'a str,&'b Context)> for Statement {
+    type Error = ParseError;
+    fn try_from((input,context): (&'a str,&'b Context)) -> Result<Self, Self::Error> {
+        let mut parser = ExpressionParser::new(input,context);
+        let statement = parser.statement()?;
+        parser.expect_end()?;
+
+        Ok(statement)
+    }
+}
+
+impl<'a> ExpressionParser<'a,'_> {
+    pub(super) fn statement(&mut self) -> Result<Statement,ParseError> {
+        if self.is_assignment() {
+            let variable = match self.lexer.next_token()? {
+                Some(Token { kind : TokenKind::Word(word), .. }) => word.chars().next().unwrap_or_default(),
+                Some(token) => return Err(Self::unexpected(token,"a variable")),
+                None => return Err(self.end_of_input("a variable"))
+            };
+
+            self.expect(TokenKind::Relation("="),"`=`")?;
+
+            return Ok(Statement::Assignment(variable,self.expression()?));
+        }
+
+        if self.is_definition() {
+            return self.definition().map(|(_,function)| Statement::Definition(function));
+        }
+
+        let expression = self.expression()?;
+
+        #[cfg(feature="equation")]
+        if let Some(Token { kind : TokenKind::Relation(_), .. }) = self.lexer.peek()? {
+            return self.chained_relation_with_first(expression).map(|chain| match chain.into_equation() {
+                Ok(equation) => Statement::Equation(equation),
+                Err(chain) => Statement::ChainedRelation(chain)
+            });
+        }
+
+        Ok(Statement::Expression(expression))
+    }
+
+    /// Checks if the upcoming tokens look like `x =`
+    fn is_assignment(&self) -> bool {
+        let mut lexer = self.lexer.clone();
+        let mut next = || lexer.next_token().ok().flatten().map(|token| token.kind);
+
+        match (next(),next()) {
+            (Some(TokenKind::Word(word)),Some(TokenKind::Relation("="))) => word.len() == 1 && word.chars().all(|c| c.is_ascii_lowercase()),
+            _ => false
+        }
+    }
+}
+
+impl Context {
+    /// Stores the statement in the innermost scope of the context if it is an assignment or function definition
+    ///
+    /// Returns [ContextError::Cycle] (and keeps the previous value) if the assignment makes a value depend on itself
+    pub fn apply(&mut self,statement : &Statement) -> Result<(),ContextError> {
+        match statement {
+            Statement::Assignment(variable,value) => {
+                let previous = self.values_mut().insert(*variable,value.clone());
+
+                if let Err(error) = self.resolve((*variable).into()) {
+                    match previous {
+                        Some(previous) => self.values_mut().insert(*variable,previous),
+                        None => self.values_mut().remove(variable)
+                    };
+
+                    return Err(error);
+                }
+            },
+            #[cfg(feature="function")]
+            Statement::Definition(function) => {
+                self.functions_mut().insert(function.name.clone(),function.clone());
+            },
+            _ => ()
+        }
+
+        Ok(())
+    }
+
+    /// Parses and applies every statement of the script , which are separated by new lines or `;`
+    ///
+    /// Statements can use the variables and functions defined by the statements before them. All parsed statements
+    /// are returned so that equations and expressions can be evaluated by the caller.
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{Context, Statement};
+    ///
+    /// let mut context = Context::default();
+    /// let statements = context.run_script("a = 2\nf(x) = x^2 + a ; f(3)").unwrap();
+    ///
+    /// assert_eq!(statements.len(),3);
+    /// assert!(context.values().contains_key(&'a'));
+    /// assert!(matches!(statements[2],Statement::Expression(_)));
+    /// ```
+    pub fn run_script(&mut self,script : &str) -> Result<Vec<Statement>,ContextError> {
+        let mut statements = Vec::new();
+        let mut offset = 0;
+
+        for line in script.split(['\n',';']) {
+            if !line.trim().is_empty() {
+                let statement = Statement::try_from((line,&*self)).map_err(|error| error.shifted(offset))?;
+                self.apply(&statement)?;
+                statements.push(statement);
+            }
+
+            offset += line.len() + 1;
+        }
+
+        Ok(statements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statement(input : &str) -> Statement {
+        Statement::try_from((input,&Context::default())).unwrap()
+    }
+
+    #[test]
+    fn distinguish_statements() {
+        assert!(matches!(statement("x = 4y + 5"),Statement::Assignment('x',_)));
+        assert!(matches!(statement("f(x, y) = x^2 + y"),Statement::Definition(ref function) if function.name() == "f"));
+        assert!(matches!(statement("2x + 1"),Statement::Expression(_)));
+
+        #[cfg(feature="equation")]
+        assert!(matches!(statement("2x + 1 = 5"),Statement::Equation(_)));
+        #[cfg(feature="equation")]
+        assert!(matches!(statement("-1 < x <= 1"),Statement::ChainedRelation(_)));
+
+        assert_eq!(statement("f(x, y) = x^2 + y").to_string(),"f(x, y) = x^2 + y");
+    }
+
+    #[test]
+    fn run_script() {
+        let mut context = Context::default();
+        let statements = context.run_script("a = 2\n\nf(x) = x^2 + a\ny = f(3) ; 2y").unwrap();
+
+        assert_eq!(statements.len(),4);
+        assert_eq!(context.values()[&'a'].to_string(),"2");
+        assert_eq!(context.values()[&'y'].to_string(),"f(3)");
+        assert!(context.functions().contains_key("f"));
+    }
+
+    #[test]
+    fn script_errors_point_into_the_script() {
+        let mut context = Context::default();
+        let error = context.run_script("a = 2\nb = (3").unwrap_err();
+
+        assert!(matches!(error,ContextError::Parse(ref error) if error.span() == (12..12)));
+        // The statements before the error are still applied
+        assert!(context.values().contains_key(&'a'));
+    }
+}
@@ -1,8 +1,8 @@
 use nom::{
     IResult, 
     sequence::{preceded, delimited, pair}, 
-    multi::many1, 
-    combinator::{opt, all_consuming},
+    multi::{many1, fold_many1}, 
+    combinator::{opt, all_consuming, map_opt},
     character::complete::{char, satisfy}, branch::alt,
 };
 
@@ -10,12 +10,14 @@ use num_notation::{Number, parse_number};
 
 use super::parse_add_sub;
 
-use crate::{Term, Variables, ArithmeticOperation};
+use crate::{Term, Variables, ArithmeticOperation, ParseError};
 
 impl<'a> TryFrom<&'a str> for Term {
-    type Error = nom::Err<nom::error::Error<&'a str>>;
+    type Error = ParseError;
     fn try_from(input: &'a str) -> Result<Self, Self::Error> {
-        all_consuming(parse_term)(input).map(|(_,value)| value)
+        all_consuming(parse_term)(input)
+            .map(|(_,value)| value)
+            .map_err(|error| ParseError::from_nom(input,error,"a term"))
     }
 }
 
@@ -31,11 +33,20 @@ pub fn parse_term(input : &str) -> IResult<&str,Term> {
 }
 
 fn parse_coefficient_with_opt_variables(input : &str) -> IResult<&str,Term> {
-    let (input,(coefficient,variables)) = pair(
-        parse_number,
-        opt(parse_variables)
+    // `parse_number` only understands the ASCII `-` , so the Unicode minus sign is handled here
+    let (input,(minus,(coefficient,variables))) = pair(
+        opt(char('−')),
+        pair(
+            parse_number,
+            opt(parse_variables)
+        )
     )(input)?;
-    
+
+    let coefficient = match minus {
+        Some(_) => -coefficient,
+        None => coefficient
+    };
+
     let term = Term::new_with_variable(coefficient, variables.unwrap_or_default());
 
     Ok((input,term))
@@ -48,7 +59,7 @@ fn parse_variables_with_opt_sign(input : &str) -> IResult<&str,Term> {
     )(input)?;
 
     let term = match sign {
-        Some(sign) if sign == ArithmeticOperation::Minus => Term::new_with_variable((-1f64).into(), variables),
+        Some(ArithmeticOperation::Minus) => Term::new_with_variable((-1f64).into(), variables),
         _ => variables.into(),
     };
 
@@ -57,7 +68,7 @@ fn parse_variables_with_opt_sign(input : &str) -> IResult<&str,Term> {
 
 // Used by super::function
 pub(super) fn satisfies_variable_name(input : &str) -> IResult<&str,char> {
-    satisfy(|c| c >= 'a' && c <= 'z' && (c != 'e' || c!= 'E') )(input)
+    satisfy(|c| c.is_ascii_lowercase() && (c != 'e' || c!= 'E') )(input)
 }
 
 fn parse_variables(input : &str) -> IResult<&str,Variables> {
@@ -76,13 +87,45 @@ fn parse_variables(input : &str) -> IResult<&str,Variables> {
 }
 
 fn parse_exponent(input : &str) -> IResult<&str,Number> {
-    preceded(char('^'),
-        delimited(
-            opt(char('(')), 
-            parse_number, 
-            opt(char(')'))
-        )
-    )(input)
+    alt((
+        preceded(char('^'),
+            delimited(
+                opt(char('(')), 
+                parse_number, 
+                opt(char(')'))
+            )
+        ),
+        parse_superscript
+    ))(input)
+}
+
+/// Parses a superscript exponent like `²` or `⁻¹` , which is used like `^2` and `^-1`
+pub(super) fn parse_superscript(input : &str) -> IResult<&str,Number> {
+    let (input,sign) = opt(satisfy(|c| c == '⁻' || c == '⁺'))(input)?;
+    let (input,digits) = fold_many1(
+        map_opt(satisfy(|_| true),superscript_digit),
+        || 0u32,
+        |number,digit| number.saturating_mul(10).saturating_add(digit)
+    )(input)?;
+
+    let exponent = match sign {
+        Some('⁻') => -f64::from(digits),
+        _ => f64::from(digits)
+    };
+
+    Ok((input,Number::Decimal(exponent)))
+}
+
+/// Converts a superscript character like `²` into the digit it represents
+fn superscript_digit(c : char) -> Option<u32> {
+    match c {
+        '⁰' => Some(0),
+        '¹' => Some(1),
+        '²' => Some(2),
+        '³' => Some(3),
+        '⁴'..='⁹' => Some(c as u32 - '⁴' as u32 + 4),
+        _ => None
+    }
 }
 
 #[cfg(test)]
@@ -150,6 +193,13 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_term_unicode() {
+        let expected_variables = Variables::from([('x',3.0.into())]);
+        assert_eq!(parse_term("−5x³"), Ok(("", Term::new_with_variable(Number::Decimal(-5.0), expected_variables.clone()))));
+        assert_eq!(parse_term("−x³"), Ok(("", Term::new_with_variable(Number::Decimal(-1.0), expected_variables))));
+    }
+
     #[test]
     fn test_parse_term_empty_input() {
         let input = "";
@@ -177,6 +227,15 @@ mod tests {
         assert_eq!(result, Ok(("", expected_variables)));
     }
 
+    #[test]
+    fn test_parse_variables_superscript() {
+        let expected_variables = Variables::from([('x',2.0.into()),('y',(-1.0).into())]);
+        assert_eq!(parse_variables("x²y⁻¹"), Ok(("", expected_variables)));
+
+        assert_eq!(parse_superscript("¹⁰x"), Ok(("x", Number::Decimal(10.0))));
+        assert!(parse_superscript("2").is_err());
+    }
+
     #[test]
     fn test_parse_variables_invalid_input() {
         let input = "x^2y3z"; // Invalid input missing '^' between 'y' and '3'
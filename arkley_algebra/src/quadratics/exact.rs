@@ -0,0 +1,153 @@
+use num_notation::{Num, Zero, Signed};
+
+use crate::manipulation::Find;
+use crate::ArkleyError;
+use crate::radicals::{Surd, NotRationalError};
+
+use super::*;
+
+/// A utility struct for finding the exact roots of a quadratic equation as surds so `(-3 ± √17)/4`
+/// instead of `0.28077640640441515` and `-1.7807764064044151`.
+///
+/// It is created by [Roots::exact] method.
+#[derive(Debug,Clone)]
+pub struct ExactRoots<T>(pub(super) T);
+
+impl<T> Roots<IntegerQuadratic<T>> where T : Num + Clone {
+    /// Finds the roots using surds instead of floating point numbers , see [ExactRoots]
+    pub fn exact(self) -> ExactRoots<IntegerQuadratic<T>> {
+        ExactRoots(self.0.0)
+    }
+}
+
+impl<T> Find for ExactRoots<IntegerQuadratic<T>> where T : Num + Clone + TryInto<Surd> {
+    /// [ArkleyError::UndefinedConcavity] is returned if `a` is `0` , [ArkleyError::NotRational] if any of the coefficients
+    /// can not be written as an exact rational number (eg `NaN`) and [ArkleyError::Overflow] if the coefficients are too large
+    type Output = Result<Nature<Surd>,ArkleyError>;
+
+    fn find(self) -> Self::Output {
+        let surd = |value : T| value.try_into().map_err(|_| NotRationalError);
+        let (a,b,c) = (surd(self.0.a)?,surd(self.0.b)?,surd(self.0.c)?);
+
+        if a.is_zero() {
+            return Err(ArkleyError::UndefinedConcavity);
+        }
+
+        // D = b^2 - 4ac
+        let four_ac = Surd::from(4).checked_mul(a.clone()).and_then(|four_a| four_a.checked_mul(c));
+        let discriminant = b.clone().checked_mul(b.clone()).zip(four_ac)
+            .and_then(|(b_squared,four_ac)| b_squared.checked_sub(four_ac))
+            .ok_or(ArkleyError::Overflow)?;
+        let value = discriminant.as_rational().ok_or(NotRationalError)?;
+
+        if value.is_negative() {
+            return Ok(Nature::NoRealRoots);
+        }
+
+        // a is not 0 so dividing by 2a can only fail by overflowing
+        let two_a = Surd::from(2).checked_mul(a).ok_or(ArkleyError::Overflow)?;
+
+        if value.is_zero() {
+            return (-b).checked_div(two_a).map(Nature::SingleRoot).ok_or(ArkleyError::Overflow);
+        }
+
+        let sqrt = Surd::sqrt_of(value).ok_or(ArkleyError::Overflow)?;
+
+        let root = |sqrt : Surd| sqrt.checked_sub(b.clone())?.checked_div(two_a.clone());
+        let r1 = root(sqrt.clone()).ok_or(ArkleyError::Overflow)?;
+        let r2 = root(-sqrt).ok_or(ArkleyError::Overflow)?;
+
+        Ok(Nature::DistinctRealRoots(r1,r2))
+    }
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    Describe, Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+impl<T> Describe for ExactRoots<IntegerQuadratic<T>> where T : Num + Clone + From<u8> + std::fmt::Display + TryInto<Surd> {
+    fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        use std::collections::HashMap;
+
+        let discriminant_description = Discriminant(self.0.clone()).describe(resources,lang)?;
+
+        let description = match self.find().ok()? {
+            Nature::DistinctRealRoots(r1, r2) => {
+                let args = HashMap::from([
+                    ("r1",r1.to_string().into()),
+                    ("r2",r2.to_string().into())
+                ]);
+                resources.lookup_single_language::<&str>(lang, "roots-integerquadratic.two",Some(&args))
+            },
+            Nature::SingleRoot(root) => {
+                let args = HashMap::from([("root",root.to_string().into())]);
+                resources.lookup_single_language::<&str>(lang, "roots-integerquadratic.one",Some(&args))
+            },
+            Nature::NoRealRoots => resources.lookup_single_language::<&str>(lang, "roots-integerquadratic.zero",None),
+        }?;
+
+        let mut vec = Vec::new();
+
+        vec.extend(discriminant_description);
+        vec.push(description);
+
+        vec.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roots(a : i32,b : i32,c : i32) -> Nature<Surd> {
+        IntegerQuadratic::new(a,b,c).roots().exact().find().unwrap()
+    }
+
+    #[test]
+    fn irrational_roots() {
+        match roots(2,3,-1) {
+            Nature::DistinctRealRoots(r1,r2) => {
+                assert_eq!(r1.to_string(),"(-3 + √17)/4");
+                assert_eq!(r2.to_string(),"(-3 - √17)/4");
+            },
+            nature => panic!("expected two roots but got {nature:?}")
+        }
+    }
+
+    #[test]
+    fn rational_roots() {
+        match roots(1,-3,2) {
+            Nature::DistinctRealRoots(r1,r2) => {
+                assert_eq!(r1.to_string(),"2");
+                assert_eq!(r2.to_string(),"1");
+            },
+            nature => panic!("expected two roots but got {nature:?}")
+        }
+
+        assert!(matches!(roots(1,2,1),Nature::SingleRoot(root) if root.to_string() == "-1"));
+        assert!(matches!(roots(1,0,1),Nature::NoRealRoots));
+    }
+
+    #[test]
+    fn fractional_coefficients() {
+        let nature = IntegerQuadratic::new(0.5,0.0,-1.0).roots().exact().find().unwrap();
+        assert!(matches!(nature,Nature::DistinctRealRoots(r1,_) if r1.to_string() == "√2"));
+    }
+
+    #[test]
+    fn not_a_quadratic() {
+        let find = |a,b,c| IntegerQuadratic::new(a,b,c).roots().exact().find();
+        assert!(matches!(find(0,2,-4),Err(ArkleyError::UndefinedConcavity)));
+        assert!(matches!(find(0,0,0),Err(ArkleyError::UndefinedConcavity)));
+    }
+
+    #[test]
+    fn overflowing_coefficients() {
+        let find = |b| IntegerQuadratic::new(1,b,1).roots().exact().find();
+        assert!(matches!(find(5_000_000_000i64),Err(ArkleyError::Overflow)));
+        assert!(matches!(find(i64::MAX),Err(ArkleyError::Overflow)));
+    }
+}
@@ -1,6 +1,6 @@
 use num_notation::{Num, Signed, Number};
 
-use crate::Term;
+use crate::{Term, ArkleyError};
 
 use super::*;
 
@@ -62,17 +62,17 @@ impl<T> std::fmt::Display for IntegerQuadratic<T> where T : std::fmt::Display +
 }
 
 impl<T> TryFrom<Term> for IntegerQuadratic<T> where T: Num + Clone + From<u8> + From<Number> {
-    type Error = QuadraticError;
+    type Error = ArkleyError;
+
+    /// Converts a term like `3x^2` into `3x^2 + 0x + 0` , keeping its coefficient as `a`
     fn try_from(value: Term) -> Result<Self, Self::Error> {
-        let mut vec : Vec<_>  = value.variables.into_iter()
-            .filter(|(_,value)| value == &2)
-            .map(|(_,value)| value )
-            .collect();
-
-        match vec.len() {
-            0 => Err(QuadraticError::UndefinedConcavity),
-            1 => Ok(IntegerQuadratic::new(vec.pop().unwrap().into(),T::zero(),T::zero())),
-            _ => Err(QuadraticError::MultipleVariablesToThePowerOf2)
+        let squared = value.variables.values().filter(|exponent| *exponent == &2).count();
+
+        match (squared,value.variables.len()) {
+            (0,_) => Err(ArkleyError::UndefinedConcavity),
+            (1,1) => Ok(IntegerQuadratic::new(value.coefficient.into(),T::zero(),T::zero())),
+            (1,_) => Err(ArkleyError::InvalidQuadratic),
+            _ => Err(ArkleyError::MultipleVariablesToThePowerOf2)
         }
     }   
 }
@@ -101,4 +101,18 @@ impl<T> Quadratic<Self> for IntegerQuadratic<T> where T: Num + Clone + From<u8>
     fn roots(self) -> Roots<Self> {
         Roots(self.discriminant())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_from_term_keeps_coefficient() {
+        let quadratic = IntegerQuadratic::<f64>::try_from(Term::try_from("3x^2").unwrap()).unwrap();
+        assert_eq!(quadratic.a,3.0);
+
+        assert!(matches!(IntegerQuadratic::<f64>::try_from(Term::try_from("3x").unwrap()),Err(ArkleyError::UndefinedConcavity)));
+        assert!(matches!(IntegerQuadratic::<f64>::try_from(Term::try_from("3x^2y").unwrap()),Err(ArkleyError::InvalidQuadratic)));
+    }
+}
@@ -1,19 +1,20 @@
 mod interger;
-
-mod error;
+mod symbolic;
 
 mod discriminant;
 mod roots;
+mod exact;
 mod sum;
 mod product;
 mod axis_symmetry;
 mod concavity;
 
 pub use interger::*;
-pub use error::*;
+pub use symbolic::*;
 
 pub use discriminant::*;
 pub use roots::*;
+pub use exact::*;
 pub use sum::*;
 pub use product::*;
 pub use axis_symmetry::*;
@@ -0,0 +1,344 @@
+use num_notation::{Number, Pow};
+
+use crate::{Expression, Term, ArkleyError};
+use crate::manipulation::{Evaluate, Find, number_to_f64, tidy};
+
+use super::*;
+
+/// Represents a quadratic equation `a*x^2 + b*x + c = 0` whose coefficients are expressions , so they can contain
+/// parameters like `k` in `x^2 + kx + 1 = 0`.
+///
+/// It is usually created from an [Expression] (which is treated as `expression = 0`) or an equation by collecting
+/// the terms of the given variable , see [SymbolicQuadratic::solve] for an example.
+#[derive(Clone)]
+pub struct SymbolicQuadratic {
+    pub(super) a: Expression,
+    pub(super) b: Expression,
+    pub(super) c: Expression,
+    pub(super) variable: char,
+}
+
+impl SymbolicQuadratic {
+    /// Creates a new `SymbolicQuadratic` for `a*variable^2 + b*variable + c = 0`
+    pub const fn new(a: Expression,b: Expression,c: Expression,variable: char) -> Self {
+        Self { a, b, c, variable }
+    }
+
+    /// Gets the coefficient of the quadratic term
+    pub fn a(&self) -> &Expression {
+        &self.a
+    }
+
+    /// Gets the coefficient of the linear term
+    pub fn b(&self) -> &Expression {
+        &self.b
+    }
+
+    /// Gets the constant term
+    pub fn c(&self) -> &Expression {
+        &self.c
+    }
+
+    /// Gets the variable the quadratic is in
+    pub const fn variable(&self) -> char {
+        self.variable
+    }
+
+    /// Finds the roots of the quadratic , this is the same as calling `roots().find()`
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{Expression, quadratics::{SymbolicQuadratic, Nature}};
+    ///
+    /// let quadratic = SymbolicQuadratic::try_from((&Expression::try_from("x^2 - 3x + 2").unwrap(),'x')).unwrap();
+    ///
+    /// match quadratic.solve() {
+    ///     Nature::DistinctRealRoots(r1,r2) => assert_eq!((r1.to_string(),r2.to_string()),("2".to_string(),"1".to_string())),
+    ///     nature => panic!("expected two roots but got {nature:?}")
+    /// }
+    /// ```
+    pub fn solve(self) -> Nature<Expression> {
+        self.roots().find()
+    }
+
+    /// Checks if all coefficients are numbers , so the quadratic can be converted into an [IntegerQuadratic]
+    fn constant_coefficients(&self) -> Option<(f64,f64,f64)> {
+        let constant = |expression : &Expression| match expression {
+            Expression::Term(term) if term.variables.is_empty() => Some(number_to_f64(&term.coefficient)),
+            _ => None
+        };
+
+        Some((constant(&self.a)?,constant(&self.b)?,constant(&self.c)?))
+    }
+}
+
+impl std::fmt::Display for SymbolicQuadratic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let x = Expression::from(self.variable);
+        let x_squared = x.clone().pow(Number::Decimal(2.0));
+
+        // Joined without combining so the terms stay in descending order
+        let expression = [self.a.clone() * x_squared,self.b.clone() * x,self.c.clone()]
+            .into_iter()
+            .filter(|expression| !expression.is_zero())
+            .reduce(Expression::new_plus)
+            .unwrap_or_else(|| 0.into());
+
+        write!(f,"{expression} = 0")
+    }
+}
+
+impl std::fmt::Debug for SymbolicQuadratic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+impl TryFrom<(&Expression,char)> for SymbolicQuadratic {
+    type Error = ArkleyError;
+
+    /// Collects `expression = 0` into `ax^2 + bx + c = 0` for the given variable
+    fn try_from((expression,variable): (&Expression,char)) -> Result<Self, Self::Error> {
+        let mut coefficients = expression.coefficients_in(&variable).ok_or(ArkleyError::InvalidQuadratic)?;
+
+        match coefficients.len() {
+            3 => {
+                let a = coefficients.pop().unwrap_or_else(|| 0.into());
+                let b = coefficients.pop().unwrap_or_else(|| 0.into());
+                let c = coefficients.pop().unwrap_or_else(|| 0.into());
+                Ok(Self::new(a,b,c,variable))
+            },
+            0..=2 => Err(ArkleyError::UndefinedConcavity),
+            _ => Err(ArkleyError::InvalidQuadratic)
+        }
+    }
+}
+
+#[cfg(feature="equation")]
+impl TryFrom<(&crate::Equation,char)> for SymbolicQuadratic {
+    type Error = ArkleyError;
+
+    /// Moves everything to one side and collects `ax^2 + bx + c = 0` for the given variable
+    ///
+    /// Only equations using `=` are accepted
+    fn try_from((equation,variable): (&crate::Equation,char)) -> Result<Self, Self::Error> {
+        if equation.relation != crate::RelationalOperator::Equal {
+            return Err(ArkleyError::InvalidQuadratic);
+        }
+
+        let expression = Expression::new_minus(equation.left.clone(),equation.right.clone());
+        Self::try_from((&expression,variable))
+    }
+}
+
+impl TryFrom<SymbolicQuadratic> for IntegerQuadratic<f64> {
+    type Error = ArkleyError;
+
+    /// Fails with `ArkleyError::InvalidQuadratic` if any coefficient still contains parameters
+    fn try_from(value: SymbolicQuadratic) -> Result<Self, Self::Error> {
+        let (a,b,c) = value.constant_coefficients().ok_or(ArkleyError::InvalidQuadratic)?;
+        Ok(IntegerQuadratic::new(a,b,c))
+    }
+}
+
+#[cfg(feature="equation")]
+impl TryFrom<(&crate::Equation,char)> for IntegerQuadratic<f64> {
+    type Error = ArkleyError;
+
+    fn try_from(value: (&crate::Equation,char)) -> Result<Self, Self::Error> {
+        SymbolicQuadratic::try_from(value)?.try_into()
+    }
+}
+
+impl TryFrom<(&Expression,char)> for IntegerQuadratic<f64> {
+    type Error = ArkleyError;
+
+    fn try_from(value: (&Expression,char)) -> Result<Self, Self::Error> {
+        SymbolicQuadratic::try_from(value)?.try_into()
+    }
+}
+
+impl Quadratic<Self> for SymbolicQuadratic {
+    fn discriminant(self) -> Discriminant<Self> {
+        Discriminant(self)
+    }
+
+    fn sum_of_roots(self) -> SumOfRoots<Self> {
+        SumOfRoots(self)
+    }
+
+    fn product_of_roots(self) -> ProductOfRoots<Self> {
+        ProductOfRoots(self)
+    }
+
+    fn axis_of_symmetry(self) -> AxisOfSymmetry<Self> {
+        AxisOfSymmetry(self)
+    }
+
+    fn concavity(self) -> Concavity<Self> {
+        Concavity(self)
+    }
+
+    fn roots(self) -> Roots<Self> {
+        Roots(self.discriminant())
+    }
+}
+
+impl Find for Discriminant<SymbolicQuadratic> {
+    type Output = Expression;
+
+    // D = b^2 - 4ac
+    fn find(self) -> Expression {
+        let SymbolicQuadratic { a, b, c, .. } = self.0;
+        ((b.clone() * b) - (Expression::from(4) * a * c)).evaluate().find()
+    }
+}
+
+impl Find for Roots<SymbolicQuadratic> {
+    type Output = Nature<Expression>;
+
+    /// If all coefficients are numbers the roots are found like for [IntegerQuadratic] , otherwise the sign of the discriminant
+    /// is not known so both roots `(-b ± √D) / 2a` are returned , which are only real if `D >= 0`
+    fn find(self) -> Nature<Expression> {
+        let quadratic = self.0.0;
+
+        if let Ok(integer) = IntegerQuadratic::<f64>::try_from(quadratic.clone()) {
+            let into_expression = |root : f64| Expression::from(Term::new(Number::Decimal(tidy(root))));
+
+            return match integer.roots().find() {
+                Nature::DistinctRealRoots(r1,r2) => Nature::DistinctRealRoots(into_expression(r1),into_expression(r2)),
+                Nature::SingleRoot(root) => Nature::SingleRoot(into_expression(root)),
+                Nature::NoRealRoots => Nature::NoRealRoots
+            };
+        }
+
+        let discriminant = quadratic.clone().discriminant().find();
+        let SymbolicQuadratic { a, b, .. } = quadratic;
+
+        let two_a = Expression::from(2) * a;
+
+        if discriminant.is_zero() {
+            return Nature::SingleRoot((-b / two_a).evaluate().find());
+        }
+
+        let sqrt = discriminant.pow(Number::Decimal(0.5));
+
+        let r1 = (-b.clone() + sqrt.clone()) / two_a.clone();
+        let r2 = (-b - sqrt) / two_a;
+
+        Nature::DistinctRealRoots(r1.evaluate().find(),r2.evaluate().find())
+    }
+}
+
+impl Find for SumOfRoots<SymbolicQuadratic> {
+    type Output = Expression;
+
+    // -b/a
+    fn find(self) -> Expression {
+        (-self.0.b / self.0.a).evaluate().find()
+    }
+}
+
+impl Find for ProductOfRoots<SymbolicQuadratic> {
+    type Output = Expression;
+
+    // c/a
+    fn find(self) -> Expression {
+        (self.0.c / self.0.a).evaluate().find()
+    }
+}
+
+impl Find for AxisOfSymmetry<SymbolicQuadratic> {
+    type Output = Expression;
+
+    // x = -b / 2a
+    fn find(self) -> Expression {
+        (-self.0.b / (Expression::from(2) * self.0.a)).evaluate().find()
+    }
+}
+
+#[cfg(feature="equation")]
+impl crate::Equation {
+    /// Solves a quadratic equation for `variable` by moving everything to one side and collecting it into `ax^2 + bx + c = 0` ,
+    /// see [SymbolicQuadratic]
+    ///
+    /// Other variables are treated as parameters , so `x^2 = k` is solved into `±√k`.
+    ///
+    /// # Example
+    /// ```
+    /// use arkley_algebra::{Equation, quadratics::Nature};
+    ///
+    /// let nature = Equation::try_from("x^2 + 1 = 3x - 1").unwrap().solve_quadratic('x').unwrap();
+    /// assert!(matches!(nature,Nature::DistinctRealRoots(r1,r2) if r1.to_string() == "2" && r2.to_string() == "1"));
+    /// ```
+    pub fn solve_quadratic(&self,variable : char) -> Result<Nature<Expression>,ArkleyError> {
+        SymbolicQuadratic::try_from((self,variable)).map(SymbolicQuadratic::solve)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn quadratic(input : &str) -> SymbolicQuadratic {
+        SymbolicQuadratic::try_from((&Expression::try_from(input).unwrap(),'x')).unwrap()
+    }
+
+    fn roots(nature : Nature<Expression>) -> Vec<String> {
+        match nature {
+            Nature::DistinctRealRoots(r1,r2) => vec![r1.to_string(),r2.to_string()],
+            Nature::SingleRoot(root) => vec![root.to_string()],
+            Nature::NoRealRoots => vec![],
+        }
+    }
+
+    #[test]
+    fn collect_coefficients() {
+        let quadratic = quadratic("2x^2 + 3x - x^2 + 5 - x");
+        assert_eq!(quadratic.to_string(),"x^2 + 2x + 5 = 0");
+
+        let quadratic = self::quadratic("(x + 1)(x - 2)");
+        assert_eq!((quadratic.a().to_string(),quadratic.b().to_string(),quadratic.c().to_string()),("1".to_string(),"-1".to_string(),"-2".to_string()));
+    }
+
+    #[test]
+    fn numeric_roots() {
+        assert_eq!(roots(quadratic("x^2 - 3x + 2").solve()),["2","1"]);
+        assert_eq!(roots(quadratic("x^2 + 2x + 1").solve()),["-1"]);
+        assert!(roots(quadratic("x^2 + 1").solve()).is_empty());
+    }
+
+    #[test]
+    fn symbolic_parameters() {
+        let quadratic = quadratic("x^2 + 2bx + c");
+        assert_eq!(quadratic.clone().discriminant().find().to_string(),"4b^2 - 4c");
+        assert_eq!(quadratic.clone().sum_of_roots().find().to_string(),"-2b");
+        assert_eq!(quadratic.product_of_roots().find().to_string(),"c");
+    }
+
+    #[test]
+    fn invalid_quadratics() {
+        let not_quadratic = |input : &str| SymbolicQuadratic::try_from((&Expression::try_from(input).unwrap(),'x'));
+
+        assert!(matches!(not_quadratic("2x + 1"),Err(ArkleyError::UndefinedConcavity)));
+        assert!(matches!(not_quadratic("x^3 + x"),Err(ArkleyError::InvalidQuadratic)));
+        assert!(matches!(not_quadratic("1 / x"),Err(ArkleyError::InvalidQuadratic)));
+    }
+
+    #[cfg(feature="equation")]
+    #[test]
+    fn from_equation() {
+        let equation = crate::Equation::try_from("x^2 = 4").unwrap();
+        assert_eq!(roots(equation.solve_quadratic('x').unwrap()),["2","-2"]);
+
+        let quadratic = IntegerQuadratic::<f64>::try_from((&equation,'x')).unwrap();
+        assert_eq!(quadratic.c,-4.0);
+
+        let equation = crate::Equation::try_from("x^2 = k").unwrap();
+        assert!(IntegerQuadratic::<f64>::try_from((&equation,'x')).is_err());
+        assert_eq!(roots(equation.solve_quadratic('x').unwrap()),["k^0.5","-1k^0.5"]);
+
+        let equation = crate::Equation::try_from("ax^2 + bx + c = 0").unwrap();
+        assert_eq!(roots(equation.solve_quadratic('x').unwrap()),["(-1b + (-4ac + b^2)^0.5)/2a","(-1b - (-4ac + b^2)^0.5)/2a"]);
+    }
+}
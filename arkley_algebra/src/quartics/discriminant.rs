@@ -0,0 +1,97 @@
+use num_notation::Num;
+
+use crate::manipulation::Find;
+
+use super::IntegerQuartic;
+
+/// A utility struct for calculating the discriminant of a quartic equation.
+///
+/// If it is negative there are two distinct real roots , if it is `0` at least two roots are equal
+/// and if it is positive the roots are either all real or all complex.
+///
+/// It is created by [super::Quartic::roots] or [super::Quartic::discriminant] method.
+#[derive(Debug,Clone)]
+pub struct Discriminant<T>(pub(super) T);
+
+impl<T> Find for Discriminant<IntegerQuartic<T>> where T : Num + Clone + From<u8> {
+    type Output = T;
+
+    // D = 256a^3e^3 - 192a^2bde^2 - 128a^2c^2e^2 + 144a^2cd^2e - 27a^2d^4
+    //   + 144ab^2ce^2 - 6ab^2d^2e - 80abc^2de + 18abcd^3 + 16ac^4e - 4ac^3d^2
+    //   - 27b^4e^2 + 18b^3cde - 4b^3d^3 - 4b^2c^3e + b^2c^2d^2
+    fn find(self) -> T {
+        let IntegerQuartic { a, b, c, d, e } = self.0;
+
+        let n = |value : u8| T::from(value);
+        let pow = |value : &T,exponent : usize| (1..exponent).fold(value.clone(),|acc,_| acc * value.clone());
+
+        n(16) * n(16) * pow(&a,3) * pow(&e,3)
+            - n(192) * pow(&a,2) * b.clone() * d.clone() * pow(&e,2)
+            - n(128) * pow(&a,2) * pow(&c,2) * pow(&e,2)
+            + n(144) * pow(&a,2) * c.clone() * pow(&d,2) * e.clone()
+            - n(27) * pow(&a,2) * pow(&d,4)
+            + n(144) * a.clone() * pow(&b,2) * c.clone() * pow(&e,2)
+            - n(6) * a.clone() * pow(&b,2) * pow(&d,2) * e.clone()
+            - n(80) * a.clone() * b.clone() * pow(&c,2) * d.clone() * e.clone()
+            + n(18) * a.clone() * b.clone() * c.clone() * pow(&d,3)
+            + n(16) * a.clone() * pow(&c,4) * e.clone()
+            - n(4) * a * pow(&c,3) * pow(&d,2)
+            - n(27) * pow(&b,4) * pow(&e,2)
+            + n(18) * pow(&b,3) * c.clone() * d.clone() * e.clone()
+            - n(4) * pow(&b,3) * pow(&d,3)
+            - n(4) * pow(&b,2) * pow(&c,3) * e
+            + pow(&b,2) * pow(&c,2) * pow(&d,2)
+    }
+}
+
+impl<T,O> From<T> for Discriminant<IntegerQuartic<O>> where T : Into<IntegerQuartic<O>> , O : Num + Clone {
+    fn from(value: T) -> Self {
+        Self(value.into())
+    }
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    Describe,
+    Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+impl<T> Describe for Discriminant<IntegerQuartic<T>> where T : Num + Clone + From<u8> + std::fmt::Display {
+    fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        let haupteil = resources.lookup_single_language::<&str>(lang, "discriminant-integerquartic.haupttiel", None)?;
+
+        let nebenteil = {
+            let mut args = std::collections::HashMap::from([
+                ("a",self.0.a.to_string().into()),
+                ("b",self.0.b.to_string().into()),
+                ("c",self.0.c.to_string().into()),
+                ("d",self.0.d.to_string().into()),
+                ("e",self.0.e.to_string().into()),
+            ]);
+
+            let result = self.find();
+            args.insert("result", result.to_string().into());
+
+            resources.lookup_single_language(lang, "discriminant-integerquartic.subsitiute",Some(&args))?
+        };
+
+        vec![format!("{haupteil}\n{nebenteil}")].into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_of_discriminant() {
+        // (x - 1)(x + 1)(x - 2)(x + 2)
+        assert_eq!(Discriminant(IntegerQuartic::new(1,0,-5,0,4)).find(),5184);
+        // (x - 1)^2 (x^2 + 1)
+        assert_eq!(Discriminant(IntegerQuartic::new(1,-2,2,-2,1)).find(),0);
+        // (x^2 - 1)(x^2 + 1)
+        assert_eq!(Discriminant(IntegerQuartic::new(1,0,0,0,-1)).find(),-256);
+    }
+}
@@ -0,0 +1,140 @@
+use num_notation::{Num, Zero, fraction::Ratio};
+
+use crate::manipulation::Find;
+use crate::radicals::Surd;
+use crate::cubics::{rational_root, deflate, exact_quadratic_roots, to_rational};
+
+use super::*;
+
+/// A utility struct for finding the exact real roots of a quartic equation as surds so `1 + √2`
+/// instead of `2.414213562373095`.
+///
+/// Rational roots are searched for using the rational root theorem until a quadratic remains which is then solved exactly.
+/// This only works if the quartic has at least two rational roots (counting multiplicity) , or if it is a biquadratic
+/// like `x^4 - 5x^2 + 6` whose roots are the square roots of the roots of a quadratic.
+///
+/// It is created by [Roots::exact] method.
+#[derive(Debug,Clone)]
+pub struct ExactRoots<T>(pub(super) T);
+
+impl<T> Roots<IntegerQuartic<T>> where T : Num + Clone {
+    /// Finds the roots using surds instead of floating point numbers , see [ExactRoots]
+    pub fn exact(self) -> ExactRoots<IntegerQuartic<T>> {
+        ExactRoots(self.0.0)
+    }
+}
+
+impl<T> Find for ExactRoots<IntegerQuartic<T>> where T : Num + Clone + TryInto<Surd> {
+    /// `None` is returned if any of the coefficients can not be written as an exact rational number (eg `NaN`)
+    /// or if the roots can not be found exactly (eg `x^4 + x + 1`) , in which case [Roots] can be used to find them numerically
+    type Output = Option<Vec<Surd>>;
+
+    fn find(self) -> Self::Output {
+        let IntegerQuartic { a, b, c, d, e } = self.0;
+        let coefficients = [to_rational(a)?,to_rational(b)?,to_rational(c)?,to_rational(d)?,to_rational(e)?];
+
+        let mut roots = rational_roots(&coefficients).or_else(|| biquadratic_roots(&coefficients))?;
+        roots.sort_by(|r1,r2| r1.to_f64().total_cmp(&r2.to_f64()));
+
+        Some(roots)
+    }
+}
+
+/// Divides out rational roots until a quadratic remains which is solved exactly
+fn rational_roots(coefficients : &[Ratio<i64>]) -> Option<Vec<Surd>> {
+    let mut coefficients = coefficients.to_vec();
+    let mut roots = Vec::new();
+
+    while coefficients.len() > 3 {
+        let root = rational_root(&coefficients)?;
+        coefficients = deflate(&coefficients,root);
+        roots.push(Surd::from(root));
+    }
+
+    roots.extend(exact_quadratic_roots(&coefficients)?);
+
+    Some(roots)
+}
+
+/// Solves `ax^4 + cx^2 + e` by solving `az^2 + cz + e` for `z = x^2` , so every `z >= 0` gives the roots `±√z`
+fn biquadratic_roots(coefficients : &[Ratio<i64>]) -> Option<Vec<Surd>> {
+    let [a,b,c,d,e] = coefficients else {
+        return None;
+    };
+
+    if !b.is_zero() || !d.is_zero() {
+        return None;
+    }
+
+    let mut roots = Vec::new();
+
+    for z in exact_quadratic_roots(&[*a,*c,*e])? {
+        if z.to_f64() < 0.0 {
+            continue;
+        }
+
+        // Roots like `√(1 + √2)` can not be written as surds
+        let root = z.sqrt()?;
+        roots.push(-root.clone());
+        roots.push(root);
+    }
+
+    Some(roots)
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    Describe, Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+impl<T> Describe for ExactRoots<IntegerQuartic<T>> where T : Num + Clone + From<u8> + std::fmt::Display + TryInto<Surd> {
+    fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        let discriminant_description = Discriminant(self.0.clone()).describe(resources,lang)?;
+
+        let description = super::roots::describe_roots(self.find()?,resources,lang)?;
+
+        let mut vec = Vec::new();
+
+        vec.extend(discriminant_description);
+        vec.push(description);
+
+        vec.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roots(a : i32,b : i32,c : i32,d : i32,e : i32) -> Option<Vec<String>> {
+        let roots = IntegerQuartic::new(a,b,c,d,e).roots().exact().find()?;
+        Some(roots.into_iter().map(|root| root.to_string()).collect())
+    }
+
+    #[test]
+    fn rational_and_irrational_roots() {
+        // (x - 1)(x + 2)(x^2 - 2)
+        assert_eq!(roots(1,1,-4,-2,4).unwrap(),["-2","-√2","1","√2"]);
+        // (x - 1)^2 (x^2 + 1)
+        assert_eq!(roots(1,-2,2,-2,1).unwrap(),["1","1"]);
+    }
+
+    #[test]
+    fn biquadratic() {
+        // (x^2 - 2)(x^2 - 3)
+        assert_eq!(roots(1,0,-5,0,6).unwrap(),["-√3","-√2","√2","√3"]);
+        // (x^2 - 2)(x^2 + 1)
+        assert_eq!(roots(1,0,-1,0,-2).unwrap(),["-√2","√2"]);
+        // (2x^2 - 1)^2
+        assert_eq!(roots(4,0,-4,0,1).unwrap().len(),4);
+    }
+
+    #[test]
+    fn not_exact() {
+        assert!(roots(1,0,0,1,1).is_none());
+        // x^2 = 1 ± √2
+        assert!(roots(1,0,-2,0,-1).is_none());
+    }
+}
@@ -0,0 +1,72 @@
+use num_notation::{Num, Signed};
+
+use crate::cubics::write_polynomial;
+
+use super::*;
+
+/// Represents an integer quartic equation of the form `a*x^4 + b*x^3 + c*x^2 + d*x + e`.
+///
+/// This struct allows you to work with quartic equations where `a`, `b`, `c`, `d` and `e` can be any numeric type,
+/// such as integers (`i32`, `i64`, etc.) or floating-point numbers (`f32`, `f64`).
+///
+/// **Note** : `a` should not be `0` , otherwise the equation is not quartic
+#[derive(Clone)]
+pub struct IntegerQuartic<T> where T: Num + Clone {
+    pub(super) a: T,
+    pub(super) b: T,
+    pub(super) c: T,
+    pub(super) d: T,
+    pub(super) e: T,
+}
+
+impl<T> IntegerQuartic<T> where T: Num + Clone {
+    /// Creates a new `IntegerQuartic` instance with the provided coefficients.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The coefficient for the quartic term (`a*x^4`).
+    /// * `b` - The coefficient for the cubic term (`b*x^3`).
+    /// * `c` - The coefficient for the quadratic term (`c*x^2`).
+    /// * `d` - The coefficient for the linear term (`d*x`).
+    /// * `e` - The constant term (`e`).
+    ///
+    /// # Returns
+    ///
+    /// A new `IntegerQuartic` instance with the given coefficients
+    pub const fn new(a: T,b: T,c: T,d: T,e: T) -> Self {
+        Self { a, b, c, d, e }
+    }
+}
+
+impl<T> std::fmt::Display for IntegerQuartic<T> where T : std::fmt::Display + Signed + Clone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_polynomial(f,&[self.a.clone(),self.b.clone(),self.c.clone(),self.d.clone(),self.e.clone()])
+    }
+}
+
+impl<T> std::fmt::Debug for IntegerQuartic<T> where T : std::fmt::Display + Signed + Clone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+impl<T> Quartic<Self> for IntegerQuartic<T> where T: Num + Clone + From<u8> {
+    fn discriminant(self) -> Discriminant<Self> {
+        Discriminant(self)
+    }
+
+    fn roots(self) -> Roots<Self> {
+        Roots(self.discriminant())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(IntegerQuartic::new(1,0,-5,0,4).to_string(),"x^4 - 5x^2 + 4 = 0");
+        assert_eq!(IntegerQuartic::new(-1,2,0,-1,3).to_string(),"-x^4 + 2x^3 - x + 3 = 0");
+    }
+}
@@ -0,0 +1,34 @@
+mod integer;
+
+mod discriminant;
+mod roots;
+mod exact;
+
+pub use integer::*;
+
+pub use discriminant::*;
+pub use roots::*;
+pub use exact::*;
+
+/// A trait for working with quartic equations and their roots.
+///
+/// The `Quartic` trait mirrors [crate::quadratics::Quadratic] for equations of the form `ax^4 + bx^3 + cx^2 + dx + e = 0`
+/// providing methods for calculating the discriminant and finding the real roots. It allows for easy extension with custom types.
+pub trait Quartic<T> : Sized {
+    /// Calculates the discriminant of the quartic equation.
+    ///
+    /// If it is negative there are two real and two complex roots , if it is positive the roots are either all real
+    /// or all complex and if it is `0` some roots repeat.
+    ///
+    /// # Returns
+    ///
+    /// The discriminant value of the quartic equation.
+    fn discriminant(self) -> Discriminant<T>;
+
+    /// Calculates the real roots of the quartic equation.
+    ///
+    /// # Returns
+    ///
+    /// A `Roots` struct representing the roots of the quartic equation.
+    fn roots(self) -> Roots<T>;
+}
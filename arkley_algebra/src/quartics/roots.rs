@@ -0,0 +1,154 @@
+use num_notation::Num;
+
+use crate::manipulation::{Find, tidy, EPSILON};
+use crate::cubics;
+
+use super::*;
+
+/// A utility struct for finding the real roots of a quartic equation.
+///
+/// The roots are found numerically using Ferrari's method , which splits the quartic into two quadratics
+/// using a root of its resolvent cubic. Use [Roots::exact] to find them as surds instead.
+///
+/// It is created by [super::Quartic::roots] method.
+#[derive(Debug,Clone)]
+pub struct Roots<T>(pub(super) Discriminant<T>);
+
+impl<T,O> From<T> for Roots<O> where T : Into<Discriminant<O>> , O : Num + Clone {
+    fn from(value: T) -> Self {
+        Self(value.into())
+    }
+}
+
+/// Finds the real roots of `y^2 + by + c` with multiplicity
+fn quadratic_roots(b : f64,c : f64) -> Vec<f64> {
+    let discriminant = b * b - 4.0 * c;
+
+    match discriminant {
+        _ if discriminant.abs() < EPSILON => vec![-b / 2.0,-b / 2.0],
+        _ if discriminant < 0.0 => vec![],
+        _ => {
+            let sqrt = discriminant.sqrt();
+            vec![(-b - sqrt) / 2.0,(-b + sqrt) / 2.0]
+        }
+    }
+}
+
+/// Finds the real roots of `ax^4 + bx^3 + cx^2 + dx + e` (where `a != 0`) with multiplicity in ascending order
+pub(crate) fn real_roots(a : f64,b : f64,c : f64,d : f64,e : f64) -> Vec<f64> {
+    let (b,c,d,e) = (b / a,c / a,d / a,e / a);
+
+    // The roots are found for x = ku where k is about as large as the roots , see [cubics::real_roots]
+    let k = cubics::scale(&[b,c,d,e]);
+    let (b,c,d,e) = (b / k,c / (k * k),d / (k * k * k),e / (k * k * k * k));
+
+    // x = y - b/4 gives the depressed quartic y^4 + py^2 + qy + r
+    let shift = b / 4.0;
+    let p = c - 3.0 * b * b / 8.0;
+    let q = d - b * c / 2.0 + b * b * b / 8.0;
+    let r = e - b * d / 4.0 + b * b * c / 16.0 - 3.0 * b * b * b * b / 256.0;
+
+    let depressed = if q.abs() < EPSILON {
+        // Biquadratic so y^2 is a root of z^2 + pz + r
+        quadratic_roots(p,r).into_iter()
+            .flat_map(|z| match z {
+                _ if z.abs() < EPSILON => vec![0.0,0.0],
+                _ if z < 0.0 => vec![],
+                _ => vec![-z.sqrt(),z.sqrt()]
+            })
+            .collect()
+    } else {
+        // Any positive root m of the resolvent cubic 8m^3 + 8pm^2 + (2p^2 - 8r)m - q^2 splits the quartic into
+        // (y^2 - sy + p/2 + m + q/2s)(y^2 + sy + p/2 + m - q/2s) where s = √(2m)
+        let m = cubics::real_roots(8.0,8.0 * p,2.0 * p * p - 8.0 * r,-q * q).into_vec()
+            .into_iter()
+            .fold(f64::NEG_INFINITY,f64::max);
+
+        let s = (2.0 * m).sqrt();
+
+        let mut roots = quadratic_roots(-s,p / 2.0 + m + q / (2.0 * s));
+        roots.extend(quadratic_roots(s,p / 2.0 + m - q / (2.0 * s)));
+        roots
+    };
+
+    let mut roots : Vec<f64> = depressed.into_iter().map(|y| tidy((y - shift) * k)).collect();
+    roots.sort_by(f64::total_cmp);
+    roots
+}
+
+impl<T> Find for Roots<IntegerQuartic<T>> where T : Num + Clone + Into<f64> {
+    /// The real roots with multiplicity in ascending order , which is empty if all of the roots are complex
+    type Output = Vec<f64>;
+
+    fn find(self) -> Vec<f64> {
+        let IntegerQuartic { a, b, c, d, e } = self.0.0;
+        real_roots(a.into(),b.into(),c.into(),d.into(),e.into())
+    }
+}
+
+#[cfg(feature="describe")]
+use arkley_describe::{
+    Describe, Steps,
+    fluent_templates::{StaticLoader, LanguageIdentifier}
+};
+
+#[cfg(feature="describe")]
+pub(super) fn describe_roots<T : std::fmt::Display>(roots : Vec<T>,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<String> {
+    if roots.is_empty() {
+        return resources.lookup_single_language::<&str>(lang, "roots-integerquartic.zero",None);
+    }
+
+    let roots = roots.iter().map(ToString::to_string).collect::<Vec<_>>().join(" , ");
+    let args = std::collections::HashMap::from([("roots",roots.into())]);
+
+    resources.lookup_single_language::<&str>(lang, "roots-integerquartic.some",Some(&args))
+}
+
+#[cfg(feature="describe")]
+impl<T> Describe for Roots<IntegerQuartic<T>> where T : Num + Clone + From<u8> + Into<f64> + std::fmt::Display {
+    fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<Steps> {
+        let discriminant_description = self.0.clone().describe(resources,lang)?;
+
+        let description = describe_roots(self.find(),resources,lang)?;
+
+        let mut vec = Vec::new();
+
+        vec.extend(discriminant_description);
+        vec.push(description);
+
+        vec.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roots(a : i32,b : i32,c : i32,d : i32,e : i32) -> Vec<f64> {
+        IntegerQuartic::new(a,b,c,d,e).roots().find()
+    }
+
+    #[test]
+    fn biquadratic() {
+        assert_eq!(roots(1,0,-5,0,4),[-2.0,-1.0,1.0,2.0]);
+        assert_eq!(roots(1,0,0,0,-1),[-1.0,1.0]);
+        assert!(roots(1,0,1,0,1).is_empty());
+    }
+
+    #[test]
+    fn ferrari() {
+        // (x - 1)(x - 2)(x - 3)(x + 4)
+        assert_eq!(roots(1,-2,-13,38,-24),[-4.0,1.0,2.0,3.0]);
+        // (x - 1)^2 (x^2 + 1)
+        assert_eq!(roots(1,-2,2,-2,1),[1.0,1.0]);
+        // x^4 + x + 1 has no real roots
+        assert!(roots(1,0,0,1,1).is_empty());
+    }
+
+    #[test]
+    fn small_roots() {
+        // (x - 0.001)(x - 0.002)(x - 0.003)(x - 0.004)
+        let roots = IntegerQuartic::new(1.0,-0.01,0.000035,-0.00000005,0.000000000024).roots().find();
+        assert_eq!(roots,[0.001,0.002,0.003,0.004]);
+    }
+}
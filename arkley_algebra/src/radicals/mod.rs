@@ -0,0 +1,5 @@
+mod surd;
+mod radical;
+
+pub use surd::*;
+pub use radical::*;
@@ -0,0 +1,189 @@
+use std::collections::BTreeSet;
+
+use num_notation::{Number, fraction::Ratio, One, Zero};
+
+use crate::{Term, Expression, Variables};
+use crate::manipulation::number_to_f64;
+
+use super::{Surd, ratio_to_number};
+
+/// The simplified square root of a [Term] like `√(8x^2y) = 2|x|√(2y)`.
+///
+/// It is created by [Term::simplify_sqrt] or [Expression::simplify_sqrt].
+#[derive(Clone,PartialEq)]
+pub struct Radical {
+    /// Rational factor taken out of the square root
+    pub(crate) coefficient : Ratio<i64>,
+    /// Variables taken out of the square root
+    pub(crate) outside : Variables,
+    /// Variables of `outside` which have to be written as `|x|` as they could be negative
+    pub(crate) absolute : BTreeSet<char>,
+    /// Square free number left inside of the square root
+    pub(crate) radicand : u64,
+    /// Variables left inside of the square root
+    pub(crate) inside : Variables,
+}
+
+impl Term {
+    /// Simplifies the square root of the term by taking out square factors so `√8 = 2√2` and `√(x^2y) = |x|√y`
+    ///
+    /// **Note** : `None` is returned if the coefficient is negative or is not rational (eg `NaN`)
+    pub fn simplify_sqrt(self) -> Option<Radical> {
+        let surd = Surd::try_from(self.coefficient).ok()?.sqrt()?;
+
+        let (radicand,coefficient) = surd.terms.into_iter().next().unwrap_or((1,Ratio::zero()));
+
+        let mut radical = Radical {
+            coefficient,
+            outside : Variables::new(),
+            absolute : BTreeSet::new(),
+            radicand,
+            inside : Variables::new()
+        };
+
+        for (variable,exponent) in self.variables {
+            let value = number_to_f64(&exponent);
+
+            // Things like x^0.5 can not be split so they stay inside
+            if value.fract() != 0.0 {
+                radical.inside.insert(variable,exponent);
+                continue;
+            }
+
+            let value = value as i64;
+            let (outside,inside) = (value.div_euclid(2),value.rem_euclid(2));
+
+            if outside != 0 {
+                radical.outside.insert(variable,Number::Decimal(outside as f64));
+
+                // √(x^2) = |x| but for √(x^3) = x√x , x can not be negative anyways
+                if outside % 2 != 0 && inside == 0 {
+                    radical.absolute.insert(variable);
+                }
+            }
+
+            if inside != 0 {
+                radical.inside.insert(variable,Number::Decimal(inside as f64));
+            }
+        }
+
+        Some(radical)
+    }
+}
+
+impl Expression {
+    /// Simplifies the square root of the expression , see [Term::simplify_sqrt]
+    ///
+    /// **Note** : `None` is returned if the expression is not a single term
+    pub fn simplify_sqrt(self) -> Option<Radical> {
+        match self {
+            Expression::Term(term) => term.simplify_sqrt(),
+            _ => None
+        }
+    }
+}
+
+impl Radical {
+    /// Checks if nothing is left inside of the square root
+    pub fn is_exact(&self) -> bool {
+        self.radicand == 1 && self.inside.is_empty()
+    }
+}
+
+impl From<Radical> for Expression {
+    /// Writes `|x|` as `(x^2)^0.5` and the square root as a power of `0.5` so `2|x|√(2y)` becomes `2((x^2)^0.5)((2y)^0.5)`
+    fn from(value : Radical) -> Self {
+        let coefficient = ratio_to_number(value.coefficient);
+
+        let (absolute,plain) : (Variables,Variables) = value.outside.into_iter()
+            .partition(|(variable,_)| value.absolute.contains(variable));
+
+        let mut expression = Expression::from(Term::new_with_variable(coefficient,plain));
+
+        for (variable,exponent) in absolute {
+            let squared = Term::create_single_variable_term(Number::one(),variable,exponent * Number::Decimal(2.0));
+            expression = Expression::new_mal(expression,Expression::new_pow(squared.into(),0.5.into()));
+        }
+
+        if value.radicand != 1 || !value.inside.is_empty() {
+            let inside = Term::new_with_variable(Number::Decimal(value.radicand as f64),value.inside);
+            expression = Expression::new_mal(expression,Expression::new_pow(inside.into(),0.5.into()));
+        }
+
+        expression
+    }
+}
+
+impl std::fmt::Display for Radical {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let is_exact = self.is_exact();
+        let has_rest = !self.outside.is_empty() || !is_exact;
+
+        match has_rest {
+            true if self.coefficient.is_one() => (),
+            true if self.coefficient == -Ratio::one() => write!(f,"-")?,
+            true if !self.coefficient.is_integer() => write!(f,"({})",self.coefficient)?,
+            _ => write!(f,"{}",self.coefficient)?,
+        }
+
+        for (variable,exponent) in self.outside.iter() {
+            let name = match exponent.is_one() {
+                true => variable.to_string(),
+                false => format!("{variable}^{exponent}")
+            };
+
+            match self.absolute.contains(variable) {
+                true => write!(f,"|{name}|")?,
+                false => write!(f,"{name}")?
+            }
+        }
+
+        if is_exact {
+            return Ok(());
+        }
+
+        let inside = Term::new_with_variable(Number::Decimal(self.radicand as f64),self.inside.clone());
+
+        // √2 and √x need no brackets but √(2x) does
+        match self.radicand == 1 || self.inside.is_empty() {
+            true if self.inside.len() <= 1 => write!(f,"√{inside}"),
+            _ => write!(f,"√({inside})")
+        }
+    }
+}
+
+impl std::fmt::Debug for Radical {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn simplify(term : Term) -> String {
+        term.simplify_sqrt().unwrap().to_string()
+    }
+
+    fn term(coeff : f64,variables : &[(char,f64)]) -> Term {
+        let variables : Variables = variables.iter().map(|(c,e)| (*c,Number::Decimal(*e))).collect();
+        Term::new_with_variable(Number::Decimal(coeff),variables)
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(simplify(term(8.0,&[])),"2√2");
+        assert_eq!(simplify(term(9.0,&[])),"3");
+        assert_eq!(simplify(term(2.0,&[])),"√2");
+        assert!(term(-4.0,&[]).simplify_sqrt().is_none());
+    }
+
+    #[test]
+    fn variables() {
+        assert_eq!(simplify(term(1.0,&[('x',2.0),('y',1.0)])),"|x|√y");
+        assert_eq!(simplify(term(8.0,&[('x',2.0),('y',1.0)])),"2|x|√(2y)");
+        assert_eq!(simplify(term(1.0,&[('x',3.0)])),"x√x");
+        assert_eq!(simplify(term(4.0,&[('x',4.0)])),"2x^2");
+    }
+}
@@ -0,0 +1,515 @@
+use std::collections::BTreeMap;
+use std::ops::{Add, Sub, Mul, Div, Neg};
+
+use num_notation::{Number, fraction::{GenericFraction, Ratio, Integer}, Zero, One, Signed, CheckedAdd, CheckedMul};
+
+use crate::{Term, Expression};
+
+/// An exact number made up of rational multiples of square roots like `2√2` or `(-3 + √17)/4`.
+///
+/// Each square root is kept in its simplest form so `√8` is stored as `2√2` , which means that
+/// surds can be added , subtracted , multiplied and divided (rationalising the denominator) without
+/// ever falling back to floating point numbers.
+///
+/// **Note** : Coefficients are stored as `Ratio<i64>` so very large numbers may overflow , use the `checked_*`
+/// methods to get `None` instead of a panic when they do
+#[derive(Clone,PartialEq,Eq,Hash,Default)]
+pub struct Surd {
+    /// Maps the square free radicand to its coefficient , the rational part uses the radicand `1`
+    pub(crate) terms : BTreeMap<u64,Ratio<i64>>,
+}
+
+/// An error indicating that a value can not be written as an exact rational number (eg `NaN` or infinity)
+#[derive(Debug,Clone,PartialEq,thiserror::Error)]
+#[error("the value can not be written as an exact rational number")]
+pub struct NotRationalError;
+
+/// Radicands larger than this are not simplified as finding their square factors would take too long
+pub(crate) const MAX_RADICAND : u64 = 1 << 40;
+
+/// Splits `n` into `(outside,inside)` so that `n = outside^2 * inside` where `inside` is square free
+pub(crate) fn square_free(mut n : u64) -> (u64,u64) {
+    let (mut outside,mut inside) = (1,1);
+    let mut factor = 2;
+
+    while factor <= n / factor {
+        let mut count = 0;
+        while n.is_multiple_of(factor) {
+            n /= factor;
+            count += 1;
+        }
+
+        outside *= factor.pow(count / 2);
+        if count % 2 == 1 {
+            inside *= factor;
+        }
+
+        factor += 1;
+    }
+
+    (outside,inside * n)
+}
+
+/// Returns the smallest prime factor of `n` (where `n > 1`)
+fn smallest_prime_factor(n : u64) -> u64 {
+    (2..).take_while(|factor| *factor <= n / factor)
+        .find(|factor| n.is_multiple_of(*factor))
+        .unwrap_or(n)
+}
+
+impl Surd {
+    /// Creates a new rational `Surd` (without any square roots)
+    pub fn rational(value : Ratio<i64>) -> Self {
+        let mut surd = Self::default();
+        // adding to an empty surd can not overflow
+        let _ = surd.insert(1,value);
+        surd
+    }
+
+    /// Creates the simplified square root of a rational number so `√8 = 2√2` and `√(1/2) = √2/2`
+    ///
+    /// **Note** : `None` is returned if the value is negative or `numerator * denominator` is larger than [MAX_RADICAND]
+    pub fn sqrt_of(value : Ratio<i64>) -> Option<Self> {
+        if value.is_negative() {
+            return None;
+        }
+
+        // √(p/q) = √(pq)/q
+        let (numer,denom) = (*value.numer() as u64,*value.denom() as u64);
+        let radicand = numer.checked_mul(denom).filter(|radicand| *radicand <= MAX_RADICAND)?;
+        let (outside,inside) = square_free(radicand);
+
+        let mut surd = Self::default();
+        surd.insert(inside,Ratio::new(outside as i64,denom as i64))?;
+        Some(surd)
+    }
+
+    /// Calculates the square root of the surd if it is rational , see [Surd::sqrt_of]
+    ///
+    /// **Note** : `None` is returned if the surd is negative or already contains square roots
+    pub fn sqrt(self) -> Option<Self> {
+        self.as_rational().and_then(Self::sqrt_of)
+    }
+
+    /// Checks if the surd is `0`
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns the value of the surd if it does not contain any square roots
+    pub fn as_rational(&self) -> Option<Ratio<i64>> {
+        match self.terms.len() {
+            0 => Some(Ratio::zero()),
+            1 => self.terms.get(&1).cloned(),
+            _ => None
+        }
+    }
+
+    /// Approximates the value of the surd as a `f64`
+    pub fn to_f64(&self) -> f64 {
+        self.terms.iter()
+            .map(|(radicand,coefficient)| (*coefficient.numer() as f64 / *coefficient.denom() as f64) * (*radicand as f64).sqrt())
+            .sum()
+    }
+
+    /// Divides the surds while rationalising the denominator so `1/√2 = √2/2` and `1/(1 + √2) = -1 + √2`
+    ///
+    /// **Note** : `None` is returned if `other` is `0`
+    pub fn checked_div(self,other : Self) -> Option<Self> {
+        let (mut numerator,mut denominator) = (self,other);
+
+        // Write the denominator as P + Q√p for some prime p , then multiplying by P - Q√p gives
+        // P^2 - pQ^2 which no longer contains √p. As no new primes appear this eventually leaves a rational number
+        while let Some(radicand) = denominator.terms.keys().find(|radicand| **radicand != 1) {
+            let prime = smallest_prime_factor(*radicand);
+
+            let mut conjugate = denominator.clone();
+            for (radicand,coefficient) in conjugate.terms.iter_mut() {
+                if (*radicand).is_multiple_of(prime) {
+                    *coefficient = -*coefficient;
+                }
+            }
+
+            numerator = numerator.checked_mul(conjugate.clone())?;
+            denominator = denominator.checked_mul(conjugate)?;
+        }
+
+        let denominator = denominator.as_rational().filter(|value| !value.is_zero())?;
+        numerator.checked_scale(denominator.recip())
+    }
+
+    /// Adds the surds
+    ///
+    /// **Note** : `None` is returned if a coefficient overflows
+    pub fn checked_add(mut self,other : Self) -> Option<Self> {
+        for (radicand,coefficient) in other.terms {
+            self.insert(radicand,coefficient)?;
+        }
+        Some(self)
+    }
+
+    /// Subtracts the surds
+    ///
+    /// **Note** : `None` is returned if a coefficient overflows
+    pub fn checked_sub(self,other : Self) -> Option<Self> {
+        self.checked_add(other.checked_scale(-Ratio::one())?)
+    }
+
+    /// Multiplies the surds
+    ///
+    /// **Note** : `None` is returned if a coefficient overflows or a radicand becomes larger than [MAX_RADICAND]
+    pub fn checked_mul(self,other : Self) -> Option<Self> {
+        let mut surd = Surd::default();
+
+        for (r1,c1) in self.terms.iter() {
+            for (r2,c2) in other.terms.iter() {
+                // √r1 * √r2 = g√((r1/g)(r2/g)) where g = gcd(r1,r2) , which stays square free
+                let g = r1.gcd(r2);
+                let radicand = (r1 / g).checked_mul(r2 / g).filter(|radicand| *radicand <= MAX_RADICAND)?;
+                let coefficient = c1.checked_mul(c2)?.checked_mul(&Ratio::from_integer(g as i64))?;
+                surd.insert(radicand,coefficient)?;
+            }
+        }
+
+        Some(surd)
+    }
+
+    /// Multiplies every coefficient by the non zero `factor` , returning `None` if any of them overflow
+    fn checked_scale(mut self,factor : Ratio<i64>) -> Option<Self> {
+        self.terms = self.terms.into_iter()
+            .map(|(radicand,coefficient)| coefficient.checked_mul(&factor).map(|coefficient| (radicand,coefficient)))
+            .collect::<Option<_>>()?;
+        Some(self)
+    }
+
+    /// Adds `coefficient√radicand` to the surd , removing the term if it cancels out
+    ///
+    /// **Note** : `None` is returned if the coefficient overflows
+    fn insert(&mut self,radicand : u64,coefficient : Ratio<i64>) -> Option<()> {
+        if coefficient.is_zero() || radicand == 0 {
+            return Some(());
+        }
+
+        let value = self.terms.entry(radicand).or_insert_with(Ratio::zero);
+        *value = value.checked_add(&coefficient)?;
+
+        if value.is_zero() {
+            self.terms.remove(&radicand);
+        }
+
+        Some(())
+    }
+}
+
+impl Add for Surd {
+    type Output = Surd;
+
+    /// Adds the surds , see [Surd::checked_add] which is what the crate itself uses
+    ///
+    /// # Panics
+    ///
+    /// Just like integer addition this panics if a coefficient overflows
+    fn add(self,other : Surd) -> Self::Output {
+        self.checked_add(other).expect("attempt to add with overflow")
+    }
+}
+
+impl Neg for Surd {
+    type Output = Surd;
+
+    fn neg(self) -> Self::Output {
+        self.checked_scale(-Ratio::one()).expect("attempt to negate with overflow")
+    }
+}
+
+impl Sub for Surd {
+    type Output = Surd;
+
+    /// Subtracts the surds , see [Surd::checked_sub] which is what the crate itself uses
+    ///
+    /// # Panics
+    ///
+    /// Just like integer subtraction this panics if a coefficient overflows
+    fn sub(self,other : Surd) -> Self::Output {
+        self.checked_sub(other).expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul for Surd {
+    type Output = Surd;
+
+    /// Multiplies the surds , see [Surd::checked_mul] which is what the crate itself uses
+    ///
+    /// # Panics
+    ///
+    /// Just like integer multiplication this panics if a coefficient or radicand overflows
+    fn mul(self,other : Surd) -> Self::Output {
+        self.checked_mul(other).expect("attempt to multiply with overflow")
+    }
+}
+
+impl Div for Surd {
+    type Output = Surd;
+
+    /// Divides the surds , see [Surd::checked_div] which is what the crate itself uses
+    ///
+    /// # Panics
+    ///
+    /// Just like integer division this panics if `other` is `0` , use [Surd::checked_div] if `other` could be `0`
+    fn div(self,other : Surd) -> Self::Output {
+        self.checked_div(other).expect("attempt to divide by zero")
+    }
+}
+
+impl From<Ratio<i64>> for Surd {
+    fn from(value : Ratio<i64>) -> Self {
+        Surd::rational(value)
+    }
+}
+
+macro_rules! from {
+    (int => $($t:ty),*) => {
+        $(
+            impl From<$t> for Surd {
+                fn from(value : $t) -> Self {
+                    Surd::rational(Ratio::from_integer(value as i64))
+                }
+            }
+        )*
+    };
+    (float => $($t:ty),*) => {
+        $(
+            impl TryFrom<$t> for Surd {
+                type Error = NotRationalError;
+                fn try_from(value : $t) -> Result<Self,Self::Error> {
+                    decimal_expansion(f64::from(value))
+                        .or_else(|| Ratio::approximate_float(value))
+                        .map(Surd::rational)
+                        .ok_or(NotRationalError)
+                }
+            }
+        )*
+    };
+}
+
+/// Converts the number into the fraction of its decimal expansion , so `0.333333333` is `333333333/1000000000` rather than `1/3`
+///
+/// **Note** : `None` is returned if the number has more than [f64::DIGITS] significant digits , as then it was most likely
+/// calculated (like `0.3333333333333333` for `1/3`) instead of written as a decimal , or if the fraction would overflow
+fn decimal_expansion(value : f64) -> Option<Ratio<i64>> {
+    // Floats are never displayed in exponent notation , and always with the fewest digits that give the same float
+    let string = value.abs().to_string();
+    let (whole,fraction) = string.split_once('.').unwrap_or((&string,""));
+    let digits = format!("{whole}{fraction}");
+
+    if digits.trim_start_matches('0').trim_end_matches('0').len() > f64::DIGITS as usize {
+        return None;
+    }
+
+    let numer = digits.parse::<i64>().ok()?;
+    let denom = 10i64.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+
+    match value.is_sign_negative() {
+        true => Some(-Ratio::new(numer,denom)),
+        false => Some(Ratio::new(numer,denom))
+    }
+}
+
+from!(int => i8, i16, i32, i64, u8, u16, u32);
+from!(float => f32, f64);
+
+impl TryFrom<Number> for Surd {
+    type Error = NotRationalError;
+
+    fn try_from(value : Number) -> Result<Self,Self::Error> {
+        match value {
+            Number::Fraction(GenericFraction::Rational(sign,ratio)) => {
+                let value = Ratio::new(*ratio.numer() as i64,*ratio.denom() as i64);
+                match sign.is_negative() {
+                    true => Ok(Surd::rational(-value)),
+                    false => Ok(Surd::rational(value))
+                }
+            },
+            Number::Fraction(_) => Err(NotRationalError),
+            Number::Decimal(_) | Number::StandardForm(_) => f64::from(value).try_into()
+        }
+    }
+}
+
+/// Converts the coefficient into a `Number` , keeping it as a fraction if it is not whole
+/// and falling back to a decimal if the numerator or denominator does not fit into a `u32`
+pub(crate) fn ratio_to_number(value : Ratio<i64>) -> Number {
+    if value.is_integer() {
+        return Number::Decimal(*value.numer() as f64);
+    }
+
+    match (u32::try_from(value.numer().unsigned_abs()),u32::try_from(*value.denom())) {
+        (Ok(numer),Ok(denom)) => {
+            let fraction = GenericFraction::<u32>::new(numer,denom);
+            match value.is_negative() {
+                true => Number::Fraction(-fraction),
+                false => Number::Fraction(fraction)
+            }
+        },
+        _ => Number::Decimal(*value.numer() as f64 / *value.denom() as f64)
+    }
+}
+
+impl From<Surd> for Expression {
+    /// Writes each square root as a power of `0.5` so `2√3` becomes `2(3^0.5)`
+    fn from(value : Surd) -> Self {
+        value.terms.into_iter()
+            .map(|(radicand,coefficient)| {
+                let coefficient = Term::new(ratio_to_number(coefficient));
+                match radicand == 1 {
+                    true => coefficient.into(),
+                    false => Expression::new_mal(
+                        coefficient.into(),
+                        Expression::new_pow(radicand.into(),0.5.into())
+                    )
+                }
+            })
+            .reduce(Expression::new_plus)
+            .unwrap_or_else(|| 0.into())
+    }
+}
+
+/// Writes a single `n√r` with its sign , where the first term only shows the sign when it is negative
+fn write_term(f : &mut std::fmt::Formatter<'_>,first : bool,numerator : i64,radicand : u64) -> std::fmt::Result {
+    match (first,numerator.is_negative()) {
+        (true,true) => write!(f,"-")?,
+        (true,false) => (),
+        (false,true) => write!(f," - ")?,
+        (false,false) => write!(f," + ")?
+    }
+
+    match (numerator.unsigned_abs(),radicand) {
+        (n,1) => write!(f,"{n}"),
+        (1,r) => write!(f,"√{r}"),
+        (n,r) => write!(f,"{n}√{r}")
+    }
+}
+
+impl std::fmt::Display for Surd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.terms.is_empty() {
+            return write!(f,"0");
+        }
+
+        // Written over a common denominator so (-3 + √17)/4 instead of -3/4 + 1/4√17
+        let common = self.terms.values()
+            .try_fold(1i64,|acc,coefficient| (acc / acc.gcd(coefficient.denom())).checked_mul(*coefficient.denom()))
+            .and_then(|denominator| self.terms.values()
+                .map(|coefficient| coefficient.numer().checked_mul(&(denominator / coefficient.denom())))
+                .collect::<Option<Vec<_>>>()
+                .map(|numerators| (denominator,numerators))
+            );
+
+        // Each term keeps its own denominator if the common one does not fit , like √2/3 + 2√3/5
+        let Some((denominator,numerators)) = common else {
+            for (index,(radicand,coefficient)) in self.terms.iter().enumerate() {
+                write_term(f,index == 0,*coefficient.numer(),*radicand)?;
+
+                if !coefficient.denom().is_one() {
+                    write!(f,"/{}",coefficient.denom())?;
+                }
+            }
+
+            return Ok(());
+        };
+
+        if self.terms.len() > 1 && denominator != 1 {
+            write!(f,"(")?;
+        }
+
+        for (index,(radicand,numerator)) in self.terms.keys().zip(numerators).enumerate() {
+            write_term(f,index == 0,numerator,*radicand)?;
+        }
+
+        match (denominator,self.terms.len()) {
+            (1,_) => Ok(()),
+            (d,1) => write!(f,"/{d}"),
+            (d,_) => write!(f,")/{d}")
+        }
+    }
+}
+
+impl std::fmt::Debug for Surd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"{self}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sqrt(n : i64) -> Surd {
+        Surd::sqrt_of(Ratio::from_integer(n)).unwrap()
+    }
+
+    #[test]
+    fn simplification() {
+        assert_eq!(sqrt(8).to_string(),"2√2");
+        assert_eq!(sqrt(72).to_string(),"6√2");
+        assert_eq!(sqrt(16).to_string(),"4");
+        assert_eq!(Surd::sqrt_of(Ratio::new(1,2)).unwrap().to_string(),"√2/2");
+        // the numerator and denominator multiplied together does not fit in a u64
+        assert_eq!(Surd::sqrt_of(Ratio::new(i64::MAX,i64::MAX - 1)),None);
+        // too large to be factored quickly
+        assert_eq!(Surd::sqrt_of(Ratio::from_integer((1 << 59) + 1)),None);
+        assert_eq!(Surd::sqrt_of(Ratio::from_integer(-4)),None);
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!((sqrt(8) + sqrt(2)).to_string(),"3√2");
+        assert_eq!((sqrt(2) - sqrt(2)).to_string(),"0");
+        assert_eq!((sqrt(6) * sqrt(3)).to_string(),"3√2");
+        assert_eq!(((Surd::from(1) + sqrt(2)) * (Surd::from(1) - sqrt(2))).to_string(),"-1");
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(Surd::from(i64::MAX).checked_mul(Surd::from(2)),None);
+        assert_eq!(Surd::from(i64::MAX).checked_add(Surd::from(1)),None);
+        assert_eq!(Surd::from(i64::MIN).checked_sub(Surd::from(1)),None);
+        assert_eq!(sqrt(2).checked_mul(Surd::from(3)).unwrap().to_string(),"3√2");
+
+        // too large for a fraction of u32s so it becomes a decimal
+        assert_eq!(ratio_to_number(Ratio::new(1,5_000_000_003)),Number::Decimal(1.0 / 5_000_000_003.0));
+        assert_eq!(ratio_to_number(Ratio::new(-1,3)),Number::Fraction(-GenericFraction::new(1u32,3u32)));
+    }
+
+    #[test]
+    fn decimals() {
+        assert_eq!(Surd::try_from(0.333333333).unwrap().as_rational(),Some(Ratio::new(333333333,1000000000)));
+        assert_eq!(Surd::try_from(-2.5).unwrap().as_rational(),Some(Ratio::new(-5,2)));
+        assert_eq!(Surd::try_from(1.0 / 3.0).unwrap().as_rational(),Some(Ratio::new(1,3)));
+        assert!(Surd::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn rationalising() {
+        assert_eq!((Surd::from(1) / sqrt(2)).to_string(),"√2/2");
+        assert_eq!((Surd::from(1) / (Surd::from(1) + sqrt(2))).to_string(),"-1 + √2");
+        assert_eq!((Surd::from(1) / (sqrt(2) + sqrt(3))).to_string(),"-√2 + √3");
+        assert_eq!(Surd::from(1).checked_div(Surd::default()),None);
+    }
+
+    #[test]
+    fn common_denominator() {
+        let surd = (Surd::from(-3) + sqrt(17)) / Surd::from(4);
+        assert_eq!(surd.to_string(),"(-3 + √17)/4");
+        assert!((surd.to_f64() - 0.28077640640441515).abs() < 1e-12);
+
+        // The common denominator or the numerators over it do not fit into an i64
+        let surd = Surd { terms : BTreeMap::from([(1,Ratio::new(1,i64::MAX)),(2,Ratio::new(1,i64::MAX - 1))]) };
+        assert_eq!(surd.to_string(),"1/9223372036854775807 + √2/9223372036854775806");
+
+        let surd = Surd { terms : BTreeMap::from([(1,Ratio::new(i64::MAX,2)),(3,Ratio::new(-2,3))]) };
+        assert_eq!(surd.to_string(),"9223372036854775807/2 - 2√3/3");
+
+        assert_eq!(Surd::from(i64::MIN).to_string(),"-9223372036854775808");
+    }
+}
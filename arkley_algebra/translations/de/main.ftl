@@ -1,11 +1,11 @@
 # Intereger Quadratics 
 
-discriminant-integerquadratic
+discriminant-integerquadratic =
     .haupttiel = Die Diskriminante (D) wird berechnet als D = b^2 - 4ac
     .subsitiute = Ersetzen Sie nun die Werte: \textbf{"{"} = {$a}, b = { $b} ,c = {$c} {"}"} 
         erhalten wir \textbf{"{"} D = {$b}^2 - 4 * {$a} * {$b} {"}"} , was zu \textbf{"{"} D = {$result} {"}"} kommt.
 
-roots-integerquadratic
+roots-integerquadratic =
     .zero = Da die Diskriminante (D) kleiner als 0 ist, hat die quadratische Gleichung keine reellen Wurzeln
     .one = Da die Diskriminante (D) gleich 0 ist, hat die quadratische Gleichung eine sich wiederholende reelle Wurzel; {$root }
     .two = Da die Diskriminante (D) größer als 0 ist, hat die quadratische Gleichung zwei verschiedene reelle Wurzeln; { $r1 } und { $r2 }
@@ -20,10 +20,34 @@ axis-symmetry-integerquadratic = Um die Symmetrieachse einer quadratischen Gleic
     verwenden Sie die Formel \textbf {"{"} x = -b / (2a) {"}"} wobei \textbf {"{"} b = { $b }{"}"} und \textbf {"{"} a = { $a } {"}"}.
 
 
+# Integer Kubische Gleichungen
+
+discriminant-integercubic =
+    .haupttiel = Die Diskriminante (D) wird berechnet als D = 18abcd - 4b^3d + b^2c^2 - 4ac^3 - 27a^2d^2
+    .subsitiute = Ersetzen Sie nun die Werte: \textbf{"{"} a = {$a}, b = { $b} ,c = { $c} ,d = { $d} {"}"} 
+        erhalten wir \textbf{"{"} D = {$result} {"}"}.
+
+roots-integercubic =
+    .one = Da die Diskriminante (D) kleiner als 0 ist, hat die kubische Gleichung eine reelle Wurzel; {$root }
+    .repeated = Da die Diskriminante (D) gleich 0 ist, hat die kubische Gleichung eine doppelte reelle Wurzel { $double } und eine weitere reelle Wurzel { $single }
+    .triple = Da die Diskriminante (D) gleich 0 ist und alle Wurzeln gleich sind, hat die kubische Gleichung eine dreifache reelle Wurzel; {$root }
+    .three = Da die Diskriminante (D) größer als 0 ist, hat die kubische Gleichung drei verschiedene reelle Wurzeln; { $r1 }, { $r2 } und { $r3 }
+
+# Integer Quartische Gleichungen
+
+discriminant-integerquartic =
+    .haupttiel = Die Diskriminante (D) von \textbf{"{"} ax^4 + bx^3 + cx^2 + dx + e {"}"} wird mit der Formel für quartische Gleichungen aus den Koeffizienten berechnet
+    .subsitiute = Ersetzen Sie nun die Werte: \textbf{"{"} a = {$a}, b = { $b} ,c = { $c} ,d = { $d} ,e = { $e} {"}"} 
+        erhalten wir \textbf{"{"} D = {$result} {"}"}.
+
+roots-integerquartic =
+    .zero = Die quartische Gleichung hat keine reellen Wurzeln
+    .some = Mit dem Verfahren von Ferrari wird die quartische Gleichung in zwei quadratische Gleichungen zerlegt , was die reellen Wurzeln ergibt; { $roots }
+
 # Terms 
 
 
-algebric-term
+algebric-term =
     .add_impossible = \textbf {"{"} { $term1 } + { $term2 } {"}"}
         Da die Terme nicht kombinierbar sind, da sie nicht dieselben Variablen und dieselben Variablenexponenten enthalten. Das Ergebnis ist dasselbe wie oben.
     .add_possible =  \textbf {"{"} { $term3 } {"}"} Da die Terme kombinierbar sind. Füge die Koeffizienten in die obige Tabelle ein.
@@ -47,13 +71,75 @@ algebric-term
 
     .div_coefficient = Jetzt dividieren wir die Koeffizienten, und das Ergebnis ist \textbf {"{"} { $term1 } / { $term2} {"}"}  
 
+    .pow = \textbf {"{"} ({ $term })^{ $exponent } = { $result } {"}"}
+        Potenzieren Sie den Koeffizienten mit { $exponent } und multiplizieren Sie den Exponenten jeder Variablen mit { $exponent }
+
+    .sqrt = \textbf {"{"} √({ $term }) = { $result } {"}"}
+        Ziehen Sie die Quadratwurzel des Koeffizienten und halbieren Sie den Exponenten jeder Variablen
+
+    .sqrt_negative = \textbf {"{"} √({ $term }) = { $result } {"}"}
+        Da der Koeffizient negativ ist, ist die Quadratwurzel keine reelle Zahl und wird daher als Potenz von 0.5 belassen
+
 # Expressions
 
-algebric-expression
+algebric-expression =
     .evalute = Mach \textbf {"{"} { $lexpr } { $op } { $lexpr } {"}"} was \textbf {"{"} $ans {"}"} ergibt
 
+    .pow_zero = Alles hoch 0 ist 1, also \textbf {"{"} ({ $expr })^0 = 1 {"}"}
+    .pow_one = Alles hoch 1 bleibt gleich, also \textbf {"{"} ({ $expr })^1 = { $result } {"}"}
+    .pow_product = Potenzieren Sie jeden Faktor einzeln mit { $exponent } nach \textbf {"{"} (ab)^n = a^n * b^n {"}"}.
+        Das Ergebnis ist \textbf {"{"} { $result } {"}"}
+    .pow_quotient = Potenzieren Sie Zähler und Nenner einzeln mit { $exponent } nach \textbf {"{"} (a/b)^n = a^n / b^n {"}"}.
+        Das Ergebnis ist \textbf {"{"} { $result } {"}"}
+    .pow_power = Multiplizieren Sie die Exponenten nach \textbf {"{"} (a^m)^n = a^(mn) {"}"}.
+        Das Ergebnis ist \textbf {"{"} { $result } {"}"}
+    .pow_binomial = Multiplizieren Sie \textbf {"{"} ({ $expr })^{ $exponent } {"}"} mit dem binomischen Lehrsatz aus, das ergibt \textbf {"{"} { $expansion } {"}"}
+        Vereinfacht man jeden Term, erhält man \textbf {"{"} { $result } {"}"}
+    .pow_unchanged = \textbf {"{"} ({ $expr })^{ $exponent } {"}"} kann nicht weiter vereinfacht werden
+
+    .sqrt_product = Ziehen Sie die Quadratwurzel jedes Faktors einzeln nach \textbf {"{"} √(ab) = √a * √b {"}"}.
+        Das Ergebnis ist \textbf {"{"} { $result } {"}"}
+    .sqrt_quotient = Ziehen Sie die Quadratwurzel von Zähler und Nenner einzeln nach \textbf {"{"} √(a/b) = √a / √b {"}"}.
+        Das Ergebnis ist \textbf {"{"} { $result } {"}"}
+    .sqrt_power = Halbieren Sie den Exponenten nach \textbf {"{"} √(a^n) = a^(n/2) {"}"}.
+        Das Ergebnis ist \textbf {"{"} { $result } {"}"}
+    .sqrt_unchanged = \textbf {"{"} √({ $expr }) {"}"} kann nicht weiter vereinfacht werden und wird daher als \textbf {"{"} { $result } {"}"} geschrieben
+
 # Equations
 
-algebric-equation
+algebric-equation =
     .start_with_left = Wir beginnen mit der linken Seite der Gleichung.
     .start_with_right = Wir beginnen mit der richten Seite der Gleichung.
+
+
+# Partialbrüche
+
+partial-fractions =
+    .factor = Zerlegen Sie den Nenner in lineare und irreduzible quadratische Faktoren: \textbf {"{"} { $denominator } {"}"}
+    .divide = Da der Grad des Zählers nicht kleiner als der Grad des Nenners ist, wird zuerst dividiert.
+        Das ergibt den Quotienten \textbf {"{"} { $quotient } {"}"} und den Rest \textbf {"{"} { $remainder } {"}"}
+    .setup = Schreiben Sie den Bruch als Summe von Partialbrüchen mit unbekannten Zählern: \textbf {"{"} { $setup } {"}"}
+    .solve = Multiplizieren Sie beide Seiten mit dem Nenner und vergleichen Sie die Koeffizienten jeder Potenz, das ergibt \textbf {"{"} { $values } {"}"}
+    .result = Setzt man die Werte wieder ein, erhält man \textbf {"{"} { $result } {"}"}
+
+# Gaußsches Eliminationsverfahren
+
+gaussian-elimination =
+    .matrix = Schreiben Sie das System als erweiterte Matrix mit einer Zeile für jede Gleichung: \textbf {"{"} { $matrix } {"}"}
+    .swap = Vertauschen Sie die Zeilen, damit das Pivotelement nicht 0 ist, mit \textbf {"{"} { $operation } {"}"}, das ergibt \textbf {"{"} { $matrix } {"}"}
+    .scale = Skalieren Sie die Zeile, damit das Pivotelement 1 wird, mit \textbf {"{"} { $operation } {"}"}, das ergibt \textbf {"{"} { $matrix } {"}"}
+    .eliminate = Eliminieren Sie die Variable aus der anderen Zeile mit \textbf {"{"} { $operation } {"}"}, das ergibt \textbf {"{"} { $matrix } {"}"}
+    .unique = Jede Variable hat ein Pivotelement, also hat das System genau eine Lösung: \textbf {"{"} { $solution } {"}"}
+    .inconsistent = Zeile { $row } lautet 0 = b, wobei b nicht 0 ist, also hat das System keine Lösung
+    .parametric = Die Variablen \textbf {"{"} { $free } {"}"} haben kein Pivotelement und können jeden Wert annehmen, also hat das System unendlich viele Lösungen: \textbf {"{"} { $solution } {"}"}
+
+# Beide Seiten
+
+both-sides =
+    .add = Addieren Sie { $expression } auf beiden Seiten von \textbf {"{"} { $equation } {"}"}, das ergibt \textbf {"{"} { $result } {"}"}
+    .sub = Subtrahieren Sie { $expression } auf beiden Seiten von \textbf {"{"} { $equation } {"}"}, das ergibt \textbf {"{"} { $result } {"}"}
+    .mul = Multiplizieren Sie beide Seiten von \textbf {"{"} { $equation } {"}"} mit { $expression }, das ergibt \textbf {"{"} { $result } {"}"}
+    .div = Dividieren Sie beide Seiten von \textbf {"{"} { $equation } {"}"} durch { $expression }, das ergibt \textbf {"{"} { $result } {"}"}
+    .apply = Wenden Sie { $expression } auf beide Seiten von \textbf {"{"} { $equation } {"}"} an, das ergibt \textbf {"{"} { $result } {"}"}
+    .swap = Vertauschen Sie beide Seiten von \textbf {"{"} { $equation } {"}"} und drehen Sie das Relationszeichen um, das ergibt \textbf {"{"} { $result } {"}"}
+    .flip = Da beide Seiten mit einer negativen Zahl multipliziert oder durch sie dividiert wurden, wird die Ungleichung zu { $relation } umgedreht
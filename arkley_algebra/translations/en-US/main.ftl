@@ -1,11 +1,11 @@
 # Intereger Quadratics 
 
-discriminant-integerquadratic
+discriminant-integerquadratic =
     .haupttiel = The discriminant (D) is calculated as D = b^2 - 4ac
     .subsitiute = Now substituting the values: \textbf{"{"} a = {$a}, b = { $b} ,c = { $c} {"}"} 
         we get \textbf{"{"} D = {$b}^2 - 4 * {$a} * {$b} {"}"} , which results in \textbf{"{"} D = {$result} {"}"}.
 
-roots-integerquadratic
+roots-integerquadratic =
     .zero = Since  the discriminant (D) is less than 0 , the quadratic equation has no real roots
     .one = Since the discriminant (D) is equal to 0, the quadratic equation has one repeating real root; {$root }
     .two = Since the discriminant (D) is greater than 0, the quadratic equation has two distinct real roots; { $r1 } and { $r2 }
@@ -19,14 +19,38 @@ sum-integerquadratic = To find the sum of the roots (α and β) of a quadratic e
 axis-symmetry-integerquadratic = To find the axis of symmetry of a quadratic equation, 
     use the formula \textbf {"{"} x = -b / (2a) {"}"} where \textbf {"{"} b = { $b }{"}"} and \textbf {"{"} a = { $a } {"}"}.
 
-concavity-integerquadratic
+concavity-integerquadratic =
     .undefined = Since \textbf {"{"} a = 0 {"}"} where \textbf {"{"} a = { $a }{"}"}, the concave is undefined
     .upwards = Since \textbf {"{"} a > 0 {"}"} where \textbf {"{"} a = { $a }{"}"}, the concave is upwards
     .downwards = Since \textbf {"{"} a < 0 {"}"} where \textbf {"{"} a = { $a }{"}"}, the concave is downwards
 
+# Integer Cubics
+
+discriminant-integercubic =
+    .haupttiel = The discriminant (D) is calculated as D = 18abcd - 4b^3d + b^2c^2 - 4ac^3 - 27a^2d^2
+    .subsitiute = Now substituting the values: \textbf{"{"} a = {$a}, b = { $b} ,c = { $c} ,d = { $d} {"}"} 
+        we get \textbf{"{"} D = {$result} {"}"}.
+
+roots-integercubic =
+    .one = Since the discriminant (D) is less than 0 , the cubic equation has one real root; {$root }
+    .repeated = Since the discriminant (D) is equal to 0, the cubic equation has a repeated real root { $double } and another real root { $single }
+    .triple = Since the discriminant (D) is equal to 0 and all the roots are equal, the cubic equation has one real root repeated three times; {$root }
+    .three = Since the discriminant (D) is greater than 0, the cubic equation has three distinct real roots; { $r1 }, { $r2 } and { $r3 }
+
+# Integer Quartics
+
+discriminant-integerquartic =
+    .haupttiel = The discriminant (D) of \textbf{"{"} ax^4 + bx^3 + cx^2 + dx + e {"}"} is calculated from the coefficients using the formula for quartic equations
+    .subsitiute = Now substituting the values: \textbf{"{"} a = {$a}, b = { $b} ,c = { $c} ,d = { $d} ,e = { $e} {"}"} 
+        we get \textbf{"{"} D = {$result} {"}"}.
+
+roots-integerquartic =
+    .zero = The quartic equation has no real roots
+    .some = Using Ferrari's method the quartic equation is split into two quadratic equations , which gives the real roots; { $roots }
+
 # Terms 
 
-algebric-term
+algebric-term =
     .add_impossible = \textbf {"{"} { $term1 } + { $term2 } {"}"}
         Since terms are not combinable as both do not contain the same variables and same variable exponents. The result is same as above.
     .add_possible =  \textbf {"{"} { $term3 } {"}"} Since terms are combinable. Add the coefficients into the above.
@@ -52,19 +76,81 @@ algebric-term
 
     .single_variable_replace = We replace \textbf {"{"} { $variable } {"}"} with its value 
         and so we do \textbf {"{"} { $coefficient }({ $value }) ^ { $exponent } = { $result } {"}"} , 
-        which leads to \textbf {"{"} { $term } }{"}"
+        which leads to \textbf {"{"} { $term } {"}"}
     
     .multi_variable_replace = We replace \textbf {"{"} { $variables } {"}"} with their values
         and so we do \textbf {"{"} { $coefficient }{ $values } = { $result } {"}"} , 
-        which leads to \textbf {"{"} { $term } }{"}"
+        which leads to \textbf {"{"} { $term } {"}"}
+
+    .pow = \textbf {"{"} ({ $term })^{ $exponent } = { $result } {"}"}
+        Raise the coefficient to the power of { $exponent } and multiply the exponent of each variable by { $exponent }
+
+    .sqrt = \textbf {"{"} √({ $term }) = { $result } {"}"}
+        Take the square root of the coefficient and halve the exponent of each variable
+
+    .sqrt_negative = \textbf {"{"} √({ $term }) = { $result } {"}"}
+        Since the coefficient is negative the square root is not a real number, so it is left as a power of 0.5
 
 # Expressions
 
-algebric-expression
+algebric-expression =
     .evalute = Do \textbf {"{"} { $lexpr } { $op } { $lexpr } {"}"} which is \textbf {"{"} $ans {"}"}
 
+    .pow_zero = Anything raised to the power of 0 is 1, so \textbf {"{"} ({ $expr })^0 = 1 {"}"}
+    .pow_one = Anything raised to the power of 1 stays the same, so \textbf {"{"} ({ $expr })^1 = { $result } {"}"}
+    .pow_product = Raise each factor to the power of { $exponent } separately using \textbf {"{"} (ab)^n = a^n * b^n {"}"}.
+        This results in \textbf {"{"} { $result } {"}"}
+    .pow_quotient = Raise the top and bottom to the power of { $exponent } separately using \textbf {"{"} (a/b)^n = a^n / b^n {"}"}.
+        This results in \textbf {"{"} { $result } {"}"}
+    .pow_power = Multiply the exponents using \textbf {"{"} (a^m)^n = a^(mn) {"}"}.
+        This results in \textbf {"{"} { $result } {"}"}
+    .pow_binomial = Expand \textbf {"{"} ({ $expr })^{ $exponent } {"}"} using the binomial theorem, which gives \textbf {"{"} { $expansion } {"}"}
+        Simplifying each term results in \textbf {"{"} { $result } {"}"}
+    .pow_unchanged = \textbf {"{"} ({ $expr })^{ $exponent } {"}"} can not be simplified any further
+
+    .sqrt_product = Take the square root of each factor separately using \textbf {"{"} √(ab) = √a * √b {"}"}.
+        This results in \textbf {"{"} { $result } {"}"}
+    .sqrt_quotient = Take the square root of the top and bottom separately using \textbf {"{"} √(a/b) = √a / √b {"}"}.
+        This results in \textbf {"{"} { $result } {"}"}
+    .sqrt_power = Halve the exponent using \textbf {"{"} √(a^n) = a^(n/2) {"}"}.
+        This results in \textbf {"{"} { $result } {"}"}
+    .sqrt_unchanged = \textbf {"{"} √({ $expr }) {"}"} can not be simplified any further, so it is written as \textbf {"{"} { $result } {"}"}
+
 # Equations
 
-algebric-equation
+algebric-equation =
     .start_with_left = We start with the left side of the equation.
     .start_with_right = We start with the right side of the equation.
+
+
+# Partial Fractions
+
+partial-fractions =
+    .factor = Factorise the denominator into linear and irreducible quadratic factors: \textbf {"{"} { $denominator } {"}"}
+    .divide = Since the degree of the numerator is not less than the degree of the denominator, divide first.
+        This gives the quotient \textbf {"{"} { $quotient } {"}"} and the remainder \textbf {"{"} { $remainder } {"}"}
+    .setup = Write the fraction as a sum of partial fractions with unknown numerators: \textbf {"{"} { $setup } {"}"}
+    .solve = Multiply both sides by the denominator and equate the coefficients of each power, which gives \textbf {"{"} { $values } {"}"}
+    .result = Substituting the values back results in \textbf {"{"} { $result } {"}"}
+
+# Gaussian Elimination
+
+gaussian-elimination =
+    .matrix = Write the system as an augmented matrix with one row for each equation: \textbf {"{"} { $matrix } {"}"}
+    .swap = Swap the rows so the pivot is not 0 using \textbf {"{"} { $operation } {"}"}, which gives \textbf {"{"} { $matrix } {"}"}
+    .scale = Scale the row so the pivot becomes 1 using \textbf {"{"} { $operation } {"}"}, which gives \textbf {"{"} { $matrix } {"}"}
+    .eliminate = Eliminate the variable from the other row using \textbf {"{"} { $operation } {"}"}, which gives \textbf {"{"} { $matrix } {"}"}
+    .unique = Every variable has a pivot, so the system has exactly one solution: \textbf {"{"} { $solution } {"}"}
+    .inconsistent = Row { $row } reads 0 = b where b is not 0, so the system has no solution
+    .parametric = The variables \textbf {"{"} { $free } {"}"} have no pivot and can take any value, so the system has infinitely many solutions: \textbf {"{"} { $solution } {"}"}
+
+# Both Sides
+
+both-sides =
+    .add = Add { $expression } to both sides of \textbf {"{"} { $equation } {"}"}, which gives \textbf {"{"} { $result } {"}"}
+    .sub = Subtract { $expression } from both sides of \textbf {"{"} { $equation } {"}"}, which gives \textbf {"{"} { $result } {"}"}
+    .mul = Multiply both sides of \textbf {"{"} { $equation } {"}"} by { $expression }, which gives \textbf {"{"} { $result } {"}"}
+    .div = Divide both sides of \textbf {"{"} { $equation } {"}"} by { $expression }, which gives \textbf {"{"} { $result } {"}"}
+    .apply = Apply { $expression } to both sides of \textbf {"{"} { $equation } {"}"}, which gives \textbf {"{"} { $result } {"}"}
+    .swap = Swap both sides of \textbf {"{"} { $equation } {"}"} and flip the relation, which gives \textbf {"{"} { $result } {"}"}
+    .flip = Since both sides were multiplied or divided by a negative number, the inequality is flipped to { $relation }
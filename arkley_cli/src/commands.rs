@@ -32,7 +32,7 @@ pub enum Arguments {
 
         #[arg(skip)]
         // TODO : Add parsers for it and allow it to be passed via cli
-        context : Context<'static>
+        context : Context
     },
 
     #[clap(about = "Rearrange an equation to isolate a variable")]
@@ -55,7 +55,7 @@ pub enum Arguments {
         
         #[arg(skip)]
         // TODO : Add parsers for it and allow it to be passed via cli
-        context : Context<'static>
+        context : Context
     },
 
     #[clap(about = "Solve an equation for a specific variable")]
@@ -66,10 +66,18 @@ pub enum Arguments {
             required = true,
         )]
         equation: String,
+
+        #[arg(
+            short,
+            long,
+            required = true,
+        )]
+        /// The variable to solve for , like `x`
+        variable : char,
         
         #[arg(skip)]
         // TODO : Add parsers for it and allow it to be passed via cli
-        context : Context<'static>
+        context : Context
     },
 
     #[clap(about = "Handle quadratic equations")]
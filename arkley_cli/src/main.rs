@@ -15,7 +15,8 @@ fn main() {
             => Command::command_evaluate(command.locale,&expression_or_equation, context),        
         Arguments::Rearrange { equation, target, context } 
             => Command::command_rearrange(command.locale, &equation, context, &target),
-        Arguments::Solve { equation, context } => todo!(),
+        Arguments::Solve { equation, variable, context } 
+            => Command::command_solve(command.locale, &equation, context, variable),
         Arguments::Quadratic { subcommand } => todo!(),
     }
 }
\ No newline at end of file
@@ -1,12 +1,12 @@
 use std::process::exit;
 
-use arkley_algebra::{parse_expression, Context, manipulation::{EvaluteWithValues, Find}, parse_equation, parse_term, Equation, Term};
+use arkley_algebra::{parse_expression, Context, manipulation::{EvaluteWithValues, Find}, parse_equation, parse_term, Equation, Term, ParseResult};
 use nom::{
     sequence::{preceded, pair, delimited, tuple, terminated}, 
     character::complete::{multispace0, multispace1},
     combinator::map, 
     branch::alt, 
-    bytes::complete::{tag, take_until, take}, IResult
+    bytes::complete::{tag, take_until, take}
 };
 
 use crate::{pretty_errors::{new_default_editor, self}, utils};
@@ -28,7 +28,7 @@ pub fn open() {
     }
 }
 
-fn parse_syntax(input : &str,context : &Context<'_>) {
+fn parse_syntax(input : &str,context : &Context) {
     let mut parser = alt((
         parse_reserved_commands,
         parse_evaluate_command(&context),
@@ -42,7 +42,7 @@ fn parse_syntax(input : &str,context : &Context<'_>) {
 }
 
 /// Parses things like "--command" w/o whitespace
-fn parse_reserved_commands(input : &str) -> IResult<&str,()> {
+fn parse_reserved_commands(input : &str) -> ParseResult<'_,()> {
     const DOC_MSG : &str = r#"
 For detailed information about how to use about the playground, please refer to our documentation:
                 
@@ -61,7 +61,7 @@ https://github.com/Deaths-Door/arkley/tree/main/arkley_cli/README.md"#;
 }
 
 /// Parses `evaluate eq/expr`
-fn parse_evaluate_command<'a>(context : &'a Context<'_>) -> impl FnMut(&'a str) -> IResult<&'a str,()>  {
+fn parse_evaluate_command<'a>(context : &'a Context) -> impl FnMut(&'a str) -> ParseResult<'a,()>  {
     move |input| {
         let (input,_) = delimited(
             multispace0,
@@ -76,7 +76,7 @@ fn parse_evaluate_command<'a>(context : &'a Context<'_>) -> impl FnMut(&'a str)
     }
 }
 
-fn parse_rearrange_command<'a>(context : &'a Context<'_>) -> impl FnMut(&'a str) -> IResult<&'a str,()>  {
+fn parse_rearrange_command<'a>(context : &'a Context) -> impl FnMut(&'a str) -> ParseResult<'a,()>  {
     move |input| {        
         let (input,_) = delimited(
             multispace0,
@@ -88,8 +88,8 @@ fn parse_rearrange_command<'a>(context : &'a Context<'_>) -> impl FnMut(&'a str)
         let (input, _) = tag("into")(input)?;
         let (input, term_str) = multispace1(input)?;
 
-        let equation : Option<Equation> = pretty_errors::try_from_with_message_no_exit((eq_str.trim_end(),context));
-        let term  : Option<Term> = pretty_errors::try_from_with_message_no_exit(term_str.trim_end());
+        let equation : Option<Equation> = pretty_errors::try_from_with_message_no_exit(eq_str.trim_end(),(eq_str.trim_end(),context));
+        let term  : Option<Term> = pretty_errors::try_from_with_message_no_exit(term_str.trim_end(),term_str.trim_end());
         
         if equation.is_some() && term.is_some() {
             utils::rearrange_equation(equation.unwrap(),term.unwrap());
@@ -101,7 +101,7 @@ fn parse_rearrange_command<'a>(context : &'a Context<'_>) -> impl FnMut(&'a str)
 }
 /* 
 /// rearrange .. eq into term
-fn parse_rearrange_command<'a>(context : &'a Context<'_>) -> impl FnMut(&'a str) -> IResult<&'a str,()>  {
+fn parse_rearrange_command<'a>(context : &'a Context) -> impl FnMut(&'a str) -> IResult<&'a str,()>  {
     move |input| {
         let (input,v) = pair(
             parse_command(
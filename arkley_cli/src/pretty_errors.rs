@@ -1,6 +1,8 @@
 use std::process::exit;
 use rustyline::DefaultEditor;
 
+use arkley_algebra::ParseError;
+
 pub fn new_default_editor() -> DefaultEditor {
     match DefaultEditor::new() {
         Ok(e) => e,
@@ -11,21 +13,23 @@ pub fn new_default_editor() -> DefaultEditor {
     }
 }
 
-pub fn try_from_with_message_no_exit<T : TryFrom<I>,I>(input : I) -> Option<T> {
+/// `source` is the text being parsed , used to point at the invalid part of it
+pub fn try_from_with_message_no_exit<T : TryFrom<I,Error = ParseError>,I>(source : &str,input : I) -> Option<T> {
     match T::try_from(input) {
         Ok(ok) => Some(ok),
-        Err(_) => {
-            eprintln!("Sadly given input is invalid , consider inputing a valid input");
+        Err(error) => {
+            eprintln!("Sadly given input is invalid :\n{}",error.render(source));
             None
         },
     }
 }
 
-pub fn try_from_with_message<T : TryFrom<I>,I>(input : I) -> T {
+/// `source` is the text being parsed , used to point at the invalid part of it
+pub fn try_from_with_message<T : TryFrom<I,Error = ParseError>,I>(source : &str,input : I) -> T {
     match T::try_from(input) {
         Ok(ok) => ok,
-        Err(_) => {
-            eprintln!("Sadly given input is invalid , consider inputing a valid input");
+        Err(error) => {
+            eprintln!("Sadly given input is invalid :\n{}",error.render(source));
             exit(1)
         },
     }
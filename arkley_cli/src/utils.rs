@@ -1,6 +1,6 @@
 use arkley_algebra::{
     Term, Context, 
-    manipulation::{EvaluteWithValues, Find}, Equation, Expression
+    manipulation::{EvaluteWithValues, Find}, Equation, Expression, Solution
 };
 
 use arkley_describe::fluent_templates::{self, LanguageIdentifier};
@@ -23,17 +23,17 @@ pub fn rearrange_equation(equation: Equation,target : Term) {
 }
 
 impl Command {
-    pub fn command_evaluate(locale : Option<LanguageIdentifier>,input : &str,context : Context<'_>) {
+    pub fn command_evaluate(locale : Option<LanguageIdentifier>,input : &str,context : Context) {
         match ['=','<','>'].into_iter().any(|c| input.contains(c)) {
             true => {
-                let e : Equation = try_from_with_message((input,&context));
+                let e : Equation = try_from_with_message(input,(input,&context));
                 match locale {
                     Some(locale) => todo!("Describe for it is still penting"),
                     None => println!("Result : {}",e.evaluate_with_multiple_values(context.values()).find()),
                 }        
             },
             false => {
-                let e : Expression = try_from_with_message((input,&context));
+                let e : Expression = try_from_with_message(input,(input,&context));
                 match locale {
                     Some(locale) => todo!("Describe for it is still penting"),
                     None => println!("Result : {}",e.evaluate_with_multiple_values(context.values()).find()),
@@ -42,9 +42,9 @@ impl Command {
         }
     }
 
-    pub fn command_rearrange(locale : Option<LanguageIdentifier>,equation : &str,context : Context<'_>,target : &str) {
-        let eq : Equation = try_from_with_message((equation,&context));
-        let target : Term = try_from_with_message(target);
+    pub fn command_rearrange(locale : Option<LanguageIdentifier>,equation : &str,context : Context,target : &str) {
+        let eq : Equation = try_from_with_message(equation,(equation,&context));
+        let target : Term = try_from_with_message(target,target);
         match locale {
             Some(locale) => todo!("Describe for it is still penting"),
             None => match eq.try_make_subject(target) {
@@ -53,4 +53,19 @@ impl Command {
             },
         }
     }
+
+    pub fn command_solve(locale : Option<LanguageIdentifier>,equation : &str,context : Context,variable : char) {
+        let eq : Equation = try_from_with_message(equation,(equation,&context));
+
+        // Solving can not be described yet so the plain result is printed instead
+        if locale.is_some() {
+            eprintln!("Note : descriptions are not supported for solving yet");
+        }
+
+        match eq.solve(variable) {
+            Ok(Solution::Unique(value)) => println!("Result : {variable} = {value}"),
+            Ok(solution) => println!("Result : {solution}"),
+            Err(err) => eprintln!("Error : {err}"),
+        }
+    }
 }
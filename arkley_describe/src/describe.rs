@@ -44,6 +44,22 @@ pub trait Describe<DescribeOutput = Steps> : Sized {
     fn describe(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<DescribeOutput>;
 }
 
+#[doc = "Represents a generic trait for describing square root operations. The associated type `DescribeOutput` specifies the return type of the method."]
+pub trait DescribeSqrt<DescribeOutput = Steps> : Sized {
+    /// Describes taking the square root of the current instance
+    /// 
+    /// # Parameters
+    /// 
+    /// - `self`: The object on which the method is called.
+    /// - `resources`: A `StaticLoader` used for localization.
+    /// - `lang` : Language to be localized into
+    /// # Returns
+    /// 
+    /// An `Option<DescribeOutput>` representing the description of the operation
+    /// None only when `resources.get(lang,..)` returns None
+    fn describe_sqrt(self,resources:&StaticLoader,lang: &LanguageIdentifier) -> Option<DescribeOutput>;
+}
+
 
 create_describe!(op => DescribeAdd,Add,describe_add);
 create_describe!(op => DescribeSub,Sub,describe_sub);